@@ -3,6 +3,7 @@
 use std::process::{self, Command};
 
 pub fn run<'a>(path: &str, args: impl Iterator<Item = &'a String>) {
+    let args: Vec<&String> = args.collect();
     let is_file = match fs::metadata(path) {
         Ok(metadata) => metadata.is_file(),
         Err(e) => {
@@ -12,16 +13,16 @@ pub fn run<'a>(path: &str, args: impl Iterator<Item = &'a String>) {
     };
 
     if is_file {
-        exit_if_err(
-            Command::new("cargo")
-                .args(["run", "--bin", "clippy-driver", "--"])
-                .args(["-L", "./target/debug"])
-                .args(["-Z", "no-codegen"])
-                .args(["--edition", "2021"])
-                .arg(path)
-                .args(args)
-                .status(),
-        );
+        let mut driver = Command::new("cargo");
+        driver
+            .args(["run", "--bin", "clippy-driver", "--"])
+            .args(["-L", "./target/debug"])
+            .args(["-Z", "no-codegen"])
+            .args(["--edition", "2021"]);
+        if is_library(path, &args) {
+            driver.args(["--crate-type", "lib"]);
+        }
+        exit_if_err(driver.arg(path).args(args).status());
     } else {
         exit_if_err(Command::new("cargo").arg("build").status());
 
@@ -34,3 +35,9 @@ pub fn run<'a>(path: &str, args: impl Iterator<Item = &'a String>) {
         exit_if_err(status);
     }
 }
+
+/// Files without a `main` function are linted as a library, unless a crate type is given.
+fn is_library(path: &str, args: &[&String]) -> bool {
+    !args.iter().any(|arg| arg.starts_with("--crate-type"))
+        && fs::read_to_string(path).map_or(false, |contents| !contents.contains("fn main("))
+}
@@ -40,6 +40,10 @@ fn main() {
                 matches.get_one::<String>("category").map(String::as_str),
                 matches.get_one::<String>("type").map(String::as_str),
                 matches.get_flag("msrv"),
+                &matches
+                    .get_many::<new_lint::ConfigOption>("config")
+                    .map(|config| config.cloned().collect::<Vec<_>>())
+                    .unwrap_or_default(),
             ) {
                 Ok(_) => update_lints::update(update_lints::UpdateMode::Change),
                 Err(e) => eprintln!("Unable to create lint: {e}"),
@@ -206,6 +210,12 @@ fn get_clap_config() -> ArgMatches {
                         .long("msrv")
                         .action(ArgAction::SetTrue)
                         .help("Add MSRV config code to the lint"),
+                    Arg::new("config")
+                        .long("config")
+                        .value_name("KEY=TYPE")
+                        .action(ArgAction::Append)
+                        .value_parser(new_lint::parse_config_option)
+                        .help("Add a configuration value to `clippy.toml`, ex: max_fn_lines=u64"),
                 ]),
             Command::new("setup")
                 .about("Support for setting up your personal development environment")
@@ -276,7 +286,7 @@ fn get_clap_config() -> ArgMatches {
                 .about("Manually run clippy on a file or package")
                 .after_help(indoc! {"
                     EXAMPLES
-                        Lint a single file:
+                        Lint a single file, files without a `main` function are linted as a library:
                             cargo dev lint tests/ui/attrs.rs
 
                         Lint a package directory:
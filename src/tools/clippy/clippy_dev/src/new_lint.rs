@@ -1,5 +1,5 @@
 use crate::clippy_project_root;
-use indoc::{formatdoc, writedoc};
+use indoc::{formatdoc, indoc, writedoc};
 use std::fmt;
 use std::fmt::Write as _;
 use std::fs::{self, OpenOptions};
@@ -12,9 +12,61 @@ struct LintData<'a> {
     name: &'a str,
     category: &'a str,
     ty: Option<&'a str>,
+    config: &'a [ConfigOption],
     project_root: PathBuf,
 }
 
+/// A configuration value of a new lint, given as `--config key=type`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ConfigOption {
+    name: String,
+    ty: String,
+}
+
+impl ConfigOption {
+    fn toml_key(&self) -> String {
+        self.name.replace('_', "-")
+    }
+
+    fn is_integer(&self) -> bool {
+        self.ty
+            .strip_prefix(['i', 'u'])
+            .map_or(false, |bits| matches!(bits, "8" | "16" | "32" | "64" | "128" | "size"))
+    }
+
+    /// Primitive values are read from `conf` directly, everything else is cloned into the lint
+    /// pass.
+    fn is_copy(&self) -> bool {
+        self.is_integer() || matches!(self.ty.as_str(), "bool" | "char" | "f32" | "f64")
+    }
+}
+
+/// Parses a `key=type` pair of the `--config` argument.
+///
+/// # Errors
+///
+/// This function errors out if the key isn't an identifier or the type is missing.
+pub fn parse_config_option(option: &str) -> Result<ConfigOption, String> {
+    let Some((name, ty)) = option.split_once('=') else {
+        return Err(format!("expected `key=type`, found `{option}`"));
+    };
+    let name = name.trim().replace('-', "_");
+    let ty = ty.trim();
+    if name.is_empty()
+        || name.starts_with(|c: char| c.is_ascii_digit())
+        || !name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+    {
+        return Err(format!("`{name}` is not a valid configuration key"));
+    }
+    if ty.is_empty() {
+        return Err(format!("missing type for configuration key `{name}`"));
+    }
+    Ok(ConfigOption {
+        name,
+        ty: ty.to_string(),
+    })
+}
+
 trait Context {
     fn context<C: AsRef<str>>(self, text: C) -> Self;
 }
@@ -43,6 +95,7 @@ pub fn create(
     category: Option<&str>,
     mut ty: Option<&str>,
     msrv: bool,
+    config: &[ConfigOption],
 ) -> io::Result<()> {
     if category == Some("cargo") && ty.is_none() {
         // `cargo` is a special category, these lints should always be in `clippy_lints/src/cargo`
@@ -54,14 +107,26 @@ pub fn create(
         name: lint_name.expect("`name` argument is validated by clap"),
         category: category.expect("`category` argument is validated by clap"),
         ty,
+        config,
         project_root: clippy_project_root(),
     };
 
     create_lint(&lint, msrv).context("Unable to create lint implementation")?;
     create_test(&lint).context("Unable to create a test for the new lint")?;
 
+    if !config.is_empty() {
+        add_config(&lint).context("Unable to add configuration to clippy_lints/src/utils/conf.rs")?;
+        create_config_test(&lint).context("Unable to create a configuration test for the new lint")?;
+    }
+
     if lint.ty.is_none() {
         add_lint(&lint, msrv).context("Unable to add lint to clippy_lints/src/lib.rs")?;
+    } else if !config.is_empty() {
+        println!(
+            "\n\
+            NOTE: The configuration has to be passed to the lint pass in `clippy_lints/src/{}/mod.rs` manually",
+            lint.ty.unwrap_or_default()
+        );
     }
 
     if pass == "early" {
@@ -128,27 +193,127 @@ fn add_lint(lint: &LintData<'_>, enable_msrv: bool) -> io::Result<()> {
 
     let comment_start = lib_rs.find("// add lints here,").expect("Couldn't find comment");
 
-    let new_lint = if enable_msrv {
-        format!(
-            "store.register_{lint_pass}_pass(move |{ctor_arg}| Box::new({module_name}::{camel_name}::new(msrv())));\n    ",
+    lib_rs.insert_str(comment_start, &get_lint_registration(lint, enable_msrv));
+
+    fs::write(path, lib_rs).context("writing")
+}
+
+fn get_lint_registration(lint: &LintData<'_>, enable_msrv: bool) -> String {
+    let mut result = String::new();
+    let mut ctor_args = Vec::new();
+    if enable_msrv {
+        ctor_args.push("msrv()".to_string());
+    }
+    for option in lint.config {
+        if option.is_copy() {
+            let _: fmt::Result = write!(result, "let {0} = conf.{0};\n    ", option.name);
+            ctor_args.push(option.name.clone());
+        } else {
+            let _: fmt::Result = write!(result, "let {0} = conf.{0}.clone();\n    ", option.name);
+            ctor_args.push(format!("{}.clone()", option.name));
+        }
+    }
+
+    let _: fmt::Result = if ctor_args.is_empty() {
+        write!(
+            result,
+            "store.register_{lint_pass}_pass(|{ctor_arg}| Box::new({module_name}::{camel_name}));\n    ",
             lint_pass = lint.pass,
             ctor_arg = if lint.pass == "late" { "_" } else { "" },
             module_name = lint.name,
             camel_name = to_camel_case(lint.name),
         )
     } else {
-        format!(
-            "store.register_{lint_pass}_pass(|{ctor_arg}| Box::new({module_name}::{camel_name}));\n    ",
+        write!(
+            result,
+            "store.register_{lint_pass}_pass(move |{ctor_arg}| Box::new({module_name}::{camel_name}::new({args})));\n    ",
             lint_pass = lint.pass,
             ctor_arg = if lint.pass == "late" { "_" } else { "" },
             module_name = lint.name,
             camel_name = to_camel_case(lint.name),
+            args = ctor_args.join(", "),
         )
     };
 
-    lib_rs.insert_str(comment_start, &new_lint);
+    result
+}
+
+fn add_config(lint: &LintData<'_>) -> io::Result<()> {
+    let path = lint.project_root.join("clippy_lints/src/utils/conf.rs");
+    let mut conf_rs = fs::read_to_string(&path).context("reading")?;
 
-    fs::write(path, lib_rs).context("writing")
+    let conf_end = find_conf_end(&conf_rs).expect("Couldn't find the end of `define_Conf!`");
+    conf_rs.insert_str(conf_end, &get_conf_entries(lint));
+
+    fs::write(&path, conf_rs).context("writing")?;
+    println!("Added configuration to `clippy_lints/src/utils/conf.rs`");
+
+    Ok(())
+}
+
+/// Returns the position of the closing brace of the `define_Conf!` invocation, the first
+/// unindented `}` after it.
+fn find_conf_end(conf_rs: &str) -> Option<usize> {
+    let start = conf_rs.find("\ndefine_Conf! {\n")?;
+    conf_rs[start..].find("\n}\n").map(|end| start + end + 1)
+}
+
+fn get_conf_entries(lint: &LintData<'_>) -> String {
+    let name_upper = lint.name.to_uppercase();
+    let mut result = String::new();
+    for option in lint.config {
+        let _: fmt::Result = writedoc!(
+            result,
+            r#"
+                    /// Lint: {name_upper}.
+                    ///
+                    /// TODO: Describe what `{key}` configures
+                    ({name}: {ty} = Default::default()),
+            "#,
+            key = option.toml_key(),
+            name = option.name,
+            ty = option.ty,
+        );
+    }
+    result
+}
+
+fn create_config_test(lint: &LintData<'_>) -> io::Result<()> {
+    let relative_test_dir = format!("tests/ui-toml/{}", lint.name);
+    let test_dir = lint.project_root.join(&relative_test_dir);
+    fs::create_dir(&test_dir)?;
+
+    write_file(test_dir.join("clippy.toml"), get_clippy_toml_contents(lint.config))?;
+    write_file(
+        test_dir.join(format!("{}.rs", lint.name)),
+        get_test_file_contents(lint.name, None),
+    )?;
+
+    println!("Generated configuration test: `{relative_test_dir}`");
+
+    Ok(())
+}
+
+fn get_clippy_toml_contents(config: &[ConfigOption]) -> String {
+    let mut result = String::new();
+    for option in config {
+        let key = option.toml_key();
+        let value = match option.ty.as_str() {
+            "bool" => Some("true"),
+            "String" => Some("\"\""),
+            _ if option.is_integer() => Some("0"),
+            ty if ty.starts_with("Vec<") => Some("[]"),
+            _ => None,
+        };
+        let _: fmt::Result = match value {
+            Some(value) => writeln!(
+                result,
+                "# TODO: Use a value that differs from the default\n{key} = {value}"
+            ),
+            None => writeln!(result, "# TODO: Add a value for `{}`\n# {key} = ", option.ty),
+        };
+    }
+    result
 }
 
 fn write_file<P: AsRef<Path>, C: AsRef<[u8]>>(path: P, contents: C) -> io::Result<()> {
@@ -253,6 +418,15 @@ fn get_lint_file_contents(lint: &LintData<'_>, enable_msrv: bool) -> String {
             use rustc_lint::{{{context_import}, {pass_type}, LintContext}};
             use rustc_session::{{declare_tool_lint, impl_lint_pass}};
 
+        "#
+        )
+    } else if !lint.config.is_empty() {
+        formatdoc!(
+            r#"
+            {pass_import}
+            use rustc_lint::{{{context_import}, {pass_type}}};
+            use rustc_session::{{declare_tool_lint, impl_lint_pass}};
+
         "#
         )
     } else {
@@ -268,30 +442,49 @@ fn get_lint_file_contents(lint: &LintData<'_>, enable_msrv: bool) -> String {
 
     let _: fmt::Result = write!(result, "{}", get_lint_declaration(&name_upper, category));
 
-    result.push_str(&if enable_msrv {
+    result.push_str(&if enable_msrv || !lint.config.is_empty() {
+        let fields: Vec<(&str, &str)> = enable_msrv
+            .then_some(("msrv", "Msrv"))
+            .into_iter()
+            .chain(lint.config.iter().map(|option| (&*option.name, &*option.ty)))
+            .collect();
+        let struct_fields: String = fields.iter().map(|(name, ty)| format!("\n    {name}: {ty},")).collect();
+        let new_params = fields
+            .iter()
+            .map(|(name, ty)| format!("{name}: {ty}"))
+            .collect::<Vec<_>>()
+            .join(", ");
+        let new_fields = fields.iter().map(|(name, _)| *name).collect::<Vec<_>>().join(", ");
+        let (pass_body, msrv_todos) = if enable_msrv {
+            (
+                format!("{{\n    extract_msrv_attr!({context_import});\n}}"),
+                indoc! {"
+
+                    // TODO: Add MSRV level to `clippy_utils/src/msrvs.rs` if needed.
+                    // TODO: Add MSRV test to `tests/ui/min_rust_version_attr.rs`.
+                    // TODO: Update msrv config comment in `clippy_lints/src/utils/conf.rs`
+                "},
+            )
+        } else {
+            ("{}".to_string(), "")
+        };
+
         formatdoc!(
             r#"
-            pub struct {name_camel} {{
-                msrv: Msrv,
+            pub struct {name_camel} {{{struct_fields}
             }}
 
             impl {name_camel} {{
                 #[must_use]
-                pub fn new(msrv: Msrv) -> Self {{
-                    Self {{ msrv }}
+                pub fn new({new_params}) -> Self {{
+                    Self {{ {new_fields} }}
                 }}
             }}
 
             impl_lint_pass!({name_camel} => [{name_upper}]);
 
-            impl {pass_type}{pass_lifetimes} for {name_camel} {{
-                extract_msrv_attr!({context_import});
-            }}
-
-            // TODO: Add MSRV level to `clippy_utils/src/msrvs.rs` if needed.
-            // TODO: Add MSRV test to `tests/ui/min_rust_version_attr.rs`.
-            // TODO: Update msrv config comment in `clippy_lints/src/utils/conf.rs`
-        "#
+            impl {pass_type}{pass_lifetimes} for {name_camel} {pass_body}
+            {msrv_todos}"#
         )
     } else {
         formatdoc!(
@@ -565,3 +758,77 @@ fn test_camel_case() {
     let name4 = to_camel_case(name3);
     assert_eq!(name4, "LintName");
 }
+
+#[test]
+fn test_parse_config_option() {
+    let option = parse_config_option("max-fn-lines=u64").unwrap();
+    assert_eq!(option.name, "max_fn_lines");
+    assert_eq!(option.ty, "u64");
+    assert_eq!(option.toml_key(), "max-fn-lines");
+    assert!(option.is_copy());
+
+    let option = parse_config_option("allowed_names = Vec<String>").unwrap();
+    assert_eq!(option.name, "allowed_names");
+    assert_eq!(option.ty, "Vec<String>");
+    assert!(!option.is_copy());
+
+    assert!(parse_config_option("max_fn_lines").is_err());
+    assert!(parse_config_option("max_fn_lines=").is_err());
+    assert!(parse_config_option("1st=u64").is_err());
+    assert!(parse_config_option("max fn=u64").is_err());
+}
+
+#[test]
+fn test_config_skeleton() {
+    let config = [
+        parse_config_option("max_fn_lines=u64").unwrap(),
+        parse_config_option("allowed_names=Vec<String>").unwrap(),
+    ];
+    let lint = LintData {
+        pass: "late",
+        name: "fn_too_long",
+        category: "nursery",
+        ty: None,
+        config: &config,
+        project_root: PathBuf::new(),
+    };
+
+    assert_eq!(
+        get_lint_registration(&lint, true),
+        "let max_fn_lines = conf.max_fn_lines;\n    \
+        let allowed_names = conf.allowed_names.clone();\n    \
+        store.register_late_pass(move |_| Box::new(fn_too_long::FnTooLong::new(msrv(), max_fn_lines, allowed_names.clone())));\n    "
+    );
+    assert_eq!(
+        get_conf_entries(&lint),
+        "    /// Lint: FN_TOO_LONG.\n    \
+        ///\n    \
+        /// TODO: Describe what `max-fn-lines` configures\n    \
+        (max_fn_lines: u64 = Default::default()),\n    \
+        /// Lint: FN_TOO_LONG.\n    \
+        ///\n    \
+        /// TODO: Describe what `allowed-names` configures\n    \
+        (allowed_names: Vec<String> = Default::default()),\n"
+    );
+    assert_eq!(
+        get_clippy_toml_contents(&config),
+        "# TODO: Use a value that differs from the default\nmax-fn-lines = 0\n\
+        # TODO: Use a value that differs from the default\nallowed-names = []\n"
+    );
+}
+
+#[test]
+fn test_find_conf_end() {
+    let conf_rs = "macro_rules! define_Conf {\n    () => {};\n}\n\n\
+        define_Conf! {\n    (msrv: Option<String> = None),\n}\n\n\
+        const CONFIG_FILE_NAMES: [&str; 2] = [\".clippy.toml\", \"clippy.toml\"];\n";
+    let conf_end = find_conf_end(conf_rs).unwrap();
+    assert!(conf_rs[..conf_end].ends_with("(msrv: Option<String> = None),\n"));
+    assert!(conf_rs[conf_end..].starts_with("}\n\nconst CONFIG_FILE_NAMES"));
+
+    assert_eq!(find_conf_end("define_Conf!"), None);
+
+    // `cargo dev new_lint --config` has to find it in the actual file
+    let conf_rs = fs::read_to_string(clippy_project_root().join("clippy_lints/src/utils/conf.rs")).unwrap();
+    assert!(find_conf_end(&conf_rs).is_some());
+}
@@ -35,9 +35,52 @@ pub enum UpdateMode {
 /// Panics if a file path could not read from or then written to
 pub fn update(update_mode: UpdateMode) {
     let (lints, deprecated_lints, renamed_lints) = gather_all();
+    let errors = check_renamed_lints(&lints, &deprecated_lints, &renamed_lints);
+    if !errors.is_empty() {
+        for error in errors {
+            eprintln!("error: {error}");
+        }
+        eprintln!("Please fix the entries of `clippy_lints/src/renamed_lints.rs`.");
+        std::process::exit(1);
+    }
     generate_lint_files(update_mode, &lints, &deprecated_lints, &renamed_lints);
 }
 
+/// Checks that every renamed lint is renamed once and to a lint which exists. Lints uplifted to
+/// rustc can't be checked here, `tests/ui/rename.rs` fails for them instead.
+fn check_renamed_lints(
+    lints: &[Lint],
+    deprecated_lints: &[DeprecatedLint],
+    renamed_lints: &[RenamedLint],
+) -> Vec<String> {
+    let mut errors = Vec::new();
+    for (i, lint) in renamed_lints.iter().enumerate() {
+        if renamed_lints[..i].iter().any(|l| l.old_name == lint.old_name) {
+            errors.push(format!("`{}` is renamed more than once", lint.old_name));
+        }
+        let Some(new_name) = lint.new_name.strip_prefix("clippy::") else {
+            continue;
+        };
+        if renamed_lints.iter().any(|l| l.old_name == lint.new_name) {
+            errors.push(format!(
+                "`{}` is renamed to `{}`, which is itself renamed",
+                lint.old_name, lint.new_name
+            ));
+        } else if deprecated_lints.iter().any(|l| l.name == new_name) {
+            errors.push(format!(
+                "`{}` is renamed to `{}`, which is deprecated",
+                lint.old_name, lint.new_name
+            ));
+        } else if !lints.iter().any(|l| l.name == new_name) {
+            errors.push(format!(
+                "`{}` is renamed to `{}`, which doesn't exist",
+                lint.old_name, lint.new_name
+            ));
+        }
+    }
+    errors
+}
+
 fn generate_lint_files(
     update_mode: UpdateMode,
     lints: &[Lint],
@@ -1178,6 +1221,30 @@ fn test_by_lint_group() {
         assert_eq!(expected, Lint::by_lint_group(lints.into_iter()));
     }
 
+    #[test]
+    fn test_check_renamed_lints() {
+        let lints = vec![Lint::new("new_name", "style", "\"abc\"", "module", Range::default())];
+        let deprecated_lints = vec![DeprecatedLint::new("removed", "\"reason\"", Range::default())];
+        let renamed_lints = vec![
+            RenamedLint::new("\"clippy::old_name\"", "\"clippy::new_name\""),
+            RenamedLint::new("\"clippy::uplifted\"", "\"drop_bounds\""),
+            RenamedLint::new("\"clippy::typo\"", "\"clippy::new_nmae\""),
+            RenamedLint::new("\"clippy::old_name\"", "\"clippy::new_name\""),
+            RenamedLint::new("\"clippy::chained\"", "\"clippy::old_name\""),
+            RenamedLint::new("\"clippy::to_removed\"", "\"clippy::removed\""),
+        ];
+
+        assert_eq!(
+            check_renamed_lints(&lints, &deprecated_lints, &renamed_lints),
+            [
+                "`clippy::typo` is renamed to `clippy::new_nmae`, which doesn't exist",
+                "`clippy::old_name` is renamed more than once",
+                "`clippy::chained` is renamed to `clippy::old_name`, which is itself renamed",
+                "`clippy::to_removed` is renamed to `clippy::removed`, which is deprecated",
+            ]
+        );
+    }
+
     #[test]
     fn test_gen_deprecated() {
         let lints = vec![
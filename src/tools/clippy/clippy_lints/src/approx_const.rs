@@ -1,8 +1,10 @@
-use clippy_utils::diagnostics::span_lint_and_help;
+use clippy_utils::diagnostics::span_lint_and_sugg;
 use clippy_utils::msrvs::{self, Msrv};
-use rustc_ast::ast::{FloatTy, LitFloatType, LitKind};
+use rustc_ast::ast::LitKind;
+use rustc_errors::Applicability;
 use rustc_hir::{Expr, ExprKind};
 use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::ty;
 use rustc_semver::RustcVersion;
 use rustc_session::{declare_tool_lint, impl_lint_pass};
 use rustc_span::symbol;
@@ -62,6 +64,10 @@
     (f64::TAU, "TAU", 3, Some(msrvs::TAU)),
 ];
 
+/// Literals written with fewer significant digits than this are likely meant as a rough value,
+/// which replacing with the exact constant could change the meaning of.
+const MIN_PRECISE_DIGITS: usize = 6;
+
 pub struct ApproxConstant {
     msrv: Msrv,
 }
@@ -73,28 +79,37 @@ pub fn new(msrv: Msrv) -> Self {
     }
 
     fn check_lit(&self, cx: &LateContext<'_>, lit: &LitKind, e: &Expr<'_>) {
-        match *lit {
-            LitKind::Float(s, LitFloatType::Suffixed(fty)) => match fty {
-                FloatTy::F32 => self.check_known_consts(cx, e, s, "f32"),
-                FloatTy::F64 => self.check_known_consts(cx, e, s, "f64"),
-            },
-            LitKind::Float(s, LitFloatType::Unsuffixed) => self.check_known_consts(cx, e, s, "f{32, 64}"),
-            _ => (),
+        if let LitKind::Float(s, _) = *lit
+            && let ty::Float(fty) = cx.typeck_results().expr_ty(e).kind()
+        {
+            self.check_known_consts(cx, e, s, fty.name_str());
         }
     }
 
     fn check_known_consts(&self, cx: &LateContext<'_>, e: &Expr<'_>, s: symbol::Symbol, module: &str) {
         let s = s.as_str();
-        if s.parse::<f64>().is_ok() {
+        // Strip the suffix of e.g. `3.14f32`
+        let value = s.split_once('f').map_or(s, |(value, _)| value);
+        if let Ok(parsed) = value.parse::<f64>()
+            && !value.contains(['e', 'E'])
+        {
             for &(constant, name, min_digits, msrv) in &KNOWN_CONSTS {
-                if is_approx_const(constant, s, min_digits) && msrv.map_or(true, |msrv| self.msrv.meets(msrv)) {
-                    span_lint_and_help(
+                if is_approx_const(constant, value, parsed, min_digits)
+                    && msrv.map_or(true, |msrv| self.msrv.meets(msrv))
+                {
+                    let app = if significant_digits(value) < MIN_PRECISE_DIGITS {
+                        Applicability::MaybeIncorrect
+                    } else {
+                        Applicability::MachineApplicable
+                    };
+                    span_lint_and_sugg(
                         cx,
                         APPROX_CONSTANT,
                         e.span,
-                        &format!("approximate value of `{module}::consts::{}` found", &name),
-                        None,
+                        &format!("approximate value of `{module}::consts::{name}` found"),
                         "consider using the constant directly",
+                        format!("std::{module}::consts::{name}"),
+                        app,
                     );
                     return;
                 }
@@ -107,7 +122,9 @@ fn check_known_consts(&self, cx: &LateContext<'_>, e: &Expr<'_>, s: symbol::Symb
 
 impl<'tcx> LateLintPass<'tcx> for ApproxConstant {
     fn check_expr(&mut self, cx: &LateContext<'tcx>, e: &'tcx Expr<'_>) {
-        if let ExprKind::Lit(lit) = &e.kind {
+        if let ExprKind::Lit(lit) = &e.kind
+            && !e.span.from_expansion()
+        {
             self.check_lit(cx, &lit.node, e);
         }
     }
@@ -115,18 +132,28 @@ fn check_expr(&mut self, cx: &LateContext<'tcx>, e: &'tcx Expr<'_>) {
     extract_msrv_attr!(LateContext);
 }
 
-/// Returns `false` if the number of significant figures in `value` are
-/// less than `min_digits`; otherwise, returns true if `value` is equal
-/// to `constant`, rounded to the number of digits present in `value`.
+/// Returns `false` if the number of characters in `value` is at most `min_digits`; otherwise,
+/// returns true if `value` is `constant` truncated or rounded to the number of decimal places
+/// written in `value`. Digits beyond the precision of `f64` are compared as parsed.
 #[must_use]
-fn is_approx_const(constant: f64, value: &str, min_digits: usize) -> bool {
+fn is_approx_const(constant: f64, value: &str, parsed: f64, min_digits: usize) -> bool {
     if value.len() <= min_digits {
-        false
-    } else if constant.to_string().starts_with(value) {
-        // The value is a truncated constant
-        true
-    } else {
-        let round_const = format!("{constant:.*}", value.len() - 2);
-        value == round_const
+        return false;
     }
+    let decimals = value.split_once('.').map_or(0, |(_, decimals)| decimals.len());
+    let unit = 10_f64.powi(-i32::try_from(decimals).unwrap_or(i32::MAX));
+    // Allow for the rounding error of the literal itself.
+    let slack = constant * f64::EPSILON;
+    let diff = constant - parsed;
+    // Truncated: `3.1415`, or rounded: `3.1416`
+    (-slack..unit + slack).contains(&diff) || diff.abs() <= unit / 2.0 + slack
+}
+
+/// Counts the digits of `value` after any leading zeros.
+fn significant_digits(value: &str) -> usize {
+    value
+        .chars()
+        .filter(char::is_ascii_digit)
+        .skip_while(|&c| c == '0')
+        .count()
 }
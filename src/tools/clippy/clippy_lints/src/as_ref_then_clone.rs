@@ -0,0 +1,106 @@
+use clippy_utils::diagnostics::span_lint_and_help;
+use clippy_utils::source::snippet;
+use clippy_utils::ty::is_type_diagnostic_item;
+use clippy_utils::usage::local_used_after_expr;
+use clippy_utils::visitors::for_each_expr;
+use clippy_utils::{get_enclosing_block, is_trait_method, path_to_local};
+use core::ops::ControlFlow;
+use rustc_hir::intravisit::FnKind;
+use rustc_hir::{Body, Expr, ExprKind, FnDecl, LangItem, MatchSource, QPath};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_span::def_id::LocalDefId;
+use rustc_span::{sym, Span};
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for `opt.as_ref()?.clone()` and `opt.as_ref().ok_or(e)?.clone()` where `opt` is a
+    /// local `Option` which isn't used afterwards.
+    ///
+    /// ### Why is this bad?
+    /// The value is borrowed only to be cloned, although it could be moved out of `opt`.
+    ///
+    /// ### Example
+    /// ```rust
+    /// fn first_name(name: Option<String>) -> Option<String> {
+    ///     let first = name.as_ref()?.clone();
+    ///     Some(first)
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// fn first_name(name: Option<String>) -> Option<String> {
+    ///     let first = name?;
+    ///     Some(first)
+    /// }
+    /// ```
+    #[clippy::version = "1.73.0"]
+    pub REDUNDANT_AS_REF_IN_QUESTION_MARK,
+    nursery,
+    "cloning the value of an `Option` through `as_ref()?` when it could be moved"
+}
+declare_lint_pass!(AsRefThenClone => [REDUNDANT_AS_REF_IN_QUESTION_MARK]);
+
+impl<'tcx> LateLintPass<'tcx> for AsRefThenClone {
+    fn check_fn(
+        &mut self,
+        cx: &LateContext<'tcx>,
+        kind: FnKind<'tcx>,
+        _: &'tcx FnDecl<'_>,
+        body: &'tcx Body<'_>,
+        _: Span,
+        _: LocalDefId,
+    ) {
+        // A value captured by a closure can't be moved out of it if it's called more than once
+        if matches!(kind, FnKind::Closure) {
+            return;
+        }
+        for_each_expr(body.value, |expr| {
+            check_clone(cx, expr);
+            ControlFlow::<()>::Continue(())
+        });
+    }
+}
+
+fn check_clone<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) {
+    if let ExprKind::MethodCall(clone_path, recv, [], _) = expr.kind
+        && clone_path.ident.name == sym::clone
+        && !expr.span.from_expansion()
+        && is_trait_method(cx, expr, sym::Clone)
+        && let ExprKind::Match(try_scrutinee, _, MatchSource::TryDesugar) = recv.kind
+        && let ExprKind::Call(branch, [tried]) = try_scrutinee.kind
+        && let ExprKind::Path(QPath::LangItem(LangItem::TryTraitBranch, ..)) = branch.kind
+        && let Some((as_ref, conversion)) = peel_ok_or(tried)
+        && let ExprKind::MethodCall(as_ref_path, opt, [], _) = as_ref.kind
+        && as_ref_path.ident.name == sym::as_ref
+        // Only an owned `Option` can be moved out of, not e.g. `&Option<T>`
+        && is_type_diagnostic_item(cx, cx.typeck_results().expr_ty(opt), sym::Option)
+        && let Some(local_id) = path_to_local(opt)
+        // Without it `local_used_after_expr` can't tell whether `opt` is used again
+        && get_enclosing_block(cx, local_id).is_some()
+        && !local_used_after_expr(cx, local_id, expr)
+    {
+        let opt = snippet(cx, opt.span, "..");
+        let conversion = conversion.map_or_else(String::new, |span| format!(".{}", snippet(cx, span, "..")));
+        span_lint_and_help(
+            cx,
+            REDUNDANT_AS_REF_IN_QUESTION_MARK,
+            expr.span,
+            "cloning a value which is only borrowed through `as_ref()`",
+            None,
+            &format!("`{opt}` isn't used afterwards, consider moving out of it: `{opt}{conversion}?`"),
+        );
+    }
+}
+
+/// Peels off `.ok_or(..)` and `.ok_or_else(..)`, and returns the span of that call without the
+/// receiver as well.
+fn peel_ok_or<'tcx>(expr: &'tcx Expr<'tcx>) -> Option<(&'tcx Expr<'tcx>, Option<Span>)> {
+    match expr.kind {
+        ExprKind::MethodCall(path, recv, [_], span) if matches!(path.ident.name.as_str(), "ok_or" | "ok_or_else") => {
+            Some((recv, Some(span)))
+        },
+        ExprKind::MethodCall(..) => Some((expr, None)),
+        _ => None,
+    }
+}
@@ -1,6 +1,6 @@
 use clippy_utils::diagnostics::span_lint_and_then;
 use clippy_utils::macros::{find_assert_eq_args, root_macro_call_first_node};
-use clippy_utils::sugg::Sugg;
+use clippy_utils::sugg::{has_total_ord_operands, Sugg};
 use clippy_utils::ty::{implements_trait, is_copy};
 use rustc_ast::ast::LitKind;
 use rustc_errors::Applicability;
@@ -133,7 +133,8 @@ fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
                     let Some(sugg) = Sugg::hir_opt(cx, non_lit_expr) else {
                         return;
                     };
-                    suggestions.push((non_lit_expr.span, (!sugg).to_string()));
+                    let total_ord = has_total_ord_operands(cx, non_lit_expr);
+                    suggestions.push((non_lit_expr.span, sugg.negate(total_ord).to_string()));
                 }
 
                 diag.multipart_suggestion(
@@ -5,7 +5,7 @@
 use rustc_session::{declare_lint_pass, declare_tool_lint};
 
 use clippy_utils::diagnostics::span_lint_and_then;
-use clippy_utils::sugg::Sugg;
+use clippy_utils::sugg::{has_total_ord_operands, Sugg};
 use clippy_utils::{in_constant, is_else_clause, is_integer_literal};
 use rustc_errors::Applicability;
 
@@ -71,7 +71,7 @@ fn check_if_else<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx rustc_hir::Expr<'tcx>
         let snippet = {
             let mut sugg = Sugg::hir_with_applicability(cx, cond, "..", &mut applicability);
             if inverted {
-                sugg = !sugg;
+                sugg = sugg.negate(has_total_ord_operands(cx, cond));
             }
             sugg
         };
@@ -1,7 +1,9 @@
 use clippy_utils::diagnostics::{span_lint_and_sugg, span_lint_hir_and_then};
 use clippy_utils::eq_expr_value;
 use clippy_utils::source::snippet_opt;
-use clippy_utils::ty::{implements_trait, is_type_diagnostic_item};
+use clippy_utils::sugg::has_total_ord_operands;
+use clippy_utils::ty::is_type_diagnostic_item;
+use clippy_utils::visitors::for_each_expr;
 use if_chain::if_chain;
 use rustc_ast::ast::LitKind;
 use rustc_errors::Applicability;
@@ -12,6 +14,7 @@
 use rustc_span::def_id::LocalDefId;
 use rustc_span::source_map::Span;
 use rustc_span::sym;
+use std::ops::ControlFlow;
 
 declare_clippy_lint! {
     /// ### What it does
@@ -154,7 +157,7 @@ fn negate(bin_op_kind: BinOpKind) -> Option<BinOpKind> {
 
             if_chain! {
                 if let ExprKind::Binary(e_binop, e_lhs, e_rhs) = &e.kind;
-                if implements_ord(self.cx, e_lhs);
+                if has_total_ord_operands(self.cx, e);
                 if let ExprKind::Binary(expr_binop, expr_lhs, expr_rhs) = &expr.kind;
                 if negate(e_binop.node) == Some(expr_binop.node);
                 if eq_expr_value(self.cx, e_lhs, expr_lhs);
@@ -248,7 +251,7 @@ fn recurse(&mut self, suggestion: &Bool) -> Option<()> {
 fn simplify_not(cx: &LateContext<'_>, expr: &Expr<'_>) -> Option<String> {
     match &expr.kind {
         ExprKind::Binary(binop, lhs, rhs) => {
-            if !implements_ord(cx, lhs) {
+            if !has_total_ord_operands(cx, expr) {
                 return None;
             }
 
@@ -385,6 +388,13 @@ fn bool_expr(&self, e: &'tcx Expr<'_>) {
             let mut improvements = Vec::with_capacity(simplified.len());
             'simplified: for suggestion in &simplified {
                 let simplified_stats = terminal_stats(suggestion);
+                // don't suggest "simplifications" that end up evaluating a function call less often than the
+                // original expression, its side effects would be lost
+                let drops_call = h2q
+                    .terminals
+                    .iter()
+                    .enumerate()
+                    .any(|(i, terminal)| stats.terminals[i] > simplified_stats.terminals[i] && contains_call(terminal));
                 let mut improvement = false;
                 for i in 0..32 {
                     // ignore any "simplifications" that end up requiring a terminal more often
@@ -424,7 +434,7 @@ fn bool_expr(&self, e: &'tcx Expr<'_>) {
                         || (stats.negations > simplified_stats.negations && stats.ops == simplified_stats.ops)
                         || (stats.ops > simplified_stats.ops && stats.negations == simplified_stats.negations);
                 }
-                if improvement {
+                if improvement && !drops_call {
                     improvements.push(suggestion);
                 }
             }
@@ -487,11 +497,16 @@ fn visit_expr(&mut self, e: &'tcx Expr<'_>) {
     }
 }
 
-fn implements_ord(cx: &LateContext<'_>, expr: &Expr<'_>) -> bool {
-    let ty = cx.typeck_results().expr_ty(expr);
-    cx.tcx
-        .get_diagnostic_item(sym::Ord)
-        .map_or(false, |id| implements_trait(cx, ty, id, &[]))
+/// Checks whether `expr` calls a function or method, which could have side effects.
+fn contains_call(expr: &Expr<'_>) -> bool {
+    for_each_expr(expr, |e| {
+        if matches!(e.kind, ExprKind::Call(..) | ExprKind::MethodCall(..)) {
+            ControlFlow::Break(())
+        } else {
+            ControlFlow::Continue(())
+        }
+    })
+    .is_some()
 }
 
 struct NotSimplificationVisitor<'a, 'tcx> {
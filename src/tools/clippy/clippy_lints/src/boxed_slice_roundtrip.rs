@@ -0,0 +1,88 @@
+use clippy_utils::diagnostics::span_lint_and_sugg;
+use clippy_utils::source::snippet_with_context;
+use clippy_utils::ty::is_type_diagnostic_item;
+use rustc_errors::Applicability;
+use rustc_hir::{Expr, ExprKind};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::ty::Ty;
+use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_span::sym;
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for `.into_boxed_slice().into_vec()` on a `Vec` and `.into_vec().into_boxed_slice()`
+    /// on a boxed slice.
+    ///
+    /// ### Why is this bad?
+    /// The conversions cancel each other out. Converting a `Vec` into a boxed slice may also
+    /// reallocate to drop the spare capacity.
+    ///
+    /// ### Known problems
+    /// Unlike the round trip, the original `Vec` keeps its spare capacity. Call
+    /// `shrink_to_fit` on it if dropping the capacity was intended.
+    ///
+    /// ### Example
+    /// ```rust
+    /// let v = vec![1, 2, 3];
+    /// let v = v.into_boxed_slice().into_vec();
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// let v = vec![1, 2, 3];
+    /// ```
+    #[clippy::version = "1.73.0"]
+    pub REDUNDANT_VEC_INTO_BOXED_SLICE_THEN_VEC,
+    complexity,
+    "converting a `Vec` into a boxed slice and back, or the other way round"
+}
+declare_lint_pass!(BoxedSliceRoundtrip => [REDUNDANT_VEC_INTO_BOXED_SLICE_THEN_VEC]);
+
+impl<'tcx> LateLintPass<'tcx> for BoxedSliceRoundtrip {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) {
+        if let ExprKind::MethodCall(outer, middle, [], _) = expr.kind
+            && let ExprKind::MethodCall(inner, recv, [], _) = middle.kind
+            && !expr.span.from_expansion()
+            && expr.span.ctxt() == middle.span.ctxt()
+        {
+            let recv_ty = cx.typeck_results().expr_ty(recv);
+            let middle_ty = cx.typeck_results().expr_ty(middle);
+            let (msg, help, mut app) = if inner.ident.name == sym!(into_boxed_slice)
+                && outer.ident.name == sym!(into_vec)
+                && is_type_diagnostic_item(cx, recv_ty, sym::Vec)
+                && is_boxed_slice(middle_ty)
+            {
+                (
+                    "converting a `Vec` into a boxed slice and back",
+                    "use the original `Vec`, which unlike the round trip keeps its spare capacity",
+                    Applicability::MaybeIncorrect,
+                )
+            } else if inner.ident.name == sym!(into_vec)
+                && outer.ident.name == sym!(into_boxed_slice)
+                && is_boxed_slice(recv_ty)
+                && is_type_diagnostic_item(cx, middle_ty, sym::Vec)
+            {
+                (
+                    "converting a boxed slice into a `Vec` and back",
+                    "use the original boxed slice",
+                    Applicability::MachineApplicable,
+                )
+            } else {
+                return;
+            };
+            let sugg = snippet_with_context(cx, recv.span, expr.span.ctxt(), "..", &mut app).0;
+            span_lint_and_sugg(
+                cx,
+                REDUNDANT_VEC_INTO_BOXED_SLICE_THEN_VEC,
+                expr.span,
+                msg,
+                help,
+                sugg.into_owned(),
+                app,
+            );
+        }
+    }
+}
+
+fn is_boxed_slice(ty: Ty<'_>) -> bool {
+    ty.is_box() && ty.boxed_ty().is_slice()
+}
@@ -1,14 +1,17 @@
 use clippy_utils::diagnostics::span_lint_and_sugg;
-use clippy_utils::in_constant;
 use clippy_utils::msrvs::{self, Msrv};
 use clippy_utils::source::snippet_opt;
 use clippy_utils::ty::is_isize_or_usize;
+use clippy_utils::{fn_def_id, in_constant, peel_blocks};
 use rustc_errors::Applicability;
-use rustc_hir::{Expr, ExprKind};
+use rustc_hir::def::DefKind;
+use rustc_hir::def_id::DefId;
+use rustc_hir::{Expr, ExprKind, Node};
 use rustc_lint::LateContext;
 use rustc_middle::ty::{self, FloatTy, Ty};
+use rustc_target::spec::abi::Abi;
 
-use super::{utils, CAST_LOSSLESS};
+use super::{utils, CAST_LOSSLESS, CAST_LOSSLESS_ON_FFI_BOUNDARY};
 
 pub(super) fn check(
     cx: &LateContext<'_>,
@@ -46,9 +49,15 @@ pub(super) fn check(
         format!("casting `{cast_from}` to `{cast_to}` may become silently lossy if you later change the type")
     };
 
+    let lint = if is_ffi_boundary(cx, expr, cast_op) {
+        CAST_LOSSLESS_ON_FFI_BOUNDARY
+    } else {
+        CAST_LOSSLESS
+    };
+
     span_lint_and_sugg(
         cx,
-        CAST_LOSSLESS,
+        lint,
         expr.span,
         &message,
         "try",
@@ -98,3 +107,32 @@ fn should_strip_parens(cast_expr: &Expr<'_>, snip: &str) -> bool {
     }
     false
 }
+
+/// Checks if the cast value is returned from a function with a non-Rust ABI, or the cast is the
+/// return value of one.
+fn is_ffi_boundary(cx: &LateContext<'_>, expr: &Expr<'_>, cast_op: &Expr<'_>) -> bool {
+    if fn_def_id(cx, peel_blocks(cast_op)).map_or(false, |id| is_ffi_fn(cx, id)) {
+        return true;
+    }
+
+    let mut child_id = expr.hir_id;
+    for (parent_id, node) in cx.tcx.hir().parent_iter(expr.hir_id) {
+        match node {
+            Node::Expr(parent) => match parent.kind {
+                ExprKind::Ret(_) => break,
+                ExprKind::Block(..) | ExprKind::DropTemps(_) => {},
+                _ => return false,
+            },
+            Node::Block(block) if block.expr.map_or(false, |tail| tail.hir_id == child_id) => {},
+            Node::Item(_) | Node::ImplItem(_) | Node::TraitItem(_) => break,
+            _ => return false,
+        }
+        child_id = parent_id;
+    }
+    let owner = cx.tcx.hir().enclosing_body_owner(expr.hir_id);
+    is_ffi_fn(cx, owner.to_def_id())
+}
+
+fn is_ffi_fn(cx: &LateContext<'_>, id: DefId) -> bool {
+    matches!(cx.tcx.def_kind(id), DefKind::Fn | DefKind::AssocFn) && cx.tcx.fn_sig(id).skip_binder().abi() != Abi::Rust
+}
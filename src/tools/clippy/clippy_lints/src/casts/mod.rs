@@ -657,6 +657,37 @@
     "casting a known floating-point NaN into an integer"
 }
 
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for lossless casts of values passed across an FFI boundary, i.e. of the result
+    /// of a call to a function with a non-Rust ABI, or of the return value of such a function.
+    ///
+    /// ### Why is this bad?
+    /// The types of FFI values are dictated by the other side, e.g. a C header, and may change
+    /// with it. `From` conversions make widenings explicit there and stop compiling if they
+    /// become lossy, which eases auditing the boundary.
+    ///
+    /// ### Example
+    /// ```rust
+    /// extern "C" {
+    ///     fn get_count() -> u32;
+    /// }
+    ///
+    /// let count = unsafe { get_count() } as u64;
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # extern "C" {
+    /// #     fn get_count() -> u32;
+    /// # }
+    /// let count = u64::from(unsafe { get_count() });
+    /// ```
+    #[clippy::version = "1.73.0"]
+    pub CAST_LOSSLESS_ON_FFI_BOUNDARY,
+    style,
+    "lossless casts of values passed across an FFI boundary"
+}
+
 pub struct Casts {
     msrv: Msrv,
 }
@@ -691,6 +722,7 @@ pub fn new(msrv: Msrv) -> Self {
     CAST_SLICE_FROM_RAW_PARTS,
     AS_PTR_CAST_MUT,
     CAST_NAN_TO_INT,
+    CAST_LOSSLESS_ON_FFI_BOUNDARY,
 ]);
 
 impl<'tcx> LateLintPass<'tcx> for Casts {
@@ -0,0 +1,84 @@
+use clippy_utils::diagnostics::span_lint_and_help;
+use clippy_utils::source::snippet;
+use clippy_utils::{is_integer_literal, SpanlessEq};
+use rustc_hir::{BinOpKind, Expr, ExprKind};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::lint::in_external_macro;
+use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_span::sym;
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for indexing with an index which is clamped to the last element, like
+    /// `v[i.min(v.len() - 1)]` or `v[i.min(v.len().saturating_sub(1))]`.
+    ///
+    /// ### Why is this bad?
+    /// An index which is out of bounds silently reads the last element instead, which may hide a
+    /// bug. Handling the `None` of `get` makes the fallback explicit.
+    ///
+    /// ### Example
+    /// ```rust
+    /// # let v = vec![1, 2, 3];
+    /// # let i = 5;
+    /// let x = v[i.min(v.len() - 1)];
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # let v = vec![1, 2, 3];
+    /// # let i = 5;
+    /// let x = v.get(i).copied().unwrap_or(0);
+    /// ```
+    #[clippy::version = "1.73.0"]
+    pub MANUAL_SATURATING_INDEX,
+    restriction,
+    "indexing with an index clamped to the last element"
+}
+declare_lint_pass!(ClampedIndex => [MANUAL_SATURATING_INDEX]);
+
+impl<'tcx> LateLintPass<'tcx> for ClampedIndex {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) {
+        if let ExprKind::Index(indexed, index) = expr.kind
+            && !in_external_macro(cx.sess(), expr.span)
+            && let ExprKind::MethodCall(min_path, lhs, [rhs], _) = index.kind
+            && min_path.ident.name.as_str() == "min"
+            && let Some(clamped) = if is_last_index(cx, rhs, indexed) {
+                Some(lhs)
+            } else if is_last_index(cx, lhs, indexed) {
+                Some(rhs)
+            } else {
+                None
+            }
+        {
+            span_lint_and_help(
+                cx,
+                MANUAL_SATURATING_INDEX,
+                expr.span,
+                "indexing with an index clamped to the last element",
+                None,
+                &format!(
+                    "if the index can be out of bounds, consider handling that explicitly with `{}.get({})`",
+                    snippet(cx, indexed.span, ".."),
+                    snippet(cx, clamped.span, ".."),
+                ),
+            );
+        }
+    }
+}
+
+/// Matches `indexed.len() - 1` and `indexed.len().saturating_sub(1)`.
+fn is_last_index(cx: &LateContext<'_>, expr: &Expr<'_>, indexed: &Expr<'_>) -> bool {
+    let len = match expr.kind {
+        ExprKind::Binary(op, len, one) if op.node == BinOpKind::Sub && is_integer_literal(one, 1) => len,
+        ExprKind::MethodCall(path, len, [one], _)
+            if path.ident.name.as_str() == "saturating_sub" && is_integer_literal(one, 1) =>
+        {
+            len
+        },
+        _ => return false,
+    };
+    matches!(
+        len.kind,
+        ExprKind::MethodCall(path, recv, [], _)
+            if path.ident.name == sym::len && SpanlessEq::new(cx).eq_expr(recv, indexed)
+    )
+}
@@ -0,0 +1,79 @@
+use clippy_utils::diagnostics::span_lint_and_help;
+use clippy_utils::ty::is_type_diagnostic_item;
+use clippy_utils::{is_trait_method, path_to_local_id};
+use rustc_hir::{BindingAnnotation, Block, ExprKind, Local, PatKind, StmtKind};
+use rustc_lint::{LateContext, LateLintPass, LintContext};
+use rustc_middle::lint::in_external_macro;
+use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_span::sym;
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for a `Vec` collected from an iterator which is sorted right away.
+    ///
+    /// ### Why is this bad?
+    /// If the elements are only needed in order, a `BTreeSet` keeps them sorted as they are
+    /// inserted, and also removes duplicates. A `BinaryHeap` keeps duplicates and gives the
+    /// elements out in order.
+    ///
+    /// ### Known problems
+    /// A `Vec` is still the better choice when its elements are indexed, or when sorting it once
+    /// is all that's needed. The lint doesn't check how the `Vec` is used afterwards.
+    ///
+    /// ### Example
+    /// ```rust
+    /// # let words = ["b", "a", "b"];
+    /// let mut sorted: Vec<_> = words.iter().collect();
+    /// sorted.sort();
+    /// sorted.dedup();
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # use std::collections::BTreeSet;
+    /// # let words = ["b", "a", "b"];
+    /// let sorted: BTreeSet<_> = words.iter().collect();
+    /// ```
+    #[clippy::version = "1.73.0"]
+    pub NEEDLESS_COLLECT_TO_SORT,
+    nursery,
+    "collecting an iterator into a `Vec` only to sort it"
+}
+declare_lint_pass!(CollectThenSort => [NEEDLESS_COLLECT_TO_SORT]);
+
+impl<'tcx> LateLintPass<'tcx> for CollectThenSort {
+    fn check_block(&mut self, cx: &LateContext<'tcx>, block: &'tcx Block<'tcx>) {
+        for window in block.stmts.windows(2) {
+            if let [collect_stmt, sort_stmt] = window
+                && !in_external_macro(cx.sess(), collect_stmt.span)
+                && !collect_stmt.span.from_expansion()
+                && !sort_stmt.span.from_expansion()
+                && let StmtKind::Local(Local {
+                    pat,
+                    init: Some(init),
+                    els: None,
+                    ..
+                }) = collect_stmt.kind
+                && let PatKind::Binding(BindingAnnotation::MUT, vec_id, _, None) = pat.kind
+                && let ExprKind::MethodCall(collect_path, _, [], _) = init.kind
+                && collect_path.ident.name.as_str() == "collect"
+                && is_trait_method(cx, init, sym::Iterator)
+                && is_type_diagnostic_item(cx, cx.typeck_results().expr_ty(init), sym::Vec)
+                && let StmtKind::Semi(sort_call) = sort_stmt.kind
+                // Only the orderings a sorted collection can keep, not e.g. `sort_by_key`
+                && let ExprKind::MethodCall(sort_path, recv, [], _) = sort_call.kind
+                && matches!(sort_path.ident.name.as_str(), "sort" | "sort_unstable")
+                && path_to_local_id(recv, vec_id)
+            {
+                span_lint_and_help(
+                    cx,
+                    NEEDLESS_COLLECT_TO_SORT,
+                    collect_stmt.span.to(sort_stmt.span),
+                    "collecting into a `Vec` only to sort it",
+                    None,
+                    "if the elements are only needed in order, consider collecting into a `BTreeSet`, which also \
+                    removes duplicates, or a `BinaryHeap`",
+                );
+            }
+        }
+    }
+}
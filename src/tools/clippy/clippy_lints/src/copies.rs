@@ -216,7 +216,7 @@ fn contains_let(e: &Expr<'_>) -> bool {
 }
 
 fn lint_if_same_then_else(cx: &LateContext<'_>, conds: &[&Expr<'_>], blocks: &[&Block<'_>]) -> bool {
-    let mut eq = SpanlessEq::new(cx);
+    let mut eq = SpanlessEq::new(cx).eval_consts(true);
     blocks
         .array_windows::<2>()
         .enumerate()
@@ -3,7 +3,10 @@
 use clippy_utils::msrvs::{self, Msrv};
 use clippy_utils::source::{snippet_with_applicability, snippet_with_context};
 use clippy_utils::sugg::has_enclosing_paren;
-use clippy_utils::ty::{adt_and_variant_of_res, expr_sig, is_copy, peel_mid_ty_refs, ty_sig};
+use clippy_utils::ty::{
+    adt_and_variant_of_res, expr_sig, implements_trait, implements_trait_with_env, is_copy, peel_mid_ty_refs, ty_sig,
+};
+use clippy_utils::usage::local_used_after_expr;
 use clippy_utils::{
     fn_def_id, get_parent_expr, get_parent_expr_for_hir, is_lint_allowed, path_to_local, walk_to_expr_usage,
 };
@@ -24,17 +27,18 @@
 use rustc_lint::{LateContext, LateLintPass};
 use rustc_middle::mir::{Rvalue, StatementKind};
 use rustc_middle::ty::adjustment::{Adjust, Adjustment, AutoBorrow, AutoBorrowMutability};
+use rustc_middle::ty::fast_reject::{simplify_type, DeepRejectCtxt, TreatParams};
 use rustc_middle::ty::{
-    self, Binder, BoundVariableKind, ClauseKind, EarlyBinder, FnSig, GenericArgKind, List, ParamEnv, ParamTy,
-    ProjectionPredicate, Ty, TyCtxt, TypeVisitableExt, TypeckResults,
+    self, AssocKind, Binder, BoundVariableKind, ClauseKind, EarlyBinder, FnSig, GenericArgKind, List, ParamEnv,
+    ParamTy, ProjectionPredicate, Ty, TyCtxt, TypeVisitableExt, TypeckResults,
 };
 use rustc_session::{declare_tool_lint, impl_lint_pass};
 use rustc_span::symbol::sym;
 use rustc_span::{Span, Symbol};
-use rustc_trait_selection::infer::InferCtxtExt as _;
 use rustc_trait_selection::traits::query::evaluate_obligation::InferCtxtExt as _;
 use rustc_trait_selection::traits::{Obligation, ObligationCause};
 use std::collections::VecDeque;
+use std::iter;
 
 declare_clippy_lint! {
     /// ### What it does
@@ -82,6 +86,9 @@
     /// The lint cannot tell when the implementation of a trait
     /// for `&T` and `T` do different things. Removing a borrow
     /// in such a case can change the semantics of the code.
+    /// If removing the borrow from a method receiver might
+    /// call a different method, the suggestion is not applied
+    /// automatically.
     ///
     /// ### Example
     /// ```rust
@@ -885,7 +892,9 @@ fn walk_parents<'tcx>(
                             && let ty::Ref(_, sub_ty, _) = *arg_ty.kind()
                             && let subs = cx
                                 .typeck_results()
-                                .node_args_opt(parent.hir_id).map(|subs| &subs[1..]).unwrap_or_default()
+                                .node_args_opt(parent.hir_id)
+                                .map(|subs| &subs[1..cx.tcx.generics_of(trait_id).count()])
+                                .unwrap_or_default()
                             && let impl_ty = if cx.tcx.fn_sig(fn_id)
                                 .instantiate_identity()
                                 .skip_binder()
@@ -897,14 +906,7 @@ fn walk_parents<'tcx>(
                                 // Trait methods taking `self`
                                 arg_ty
                             } && impl_ty.is_ref()
-                            && let infcx = cx.tcx.infer_ctxt().build()
-                            && infcx
-                                .type_implements_trait(
-                                    trait_id,
-                                    [impl_ty.into()].into_iter().chain(subs.iter().copied()),
-                                    cx.param_env,
-                                )
-                                .must_apply_modulo_regions()
+                            && implements_trait(cx, impl_ty, trait_id, subs)
                         {
                             return Some(Position::MethodReceiverRefImpl)
                         }
@@ -1196,9 +1198,12 @@ fn needless_borrow_impl_arg_position<'tcx>(
     let mut check_reference_and_referent = |reference, referent| {
         let referent_ty = cx.typeck_results().expr_ty(referent);
 
+        // Removing the borrow moves the referent. Besides the MIR check, make sure a moved binding isn't
+        // used afterwards, e.g. when the move happens in a closure.
         if !is_copy(cx, referent_ty)
             && (referent_ty.has_significant_drop(cx.tcx, cx.param_env)
-                || !referent_used_exactly_once(cx, possible_borrowers, reference))
+                || !referent_used_exactly_once(cx, possible_borrowers, reference)
+                || path_to_local(referent).map_or(false, |local| local_used_after_expr(cx, local, reference)))
         {
             return false;
         }
@@ -1487,6 +1492,140 @@ fn ty_contains_field(ty: Ty<'_>, name: Symbol) -> bool {
     }
 }
 
+// Checks whether removing the borrows from the receiver of the method call `call`, leaving a
+// receiver of type `recv_ty`, could change which method is called.
+//
+// Method probing is emulated for the methods with the same name: each auto-deref step of the new
+// receiver is tried by value, then with an auto-borrow, and inherent methods take priority over the
+// methods of the traits in scope. Overloaded derefs are not followed, the same method is assumed to
+// be found behind them as before.
+fn receiver_method_changes<'tcx>(cx: &LateContext<'tcx>, call: &Expr<'_>, recv_ty: Ty<'tcx>) -> bool {
+    let typeck = cx.typeck_results();
+    let Some(method_id) = typeck.type_dependent_def_id(call.hir_id) else {
+        return false;
+    };
+    let name = cx.tcx.item_name(method_id);
+    let method_self_ty = cx
+        .tcx
+        .trait_of_item(method_id)
+        .map(|_| cx.tcx.erase_regions(typeck.node_args(call.hir_id).type_at(0)));
+    let traits = cx.tcx.in_scope_traits(call.hir_id).unwrap_or_default();
+
+    let mut ty = cx.tcx.erase_regions(recv_ty);
+    loop {
+        for probe_ty in [
+            ty,
+            Ty::new_imm_ref(cx.tcx, cx.tcx.lifetimes.re_erased, ty),
+            Ty::new_mut_ref(cx.tcx, cx.tcx.lifetimes.re_erased, ty),
+        ] {
+            let mut candidates = inherent_method_candidates(cx, probe_ty, name);
+            if candidates.is_empty() {
+                candidates = traits
+                    .iter()
+                    .filter_map(|candidate| trait_method_candidate(cx, probe_ty, name, candidate.def_id))
+                    .collect();
+            }
+            if !candidates.is_empty() {
+                return !matches!(*candidates, [(id, self_ty)] if id == method_id && self_ty == method_self_ty);
+            }
+        }
+        match ty.builtin_deref(true) {
+            Some(deref) => ty = deref.ty,
+            None => return false,
+        }
+    }
+}
+
+// The type a method's `self` parameter is taken as relative to the `Self` type. `None` if the
+// method is taken by value, and `Some` for methods taking `&self` or `&mut self`. Other receivers,
+// e.g. `self: Box<Self>`, are never matched by a borrow being removed and are ignored.
+fn method_self_kind<'tcx>(cx: &LateContext<'tcx>, method_id: DefId, self_ty: Ty<'tcx>) -> Option<Option<Mutability>> {
+    let input = cx.tcx.fn_sig(method_id).instantiate_identity().skip_binder().inputs()[0];
+    match *input.kind() {
+        _ if input == self_ty => Some(None),
+        ty::Ref(_, ty, mutability) if ty == self_ty => Some(Some(mutability)),
+        _ => None,
+    }
+}
+
+// Gets the `Self` type a method taking `self` in the given way would need to be called on a
+// receiver of type `probe_ty`.
+fn probed_self_ty(probe_ty: Ty<'_>, kind: Option<Mutability>) -> Option<Ty<'_>> {
+    match (kind, *probe_ty.kind()) {
+        (None, _) => Some(probe_ty),
+        (Some(mutability), ty::Ref(_, ty, probe_mutability)) if mutability == probe_mutability => Some(ty),
+        _ => None,
+    }
+}
+
+// Finds the inherent methods named `name` which are callable on a receiver of type `probe_ty`.
+fn inherent_method_candidates<'tcx>(
+    cx: &LateContext<'tcx>,
+    probe_ty: Ty<'tcx>,
+    name: Symbol,
+) -> Vec<(DefId, Option<Ty<'tcx>>)> {
+    let mut candidates = Vec::new();
+    for kind in [None, Some(Mutability::Not), Some(Mutability::Mut)] {
+        let Some(self_ty) = probed_self_ty(probe_ty, kind) else {
+            continue;
+        };
+        let impls = if let ty::Adt(adt, _) = self_ty.kind() {
+            cx.tcx.inherent_impls(adt.did())
+        } else if let Some(simplified) = simplify_type(cx.tcx, self_ty, TreatParams::AsCandidateKey) {
+            cx.tcx.incoherent_impls(simplified)
+        } else {
+            continue;
+        };
+        let drcx = DeepRejectCtxt {
+            treat_obligation_params: TreatParams::ForLookup,
+        };
+        for &impl_id in impls {
+            let impl_self_ty = cx.tcx.type_of(impl_id).instantiate_identity();
+            if !drcx.types_may_unify(self_ty, impl_self_ty) {
+                continue;
+            }
+            candidates.extend(
+                cx.tcx
+                    .associated_items(impl_id)
+                    .filter_by_name_unhygienic(name)
+                    .filter(|item| {
+                        item.kind == AssocKind::Fn
+                            && item.fn_has_self_parameter
+                            && method_self_kind(cx, item.def_id, impl_self_ty) == Some(kind)
+                    })
+                    .map(|item| (item.def_id, None)),
+            );
+        }
+    }
+    candidates
+}
+
+// Gets the method named `name` of the trait `trait_id` if it's callable on a receiver of type
+// `probe_ty`, along with the `Self` type it's called with.
+fn trait_method_candidate<'tcx>(
+    cx: &LateContext<'tcx>,
+    probe_ty: Ty<'tcx>,
+    name: Symbol,
+    trait_id: DefId,
+) -> Option<(DefId, Option<Ty<'tcx>>)> {
+    let method = cx
+        .tcx
+        .associated_items(trait_id)
+        .filter_by_name_unhygienic(name)
+        .find(|item| item.kind == AssocKind::Fn && item.fn_has_self_parameter)?;
+    let kind = method_self_kind(cx, method.def_id, cx.tcx.types.self_param)?;
+    let self_ty = probed_self_ty(probe_ty, kind)?;
+    let trait_params = cx.tcx.generics_of(trait_id).count() - 1;
+    implements_trait_with_env(
+        cx.tcx,
+        cx.param_env,
+        self_ty,
+        trait_id,
+        iter::repeat(None).take(trait_params),
+    )
+    .then_some((method.def_id, Some(self_ty)))
+}
+
 #[expect(clippy::needless_pass_by_value, clippy::too_many_lines)]
 fn report<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>, state: State, data: StateData) {
     match state {
@@ -1548,8 +1687,20 @@ fn report<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>, state: State, data
             );
         },
         State::DerefedBorrow(state) => {
-            let mut app = Applicability::MachineApplicable;
             let snip_expr = state.snip_expr.map_or(expr, |hir_id| cx.tcx.hir().expect_expr(hir_id));
+            let changed_method = if let Position::MethodReceiver = data.position
+                && let Some(call) = get_parent_expr_for_hir(cx, data.hir_id)
+                && receiver_method_changes(cx, call, cx.typeck_results().expr_ty(snip_expr))
+            {
+                cx.typeck_results().type_dependent_def_id(call.hir_id)
+            } else {
+                None
+            };
+            let mut app = if changed_method.is_some() {
+                Applicability::MaybeIncorrect
+            } else {
+                Applicability::MachineApplicable
+            };
             let (snip, snip_is_macro) = snippet_with_context(cx, snip_expr.span, data.span.ctxt(), "..", &mut app);
             span_lint_hir_and_then(cx, NEEDLESS_BORROW, data.hir_id, data.span, state.msg, |diag| {
                 let calls_field = matches!(expr.kind, ExprKind::Field(..)) && matches!(data.position, Position::Callee);
@@ -1562,6 +1713,12 @@ fn report<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>, state: State, data
                     snip.into()
                 };
                 diag.span_suggestion(data.span, "change this to", sugg, app);
+                if let Some(method_id) = changed_method {
+                    diag.note(format!(
+                        "without the borrow, a different `{}` method may be called",
+                        cx.tcx.item_name(method_id)
+                    ));
+                }
             });
         },
         State::ExplicitDeref { mutability } => {
@@ -171,6 +171,7 @@ fn check_variant(cx: &LateContext<'_>, threshold: u64, def: &EnumDef<'_>, item_n
     let mut pre = camel_case_split(first);
     let mut post = pre.clone();
     post.reverse();
+    let mut min_words = pre.len();
     for var in def.variants {
         check_enum_start(cx, item_name, var);
         check_enum_end(cx, item_name, var);
@@ -180,6 +181,7 @@ fn check_variant(cx: &LateContext<'_>, threshold: u64, def: &EnumDef<'_>, item_n
         if variant_split.len() == 1 {
             return;
         }
+        min_words = min_words.min(variant_split.len());
 
         pre = pre
             .iter()
@@ -194,6 +196,14 @@ fn check_variant(cx: &LateContext<'_>, threshold: u64, def: &EnumDef<'_>, item_n
             .map(|e| *e.0)
             .collect();
     }
+    // A prefix or postfix which is the whole name of one of the variants, e.g. `Error` and
+    // `ErrorKind`, can't be removed from all of them.
+    if pre.len() == min_words {
+        pre.clear();
+    }
+    if post.len() == min_words {
+        post.clear();
+    }
     let (what, value) = match (have_no_extra_prefix(&pre), post.is_empty()) {
         (true, true) => return,
         (false, _) => ("pre", pre.join("")),
@@ -0,0 +1,97 @@
+use clippy_utils::diagnostics::span_lint_and_help;
+use rustc_hir::{Expr, ExprKind, MatchSource};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_session::{declare_tool_lint, impl_lint_pass};
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for `match` expressions which are nested within each other beyond a certain depth.
+    ///
+    /// The depth can be configured with `excessive-match-nesting-threshold` in the clippy.toml file.
+    ///
+    /// ### Why is this bad?
+    /// Each level of nesting adds another set of arms to keep track of, which makes the
+    /// innermost arms hard to follow.
+    ///
+    /// ### Example
+    /// ```rust
+    /// # fn f(a: Option<u8>, b: Option<u8>, c: Option<u8>, d: Option<u8>) -> u8 {
+    /// match a {
+    ///     Some(a) => match b {
+    ///         Some(b) => match c {
+    ///             Some(c) => match d {
+    ///                 Some(d) => a + b + c + d,
+    ///                 None => 0,
+    ///             },
+    ///             None => 0,
+    ///         },
+    ///         None => 0,
+    ///     },
+    ///     None => 0,
+    /// }
+    /// # }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # fn f(a: Option<u8>, b: Option<u8>, c: Option<u8>, d: Option<u8>) -> u8 {
+    /// match (a, b, c, d) {
+    ///     (Some(a), Some(b), Some(c), Some(d)) => a + b + c + d,
+    ///     _ => 0,
+    /// }
+    /// # }
+    /// ```
+    #[clippy::version = "1.73.0"]
+    pub EXCESSIVE_NESTING_IN_MATCH_ARM,
+    restriction,
+    "`match` expressions nested beyond a certain depth"
+}
+impl_lint_pass!(ExcessiveMatchNesting => [EXCESSIVE_NESTING_IN_MATCH_ARM]);
+
+pub struct ExcessiveMatchNesting {
+    threshold: u64,
+    depth: u64,
+}
+
+impl ExcessiveMatchNesting {
+    pub fn new(threshold: u64) -> Self {
+        Self { threshold, depth: 0 }
+    }
+}
+
+impl<'tcx> LateLintPass<'tcx> for ExcessiveMatchNesting {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) {
+        if let Some(scrutinee) = user_written_match(expr) {
+            self.depth += 1;
+            // Only the outermost `match` beyond the threshold is linted, the ones nested in it
+            // would be fixed along with it.
+            if self.depth == self.threshold + 1 {
+                span_lint_and_help(
+                    cx,
+                    EXCESSIVE_NESTING_IN_MATCH_ARM,
+                    expr.span.with_hi(scrutinee.span.hi()),
+                    "this `match` is nested too deeply",
+                    None,
+                    "consider moving it into a helper function or matching on a tuple of the values instead",
+                );
+            }
+        }
+    }
+
+    fn check_expr_post(&mut self, _: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) {
+        if user_written_match(expr).is_some() {
+            self.depth -= 1;
+        }
+    }
+}
+
+/// Returns the scrutinee of a `match` expression written in the source, ignoring those from
+/// desugarings and macro expansions.
+fn user_written_match<'tcx>(expr: &'tcx Expr<'tcx>) -> Option<&'tcx Expr<'tcx>> {
+    if let ExprKind::Match(scrutinee, _, MatchSource::Normal) = expr.kind
+        && !expr.span.from_expansion()
+    {
+        Some(scrutinee)
+    } else {
+        None
+    }
+}
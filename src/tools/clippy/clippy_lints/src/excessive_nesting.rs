@@ -17,6 +17,11 @@
     /// ### Why is this bad?
     /// It can severely hinder readability.
     ///
+    /// ### Known problems
+    /// Items defined inside a function, such as nested functions, keep counting from the
+    /// nesting level of the enclosing function, since they add to its indentation. Blocks
+    /// coming from macro expansions are not counted.
+    ///
     /// ### Example
     /// An example clippy.toml configuration:
     /// ```toml
@@ -78,7 +83,10 @@ pub fn check_node_id(&self, cx: &EarlyContext<'_>, span: Span, node_id: NodeId)
                 span,
                 "this block is too nested",
                 None,
-                "try refactoring your code to minimize nesting",
+                &format!(
+                    "try refactoring your code to nest at most {} levels deep",
+                    self.excessive_nesting_threshold
+                ),
             );
         }
     }
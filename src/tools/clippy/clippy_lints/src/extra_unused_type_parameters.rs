@@ -1,4 +1,4 @@
-use clippy_utils::diagnostics::{span_lint_and_help, span_lint_and_then};
+use clippy_utils::diagnostics::{span_lint_and_help, span_lint_and_multipart_sugg};
 use clippy_utils::{is_from_proc_macro, trait_ref_of_method};
 use rustc_data_structures::fx::{FxHashMap, FxHashSet};
 use rustc_errors::Applicability;
@@ -116,9 +116,15 @@ fn emit_help(&self, spans: Vec<Span>, msg: &str, help: &'static str) {
 
     fn emit_sugg(&self, spans: Vec<Span>, msg: &str, help: &'static str) {
         let suggestions: Vec<(Span, String)> = spans.iter().copied().zip(std::iter::repeat(String::new())).collect();
-        span_lint_and_then(self.cx, EXTRA_UNUSED_TYPE_PARAMETERS, spans, msg, |diag| {
-            diag.multipart_suggestion(help, suggestions, Applicability::MachineApplicable);
-        });
+        span_lint_and_multipart_sugg(
+            self.cx,
+            EXTRA_UNUSED_TYPE_PARAMETERS,
+            spans,
+            msg,
+            help,
+            suggestions,
+            Applicability::MachineApplicable,
+        );
     }
 
     fn emit_lint(&self) {
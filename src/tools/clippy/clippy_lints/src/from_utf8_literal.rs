@@ -0,0 +1,115 @@
+use clippy_utils::diagnostics::span_lint_and_sugg;
+use clippy_utils::source::snippet_opt;
+use clippy_utils::ty::is_type_diagnostic_item;
+use clippy_utils::{is_expr_path_def_path, is_path_diagnostic_item, paths};
+use rustc_ast::ast::{LitKind, StrStyle};
+use rustc_errors::Applicability;
+use rustc_hir::{Expr, ExprKind};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_span::{sym, Span};
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for `String::from_utf8` and `str::from_utf8` called on a byte string literal
+    /// whose result is then unwrapped.
+    ///
+    /// ### Why is this bad?
+    /// If the literal is valid UTF-8 the conversion can't fail, so it can be written as a
+    /// string literal instead, without the runtime check and the `unwrap`.
+    ///
+    /// ### Example
+    /// ```rust
+    /// let s = std::str::from_utf8(b"hello").unwrap();
+    /// let owned = String::from_utf8(b"hello".to_vec()).unwrap();
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// let s = "hello";
+    /// let owned = String::from("hello");
+    /// ```
+    #[clippy::version = "1.73.0"]
+    pub STRING_FROM_UTF8_UNWRAP_ON_LITERAL,
+    complexity,
+    "unwrapping the UTF-8 conversion of a byte string literal instead of using a string literal"
+}
+declare_lint_pass!(FromUtf8Literal => [STRING_FROM_UTF8_UNWRAP_ON_LITERAL]);
+
+impl<'tcx> LateLintPass<'tcx> for FromUtf8Literal {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
+        if let ExprKind::MethodCall(path, recv, args, _) = expr.kind
+            && matches!((path.ident.name, args), (sym::unwrap, []) | (sym::expect, [_]))
+            && !expr.span.from_expansion()
+            && let ExprKind::Call(func, [arg]) = recv.kind
+            && is_type_diagnostic_item(cx, cx.typeck_results().expr_ty(recv), sym::Result)
+        {
+            let (lit, owned) = if is_path_diagnostic_item(cx, func, sym::str_from_utf8) {
+                (arg, false)
+            } else if is_expr_path_def_path(cx, func, &paths::STRING_FROM_UTF8)
+                && let ExprKind::MethodCall(path, lit, [], _) = arg.kind
+                && path.ident.as_str() == "to_vec"
+            {
+                (lit, true)
+            } else {
+                return;
+            };
+
+            if let ExprKind::Lit(lit) = lit.kind
+                && let LitKind::ByteStr(ref bytes, style) = lit.node
+                // Non UTF-8 literals would make the conversion fail at runtime.
+                && let Ok(string) = std::str::from_utf8(bytes)
+                && let Some(str_lit) = str_literal(cx, lit.span, style, string)
+            {
+                let (msg, sugg) = if owned {
+                    (
+                        "converting a byte string literal to a `String`",
+                        format!("String::from({str_lit})"),
+                    )
+                } else {
+                    ("converting a byte string literal to a `&str`", str_lit)
+                };
+                span_lint_and_sugg(
+                    cx,
+                    STRING_FROM_UTF8_UNWRAP_ON_LITERAL,
+                    expr.span,
+                    msg,
+                    "use a string literal instead",
+                    sugg,
+                    Applicability::MachineApplicable,
+                );
+            }
+        }
+    }
+}
+
+/// Writes the byte string literal at `span` as a string literal. The original spelling,
+/// including escapes, is kept unless it uses `\x` escapes for non-ASCII bytes, which aren't
+/// allowed in string literals.
+fn str_literal(cx: &LateContext<'_>, span: Span, style: StrStyle, string: &str) -> Option<String> {
+    let snip = snippet_opt(cx, span)?;
+    let unprefixed = snip.strip_prefix('b')?;
+    if matches!(style, StrStyle::Raw(_)) || !has_non_ascii_escape(unprefixed) {
+        Some(unprefixed.to_owned())
+    } else {
+        Some(format!("{string:?}"))
+    }
+}
+
+fn has_non_ascii_escape(lit: &str) -> bool {
+    let mut chars = lit.chars();
+    while let Some(c) = chars.next() {
+        if c == '\\' {
+            match chars.next() {
+                Some('x') => {
+                    let hex: String = chars.by_ref().take(2).collect();
+                    if u8::from_str_radix(&hex, 16).map_or(true, |b| !b.is_ascii()) {
+                        return true;
+                    }
+                },
+                Some(_) => {},
+                None => return false,
+            }
+        }
+    }
+    false
+}
@@ -0,0 +1,84 @@
+use clippy_utils::diagnostics::span_lint_and_sugg;
+use clippy_utils::{is_lint_allowed, is_trait_method};
+use clippy_utils::source::snippet_with_applicability;
+use clippy_utils::ty::is_type_diagnostic_item;
+use rustc_errors::Applicability;
+use rustc_hir::{Expr, ExprKind};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::ty;
+use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_span::sym;
+
+use crate::methods::NEEDLESS_COLLECT;
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for iterators which are collected into a `HashSet` only to call `contains`
+    /// on the result once.
+    ///
+    /// ### Why is this bad?
+    /// Building the set hashes and allocates every element, while a single lookup only needs
+    /// to compare the elements until a match is found.
+    ///
+    /// ### Known problems
+    /// The suggested comparison uses `PartialEq` rather than `Hash` and `Eq`, which may behave
+    /// differently for types implementing them inconsistently.
+    ///
+    /// This is not linted where `needless_collect` is enabled, since that lint already covers it.
+    ///
+    /// ### Example
+    /// ```rust
+    /// # use std::collections::HashSet;
+    /// # let names = ["a", "b"];
+    /// let found = names.iter().collect::<HashSet<_>>().contains(&&"b");
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # let names = ["a", "b"];
+    /// let found = names.iter().any(|x| x == &"b");
+    /// ```
+    #[clippy::version = "1.73.0"]
+    pub NEEDLESS_COLLECT_INTO_HASHSET_FOR_CONTAINS,
+    perf,
+    "collecting an iterator into a `HashSet` just to call `contains` once"
+}
+declare_lint_pass!(HashsetSingleContains => [NEEDLESS_COLLECT_INTO_HASHSET_FOR_CONTAINS]);
+
+impl<'tcx> LateLintPass<'tcx> for HashsetSingleContains {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
+        // The set is only used by this call if it's collected inline; a bound set may be used
+        // for further lookups.
+        if let ExprKind::MethodCall(path, collect_expr, [arg], _) = expr.kind
+            && path.ident.name == sym!(contains)
+            && !expr.span.from_expansion()
+            // `needless_collect` reports every inline `collect` followed by `contains`.
+            && is_lint_allowed(cx, NEEDLESS_COLLECT, expr.hir_id)
+            && let ExprKind::MethodCall(collect_path, _, [], _) = collect_expr.kind
+            && collect_path.ident.name == sym!(collect)
+            && is_trait_method(cx, collect_expr, sym::Iterator)
+            && let set_ty = cx.typeck_results().expr_ty(collect_expr)
+            && is_type_diagnostic_item(cx, set_ty, sym::HashSet)
+            && let ty::Adt(_, args) = set_ty.kind()
+            // Lookups of a borrowed form of the element, e.g. a `&str` in a set of `String`s, compare
+            // differently.
+            && let ty::Ref(_, search_ty, _) = cx.typeck_results().expr_ty(arg).kind()
+            && *search_ty == args.type_at(0)
+        {
+            let mut app = Applicability::MaybeIncorrect;
+            let target = if let ExprKind::AddrOf(_, _, target) = arg.kind {
+                snippet_with_applicability(cx, target.span, "..", &mut app).into_owned()
+            } else {
+                format!("*{}", snippet_with_applicability(cx, arg.span, "..", &mut app))
+            };
+            span_lint_and_sugg(
+                cx,
+                NEEDLESS_COLLECT_INTO_HASHSET_FOR_CONTAINS,
+                collect_path.ident.span.with_hi(expr.span.hi()),
+                "collecting into a `HashSet` to call `contains` once",
+                "search the iterator instead",
+                format!("any(|x| x == {target})"),
+                app,
+            );
+        }
+    }
+}
@@ -0,0 +1,85 @@
+use clippy_utils::diagnostics::span_lint_and_help;
+use clippy_utils::source::snippet;
+use clippy_utils::ty::is_type_diagnostic_item;
+use clippy_utils::usage::is_potentially_mutated;
+use clippy_utils::visitors::for_each_expr;
+use clippy_utils::{higher, path_to_local, path_to_local_id};
+use core::ops::ControlFlow;
+use rustc_hir::{Expr, ExprKind, HirId};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::lint::in_external_macro;
+use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_span::{sym, Span};
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for `if` expressions guarded by `opt.is_some()` whose body calls
+    /// `opt.unwrap()` on the same `Option`.
+    ///
+    /// ### Why is this bad?
+    /// The `unwrap` can never fail, and `if let Some(..)` binds the value directly
+    /// without a redundant check.
+    ///
+    /// ### Example
+    /// ```rust
+    /// # let opt = Some(1);
+    /// if opt.is_some() {
+    ///     let x = opt.unwrap();
+    ///     println!("{x}");
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # let opt = Some(1);
+    /// if let Some(x) = opt {
+    ///     println!("{x}");
+    /// }
+    /// ```
+    #[clippy::version = "1.73.0"]
+    pub REDUNDANT_IF_LET_SOME_ON_OPTION_IS_SOME,
+    pedantic,
+    "checking `is_some()` and then calling `unwrap()` on the same `Option`"
+}
+declare_lint_pass!(IfSomeUnwrap => [REDUNDANT_IF_LET_SOME_ON_OPTION_IS_SOME]);
+
+impl<'tcx> LateLintPass<'tcx> for IfSomeUnwrap {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) {
+        if let Some(higher::If { cond, then, .. }) = higher::If::hir(expr)
+            && !in_external_macro(cx.sess(), expr.span)
+            && !expr.span.from_expansion()
+            && let ExprKind::MethodCall(path, recv, [], _) = cond.kind
+            && path.ident.name == sym!(is_some)
+            && let Some(local_id) = path_to_local(recv)
+            && is_type_diagnostic_item(cx, cx.typeck_results().expr_ty(recv), sym::Option)
+            && let Some(unwrap_span) = find_unwrap(then, local_id)
+            // `unwrap` is only guaranteed to succeed if the `Option` is left untouched.
+            && !is_potentially_mutated(local_id, then, cx)
+        {
+            span_lint_and_help(
+                cx,
+                REDUNDANT_IF_LET_SOME_ON_OPTION_IS_SOME,
+                cond.span,
+                "checking `is_some()` before calling `unwrap()` on the same `Option`",
+                Some(unwrap_span),
+                &format!(
+                    "use `if let Some(..) = {}` and the bound value instead of this `unwrap()`",
+                    snippet(cx, recv.span, "..")
+                ),
+            );
+        }
+    }
+}
+
+/// Finds the first `local.unwrap()` call in `body`.
+fn find_unwrap<'tcx>(body: &'tcx Expr<'tcx>, local_id: HirId) -> Option<Span> {
+    for_each_expr(body, |e| {
+        if let ExprKind::MethodCall(path, recv, [], _) = e.kind
+            && path.ident.name == sym::unwrap
+            && path_to_local_id(recv, local_id)
+        {
+            ControlFlow::Break(e.span)
+        } else {
+            ControlFlow::Continue(())
+        }
+    })
+}
@@ -1,10 +1,10 @@
 use std::borrow::Cow;
 use std::collections::BTreeMap;
 
-use rustc_errors::Diagnostic;
+use rustc_errors::{Applicability, Diagnostic};
 use rustc_hir as hir;
 use rustc_hir::intravisit::{walk_body, walk_expr, walk_inf, walk_ty, Visitor};
-use rustc_hir::{Body, Expr, ExprKind, GenericArg, Item, ItemKind, QPath, TyKind};
+use rustc_hir::{Body, Expr, ExprKind, GenericArg, Generics, Item, ItemKind, QPath, TyKind};
 use rustc_hir_analysis::hir_ty_to_ty;
 use rustc_lint::{LateContext, LateLintPass, LintContext};
 use rustc_middle::hir::nested_filter;
@@ -14,9 +14,7 @@
 use rustc_span::source_map::Span;
 use rustc_span::symbol::sym;
 
-use if_chain::if_chain;
-
-use clippy_utils::diagnostics::{multispan_sugg, span_lint_and_then};
+use clippy_utils::diagnostics::{multispan_sugg_with_applicability, span_lint_and_then};
 use clippy_utils::source::{snippet, snippet_opt};
 use clippy_utils::ty::is_type_diagnostic_item;
 
@@ -67,47 +65,60 @@ fn check_item(&mut self, cx: &LateContext<'tcx>, item: &'tcx Item<'_>) {
         use rustc_span::BytePos;
 
         fn suggestion(
-            cx: &LateContext<'_>,
             diag: &mut Diagnostic,
-            generics_span: Span,
+            generics: &Generics<'_>,
             generics_suggestion_span: Span,
             target: &ImplicitHasherType<'_>,
             vis: ImplicitHasherConstructorVisitor<'_, '_, '_>,
+            is_only_target: bool,
         ) {
-            let generics_snip = snippet(cx, generics_span, "");
-            // trim `<` `>`
-            let generics_snip = if generics_snip.is_empty() {
-                ""
+            // The suggestions can only be applied as-is if they are the only change to the item, the new
+            // type parameter doesn't clash with an existing one and every constructor could be rewritten.
+            let applicability = if is_only_target
+                && !vis.has_unhandled_constructor
+                && !generics
+                    .params
+                    .iter()
+                    .any(|param| param.name.ident().name.as_str() == "S")
+            {
+                Applicability::MachineApplicable
             } else {
-                &generics_snip[1..generics_snip.len() - 1]
+                Applicability::MaybeIncorrect
             };
 
-            multispan_sugg(
-                diag,
-                "consider adding a type parameter",
-                vec![
-                    (
-                        generics_suggestion_span,
-                        format!(
-                            "<{generics_snip}{}S: ::std::hash::BuildHasher{}>",
-                            if generics_snip.is_empty() { "" } else { ", " },
-                            if vis.suggestions.is_empty() {
-                                ""
-                            } else {
-                                // request users to add `Default` bound so that generic constructors can be used
-                                " + Default"
-                            },
-                        ),
-                    ),
-                    (
-                        target.span(),
-                        format!("{}<{}, S>", target.type_name(), target.type_arguments(),),
-                    ),
-                ],
-            );
+            let bound = if vis.needs_default {
+                // request users to add `Default` bound so that generic constructors can be used
+                "::std::hash::BuildHasher + Default"
+            } else {
+                "::std::hash::BuildHasher"
+            };
+
+            let mut sugg = Vec::new();
+            // Keep the bound next to the existing ones if there is a where clause.
+            let param = if generics.has_where_clause_predicates {
+                sugg.push((generics.tail_span_for_predicate_suggestion(), format!(", S: {bound}")));
+                "S".to_owned()
+            } else {
+                format!("S: {bound}")
+            };
+            if let Some(span) = generics.span_for_param_suggestion() {
+                sugg.push((span, format!(", {param}")));
+            } else {
+                sugg.push((generics_suggestion_span, format!("<{param}>")));
+            }
+            sugg.push((
+                target.span(),
+                format!("{}<{}, S>", target.type_name(), target.type_arguments()),
+            ));
+            multispan_sugg_with_applicability(diag, "consider adding a type parameter", applicability, sugg);
 
             if !vis.suggestions.is_empty() {
-                multispan_sugg(diag, "...and use generic constructor", vis.suggestions);
+                multispan_sugg_with_applicability(
+                    diag,
+                    "...and use generic constructor",
+                    applicability,
+                    vis.suggestions,
+                );
             }
         }
 
@@ -119,6 +130,7 @@ fn suggestion(
             ItemKind::Impl(impl_) => {
                 let mut vis = ImplicitHasherTypeVisitor::new(cx);
                 vis.visit_ty(impl_.self_ty);
+                let is_only_target = vis.found.len() == 1;
 
                 for target in &vis.found {
                     if item.span.ctxt() != target.span().ctxt() {
@@ -149,7 +161,14 @@ fn suggestion(
                             target.type_name()
                         ),
                         move |diag| {
-                            suggestion(cx, diag, impl_.generics.span, generics_suggestion_span, target, ctr_vis);
+                            suggestion(
+                                diag,
+                                impl_.generics,
+                                generics_suggestion_span,
+                                target,
+                                ctr_vis,
+                                is_only_target,
+                            );
                         },
                     );
                 }
@@ -157,48 +176,56 @@ fn suggestion(
             ItemKind::Fn(ref sig, generics, body_id) => {
                 let body = cx.tcx.hir().body(body_id);
 
+                let mut vis = ImplicitHasherTypeVisitor::new(cx);
                 for ty in sig.decl.inputs {
-                    let mut vis = ImplicitHasherTypeVisitor::new(cx);
                     vis.visit_ty(ty);
+                }
+                let is_only_target = vis.found.len() == 1;
 
-                    for target in &vis.found {
-                        if in_external_macro(cx.sess(), generics.span) {
-                            continue;
-                        }
-                        let generics_suggestion_span = generics.span.substitute_dummy({
-                            let pos = snippet_opt(
-                                cx,
-                                Span::new(
-                                    item.span.lo(),
-                                    body.params[0].pat.span.lo(),
-                                    item.span.ctxt(),
-                                    item.span.parent(),
-                                ),
-                            )
-                            .and_then(|snip| {
-                                let i = snip.find("fn")?;
-                                Some(item.span.lo() + BytePos((i + snip[i..].find('(')?) as u32))
-                            })
-                            .expect("failed to create span for type parameters");
-                            Span::new(pos, pos, item.span.ctxt(), item.span.parent())
-                        });
-
-                        let mut ctr_vis = ImplicitHasherConstructorVisitor::new(cx, target);
-                        ctr_vis.visit_body(body);
-
-                        span_lint_and_then(
+                for target in &vis.found {
+                    if in_external_macro(cx.sess(), generics.span) {
+                        continue;
+                    }
+                    let generics_suggestion_span = generics.span.substitute_dummy({
+                        let pos = snippet_opt(
                             cx,
-                            IMPLICIT_HASHER,
-                            target.span(),
-                            &format!(
-                                "parameter of type `{}` should be generalized over different hashers",
-                                target.type_name()
+                            Span::new(
+                                item.span.lo(),
+                                body.params[0].pat.span.lo(),
+                                item.span.ctxt(),
+                                item.span.parent(),
                             ),
-                            move |diag| {
-                                suggestion(cx, diag, generics.span, generics_suggestion_span, target, ctr_vis);
-                            },
-                        );
-                    }
+                        )
+                        .and_then(|snip| {
+                            let i = snip.find("fn")?;
+                            Some(item.span.lo() + BytePos((i + snip[i..].find('(')?) as u32))
+                        })
+                        .expect("failed to create span for type parameters");
+                        Span::new(pos, pos, item.span.ctxt(), item.span.parent())
+                    });
+
+                    let mut ctr_vis = ImplicitHasherConstructorVisitor::new(cx, target);
+                    ctr_vis.visit_body(body);
+
+                    span_lint_and_then(
+                        cx,
+                        IMPLICIT_HASHER,
+                        target.span(),
+                        &format!(
+                            "parameter of type `{}` should be generalized over different hashers",
+                            target.type_name()
+                        ),
+                        move |diag| {
+                            suggestion(
+                                diag,
+                                generics,
+                                generics_suggestion_span,
+                                target,
+                                ctr_vis,
+                                is_only_target,
+                            );
+                        },
+                    );
                 }
             },
             _ => {},
@@ -315,6 +342,11 @@ struct ImplicitHasherConstructorVisitor<'a, 'b, 'tcx> {
     maybe_typeck_results: Option<&'tcx TypeckResults<'tcx>>,
     target: &'b ImplicitHasherType<'tcx>,
     suggestions: BTreeMap<Span, String>,
+    /// Whether the hasher has to implement `Default`, either for the rewritten constructors or for
+    /// `collect`.
+    needs_default: bool,
+    /// Whether a constructor of the target type was found which can't be rewritten.
+    has_unhandled_constructor: bool,
 }
 
 impl<'a, 'b, 'tcx> ImplicitHasherConstructorVisitor<'a, 'b, 'tcx> {
@@ -324,6 +356,8 @@ fn new(cx: &'a LateContext<'tcx>, target: &'b ImplicitHasherType<'tcx>) -> Self
             maybe_typeck_results: cx.maybe_typeck_results(),
             target,
             suggestions: BTreeMap::new(),
+            needs_default: false,
+            has_unhandled_constructor: false,
         }
     }
 }
@@ -338,44 +372,48 @@ fn visit_body(&mut self, body: &'tcx Body<'_>) {
     }
 
     fn visit_expr(&mut self, e: &'tcx Expr<'_>) {
-        if_chain! {
-            if let ExprKind::Call(fun, args) = e.kind;
-            if let ExprKind::Path(QPath::TypeRelative(ty, method)) = fun.kind;
-            if let TyKind::Path(QPath::Resolved(None, ty_path)) = ty.kind;
-            if let Some(ty_did) = ty_path.res.opt_def_id();
-            then {
-                if self.target.ty() != self.maybe_typeck_results.unwrap().expr_ty(e) {
-                    return;
-                }
+        if self.target.ty() != self.maybe_typeck_results.unwrap().expr_ty(e) {
+            walk_expr(self, e);
+            return;
+        }
 
-                if self.cx.tcx.is_diagnostic_item(sym::HashMap, ty_did) {
-                    if method.ident.name == sym::new {
-                        self.suggestions
-                            .insert(e.span, "HashMap::default()".to_string());
-                    } else if method.ident.name == sym!(with_capacity) {
+        match e.kind {
+            ExprKind::Call(fun, args) if let ExprKind::Path(QPath::TypeRelative(ty, method)) = fun.kind => {
+                // The type is named as written, which may be through a `use` alias, but without its
+                // generic arguments, as they would fix the hasher to the default one
+                let type_name = if let TyKind::Path(QPath::Resolved(None, ty_path)) = ty.kind
+                    && let Some(ty_did) = ty_path.res.opt_def_id()
+                    && (self.cx.tcx.is_diagnostic_item(sym::HashMap, ty_did)
+                        || self.cx.tcx.is_diagnostic_item(sym::HashSet, ty_did))
+                    && let Some(last) = ty_path.segments.last()
+                {
+                    snippet_opt(self.cx, ty_path.span.with_hi(last.ident.span.hi()))
+                } else {
+                    None
+                };
+
+                match (type_name, args) {
+                    (Some(type_name), []) if method.ident.name == sym::new => {
+                        self.suggestions.insert(e.span, format!("{type_name}::default()"));
+                    },
+                    (Some(type_name), [capacity]) if method.ident.name == sym!(with_capacity) => {
                         self.suggestions.insert(
                             e.span,
                             format!(
-                                "HashMap::with_capacity_and_hasher({}, Default::default())",
-                                snippet(self.cx, args[0].span, "capacity"),
+                                "{type_name}::with_capacity_and_hasher({}, Default::default())",
+                                snippet(self.cx, capacity.span, "capacity"),
                             ),
                         );
-                    }
-                } else if self.cx.tcx.is_diagnostic_item(sym::HashSet, ty_did) {
-                    if method.ident.name == sym::new {
-                        self.suggestions
-                            .insert(e.span, "HashSet::default()".to_string());
-                    } else if method.ident.name == sym!(with_capacity) {
-                        self.suggestions.insert(
-                            e.span,
-                            format!(
-                                "HashSet::with_capacity_and_hasher({}, Default::default())",
-                                snippet(self.cx, args[0].span, "capacity"),
-                            ),
-                        );
-                    }
+                    },
+                    // e.g. `Self::new()` or `HashMap::from_iter(..)`
+                    _ => self.has_unhandled_constructor = true,
                 }
-            }
+                self.needs_default = true;
+            },
+            ExprKind::MethodCall(method, ..) if method.ident.name == sym!(collect) => self.needs_default = true,
+            // e.g. `make_map()`, which still returns the type with the default hasher
+            ExprKind::Call(..) | ExprKind::MethodCall(..) => self.has_unhandled_constructor = true,
+            _ => {},
         }
 
         walk_expr(self, e);
@@ -1,18 +1,18 @@
 use clippy_utils::consts::{constant, Constant};
 use clippy_utils::diagnostics::span_lint_and_sugg;
-use clippy_utils::get_parent_expr;
 use clippy_utils::source::snippet_with_context;
+use clippy_utils::{get_parent_expr, peel_blocks_with_stmt};
 use if_chain::if_chain;
-use rustc_ast::ast::{LitIntType, LitKind};
 use rustc_errors::Applicability;
-use rustc_hir::{BinOpKind, Block, Expr, ExprKind, Stmt, StmtKind};
+use rustc_hir::{BinOpKind, Block, Expr, ExprKind};
 use rustc_lint::{LateContext, LateLintPass};
 use rustc_middle::ty::{Int, IntTy, Ty, Uint, UintTy};
 use rustc_session::{declare_lint_pass, declare_tool_lint};
 
 declare_clippy_lint! {
     /// ### What it does
-    /// Checks for implicit saturating addition.
+    /// Checks for implicit saturating addition, like `if u != u32::MAX { u += 1 }` or
+    /// `if u <= u32::MAX - n { u += n }` with a constant `n`.
     ///
     /// ### Why is this bad?
     /// The built-in function is more readable and may be faster.
@@ -44,35 +44,41 @@ fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) {
             if let ExprKind::If(cond, then, None) = expr.kind;
             if let ExprKind::DropTemps(expr1) = cond.kind;
             if let Some((c, op_node, l)) = get_const(cx, expr1);
-            if let BinOpKind::Ne | BinOpKind::Lt = op_node;
-            if let ExprKind::Block(block, None) = then.kind;
-            if let Block {
-                stmts:
-                    [Stmt
-                        { kind: StmtKind::Expr(ex) | StmtKind::Semi(ex), .. }],
-                        expr: None, ..} |
-                        Block { stmts: [], expr: Some(ex), ..} = block;
+            if let ExprKind::Block(Block { expr: None, .. }, None) = then.kind;
+            let ex = peel_blocks_with_stmt(then);
             if let ExprKind::AssignOp(op1, target, value) = ex.kind;
             let ty = cx.typeck_results().expr_ty(target);
-            if Some(c) == get_int_max(ty);
+            if let Some(max) = get_int_max(ty);
             let ctxt = expr.span.ctxt();
             if ex.span.ctxt() == ctxt;
             if expr1.span.ctxt() == ctxt;
             if clippy_utils::SpanlessEq::new(cx).eq_expr(l, target);
             if BinOpKind::Add == op1.node;
-            if let ExprKind::Lit(lit) = value.kind;
-            if let LitKind::Int(1, LitIntType::Unsuffixed) = lit.node;
-            if block.expr.is_none();
+            if let Some(Constant::Int(n)) = constant(cx, cx.typeck_results(), value);
+            // Rules out negative increments as well
+            if (1..=max).contains(&n);
+            // The guard has to let the addition through exactly when it doesn't overflow
+            if match op_node {
+                BinOpKind::Ne | BinOpKind::Lt => n == 1 && c == max,
+                BinOpKind::Le => c == max - n,
+                _ => false,
+            };
             then {
-                let mut app = Applicability::MachineApplicable;
+                // With `x <= MAX - n` the original code leaves `x` in `MAX - n + 1..MAX` unchanged
+                let mut app = if n == 1 {
+                    Applicability::MachineApplicable
+                } else {
+                    Applicability::MaybeIncorrect
+                };
                 let code = snippet_with_context(cx, target.span, ctxt, "_", &mut app).0;
+                let n = snippet_with_context(cx, value.span, ctxt, "_", &mut app).0;
                 let sugg = if let Some(parent) = get_parent_expr(cx, expr)
                     && let ExprKind::If(_cond, _then, Some(else_)) = parent.kind
                     && else_.hir_id == expr.hir_id
                 {
-                    format!("{{{code} = {code}.saturating_add(1); }}")
+                    format!("{{{code} = {code}.saturating_add({n}); }}")
                 } else {
-                    format!("{code} = {code}.saturating_add(1);")
+                    format!("{code} = {code}.saturating_add({n});")
                 };
                 span_lint_and_sugg(cx, IMPLICIT_SATURATING_ADD, expr.span, "manual saturating add detected", "use instead", sugg, app);
             }
@@ -1,7 +1,8 @@
 use clippy_utils::diagnostics::{span_lint, span_lint_and_sugg, span_lint_and_then};
 use clippy_utils::source::snippet_with_context;
 use clippy_utils::sugg::Sugg;
-use clippy_utils::{get_item_name, get_parent_as_impl, is_lint_allowed, peel_ref_operators};
+use clippy_utils::ty::is_type_lang_item;
+use clippy_utils::{get_item_name, get_parent_as_impl, is_lint_allowed, is_path_lang_item, peel_ref_operators};
 use if_chain::if_chain;
 use rustc_ast::ast::LitKind;
 use rustc_errors::Applicability;
@@ -9,7 +10,7 @@
 use rustc_hir::def_id::{DefId, DefIdSet};
 use rustc_hir::lang_items::LangItem;
 use rustc_hir::{
-    AssocItemKind, BinOpKind, Expr, ExprKind, FnRetTy, GenericArg, GenericBound, ImplItem, ImplItemKind,
+    AssocItemKind, BinOpKind, Expr, ExprKind, FnHeader, FnRetTy, GenericArg, GenericBound, ImplItem, ImplItemKind,
     ImplicitSelfKind, Item, ItemKind, Mutability, Node, PathSegment, PrimTy, QPath, TraitItemRef, TyKind,
     TypeBindingKind,
 };
@@ -56,8 +57,8 @@
 
 declare_clippy_lint! {
     /// ### What it does
-    /// Checks for items that implement `.len()` but not
-    /// `.is_empty()`.
+    /// Checks for items that implement `.len()` returning an integer (or an `Option` of one) but
+    /// not `.is_empty()`.
     ///
     /// ### Why is this bad?
     /// It is good custom to have both methods, because for
@@ -82,7 +83,7 @@
 
 declare_clippy_lint! {
     /// ### What it does
-    /// Checks for comparing to an empty slice such as `""` or `[]`,
+    /// Checks for comparing to an empty slice such as `""` or `[]`, or to `String::new()`,
     /// and suggests using `.is_empty()` where applicable.
     ///
     /// ### Why is this bad?
@@ -161,7 +162,7 @@ fn check_impl_item(&mut self, cx: &LateContext<'tcx>, item: &'tcx ImplItem<'_>)
                     }
                     _ => return,
                 };
-                check_for_is_empty(cx, sig.span, sig.decl.implicit_self, output, ty_id, name, kind)
+                check_for_is_empty(cx, sig.span, sig.decl.implicit_self, sig.header, output, ty_id, name, kind)
             }
         }
     }
@@ -260,7 +261,6 @@ fn fill_trait_set(traitt: DefId, set: &mut DefIdSet, cx: &LateContext<'_>) {
 enum LenOutput {
     Integral,
     Option(DefId),
-    Result(DefId),
 }
 
 fn extract_future_output<'tcx>(cx: &LateContext<'tcx>, ty: Ty<'tcx>) -> Option<&'tcx PathSegment<'tcx>> {
@@ -314,8 +314,6 @@ fn parse_len_output<'tcx>(cx: &LateContext<'tcx>, sig: FnSig<'tcx>) -> Option<Le
         if let Res::Def(_, def_id) = res {
             if cx.tcx.is_diagnostic_item(sym::Option, def_id) && is_first_generic_integral(segment) {
                 return Some(LenOutput::Option(def_id));
-            } else if cx.tcx.is_diagnostic_item(sym::Result, def_id) && is_first_generic_integral(segment) {
-                return Some(LenOutput::Result(def_id));
             }
         }
 
@@ -327,9 +325,6 @@ fn parse_len_output<'tcx>(cx: &LateContext<'tcx>, sig: FnSig<'tcx>) -> Option<Le
         ty::Adt(adt, subs) if cx.tcx.is_diagnostic_item(sym::Option, adt.did()) => {
             subs.type_at(0).is_integral().then(|| LenOutput::Option(adt.did()))
         },
-        ty::Adt(adt, subs) if cx.tcx.is_diagnostic_item(sym::Result, adt.did()) => {
-            subs.type_at(0).is_integral().then(|| LenOutput::Result(adt.did()))
-        },
         _ => None,
     }
 }
@@ -340,7 +335,6 @@ fn matches_is_empty_output<'tcx>(self, cx: &LateContext<'tcx>, ty: Ty<'tcx>) ->
             return match (self, segment.res) {
                 (_, Res::PrimTy(PrimTy::Bool)) => true,
                 (Self::Option(_), Res::Def(_, def_id)) if cx.tcx.is_diagnostic_item(sym::Option, def_id) => true,
-                (Self::Result(_), Res::Def(_, def_id)) if cx.tcx.is_diagnostic_item(sym::Result, def_id) => true,
                 _ => false,
             };
         }
@@ -348,7 +342,6 @@ fn matches_is_empty_output<'tcx>(self, cx: &LateContext<'tcx>, ty: Ty<'tcx>) ->
         match (self, ty.kind()) {
             (_, &ty::Bool) => true,
             (Self::Option(id), &ty::Adt(adt, subs)) if id == adt.did() => subs.type_at(0).is_bool(),
-            (Self::Result(id), &ty::Adt(adt, subs)) if id == adt.did() => subs.type_at(0).is_bool(),
             _ => false,
         }
     }
@@ -364,8 +357,33 @@ fn expected_sig(self, self_kind: ImplicitSelfKind) -> String {
             Self::Option(_) => {
                 format!("expected signature: `({self_ref}self) -> bool` or `({self_ref}self) -> Option<bool>")
             },
-            Self::Result(..) => {
-                format!("expected signature: `({self_ref}self) -> bool` or `({self_ref}self) -> Result<bool>")
+        }
+    }
+
+    /// Builds an `is_empty` method to go alongside a `len` method with the given receiver and
+    /// header.
+    fn is_empty_skeleton(self, self_kind: ImplicitSelfKind, header: FnHeader) -> String {
+        let receiver = match self_kind {
+            ImplicitSelfKind::ImmRef => "&self",
+            ImplicitSelfKind::MutRef => "&mut self",
+            _ => "self",
+        };
+        let qualifier = if header.is_const() {
+            "const "
+        } else if header.is_async() {
+            "async "
+        } else {
+            ""
+        };
+        let len = if header.is_async() {
+            "self.len().await"
+        } else {
+            "self.len()"
+        };
+        match self {
+            Self::Integral => format!("pub {qualifier}fn is_empty({receiver}) -> bool {{ {len} == 0 }}"),
+            Self::Option(_) => {
+                format!("pub {qualifier}fn is_empty({receiver}) -> Option<bool> {{ {len}.map(|len| len == 0) }}")
             },
         }
     }
@@ -390,11 +408,31 @@ fn check_is_empty_sig<'tcx>(
     }
 }
 
+/// Checks if the given type implements a trait providing an `is_empty` method, e.g.
+/// `ExactSizeIterator`.
+fn has_is_empty_from_trait(cx: &LateContext<'_>, impl_ty: DefId) -> bool {
+    let is_empty = sym!(is_empty);
+    cx.tcx.all_local_trait_impls(()).iter().any(|(&trait_id, impls)| {
+        cx.tcx
+            .associated_items(trait_id)
+            .filter_by_name_unhygienic(is_empty)
+            .any(|item| item.kind == AssocKind::Fn && item.fn_has_self_parameter)
+            && impls.iter().any(|&imp| {
+                matches!(
+                    cx.tcx.type_of(imp).instantiate_identity().kind(),
+                    ty::Adt(adt, _) if adt.did() == impl_ty
+                )
+            })
+    })
+}
+
 /// Checks if the given type has an `is_empty` method with the appropriate signature.
+#[expect(clippy::too_many_arguments)]
 fn check_for_is_empty(
     cx: &LateContext<'_>,
     span: Span,
     self_kind: ImplicitSelfKind,
+    len_header: FnHeader,
     output: LenOutput,
     impl_ty: DefId,
     item_name: Symbol,
@@ -409,6 +447,7 @@ fn check_for_is_empty(
         .find(|item| item.kind == AssocKind::Fn);
 
     let (msg, is_empty_span, self_kind) = match is_empty {
+        None if has_is_empty_from_trait(cx, impl_ty) => return,
         None => (
             format!(
                 "{item_kind} `{}` has a public `len` method, but no `is_empty` method",
@@ -446,6 +485,10 @@ fn check_for_is_empty(
         Some(_) => return,
     };
 
+    let skeleton = is_empty
+        .is_none()
+        .then(|| output.is_empty_skeleton(self_kind, len_header));
+
     span_lint_and_then(cx, LEN_WITHOUT_IS_EMPTY, span, &msg, |db| {
         if let Some(span) = is_empty_span {
             db.span_note(span, "`is_empty` defined here");
@@ -453,6 +496,9 @@ fn check_for_is_empty(
         if let Some(self_kind) = self_kind {
             db.note(output.expected_sig(self_kind));
         }
+        if let Some(skeleton) = skeleton {
+            db.help(format!("consider adding an `is_empty` method: `{skeleton}`"));
+        }
     });
 }
 
@@ -517,7 +563,7 @@ fn check_len(
 }
 
 fn check_empty_expr(cx: &LateContext<'_>, span: Span, lit1: &Expr<'_>, lit2: &Expr<'_>, op: &str) {
-    if (is_empty_array(lit2) || is_empty_string(lit2)) && has_is_empty(cx, lit1) {
+    if (is_empty_array(lit2) || is_empty_string(lit2) || is_string_new(cx, lit1, lit2)) && has_is_empty(cx, lit1) {
         let mut applicability = Applicability::MachineApplicable;
 
         let lit1 = peel_ref_operators(cx, lit1);
@@ -545,6 +591,20 @@ fn is_empty_string(expr: &Expr<'_>) -> bool {
     false
 }
 
+/// Checks if `expr` is a call to `String::new()` which is compared to a `String` or a `str`.
+fn is_string_new(cx: &LateContext<'_>, other: &Expr<'_>, expr: &Expr<'_>) -> bool {
+    if let ExprKind::Call(func, []) = expr.kind
+        && let ExprKind::Path(QPath::TypeRelative(ty, seg)) = func.kind
+        && seg.ident.name == sym::new
+        && is_path_lang_item(cx, ty, LangItem::String)
+    {
+        let other_ty = cx.typeck_results().expr_ty(other).peel_refs();
+        other_ty.is_str() || is_type_lang_item(cx, other_ty, LangItem::String)
+    } else {
+        false
+    }
+}
+
 fn is_empty_array(expr: &Expr<'_>) -> bool {
     if let ExprKind::Array(arr) = expr.kind {
         return arr.is_empty();
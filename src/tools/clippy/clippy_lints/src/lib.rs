@@ -53,8 +53,10 @@
 
 use clippy_utils::msrvs::Msrv;
 use rustc_data_structures::fx::FxHashSet;
-use rustc_lint::{Lint, LintId};
+use rustc_errors::Applicability;
+use rustc_lint::{Level, Lint, LintId};
 use rustc_session::Session;
+use rustc_span::edit_distance::edit_distance;
 
 #[cfg(feature = "internal")]
 pub mod deprecated_lints;
@@ -70,6 +72,7 @@
 mod approx_const;
 mod arc_with_non_send_sync;
 mod as_conversions;
+mod as_ref_then_clone;
 mod asm_syntax;
 mod assertions_on_constants;
 mod assertions_on_result_states;
@@ -82,11 +85,14 @@
 mod booleans;
 mod borrow_deref_ref;
 mod box_default;
+mod boxed_slice_roundtrip;
 mod cargo;
 mod casts;
 mod checked_conversions;
+mod clamped_index;
 mod cognitive_complexity;
 mod collapsible_if;
+mod collect_then_sort;
 mod collection_is_never_read;
 mod comparison_chain;
 mod copies;
@@ -123,6 +129,7 @@
 mod escape;
 mod eta_reduction;
 mod excessive_bools;
+mod excessive_match_nesting;
 mod excessive_nesting;
 mod exhaustive_items;
 mod exit;
@@ -139,10 +146,13 @@
 mod from_over_into;
 mod from_raw_with_void_ptr;
 mod from_str_radix_10;
+mod from_utf8_literal;
 mod functions;
 mod future_not_send;
+mod hashset_single_contains;
 mod if_let_mutex;
 mod if_not_else;
+mod if_some_unwrap;
 mod if_then_some_else_none;
 mod implicit_hasher;
 mod implicit_return;
@@ -179,21 +189,40 @@
 mod loops;
 mod macro_use;
 mod main_recursion;
+mod manual_array_try_into;
 mod manual_assert;
 mod manual_async_fn;
 mod manual_bits;
+mod manual_checked_div;
 mod manual_clamp;
+mod manual_count_ones;
+mod manual_div_floor;
 mod manual_float_methods;
+mod manual_get_or_insert;
 mod manual_is_ascii_check;
+mod manual_iter_last;
+mod manual_leading_zeros;
 mod manual_let_else;
 mod manual_main_separator_str;
+mod manual_min_max;
 mod manual_non_exhaustive;
+mod manual_option_and;
+mod manual_option_insert;
+mod manual_option_xor;
+mod manual_position;
+mod manual_ptr_offset;
 mod manual_range_patterns;
 mod manual_rem_euclid;
 mod manual_retain;
+mod manual_retain_swap_remove;
+mod manual_slice_contains;
+mod manual_slice_reverse;
 mod manual_slice_size_calculation;
 mod manual_string_new;
 mod manual_strip;
+mod manual_sum;
+mod manual_unzip;
+mod manual_wraparound_index;
 mod map_unit_fn;
 mod match_result_ok;
 mod matches;
@@ -221,9 +250,12 @@
 mod mutable_debug_assertion;
 mod mutex_atomic;
 mod needless_arbitrary_self_type;
+mod needless_arc_clone;
 mod needless_bool;
 mod needless_borrowed_ref;
+mod needless_box_return;
 mod needless_continue;
+mod needless_drop_at_scope_end;
 mod needless_else;
 mod needless_for_each;
 mod needless_if;
@@ -249,12 +281,14 @@
 mod option_env_unwrap;
 mod option_if_let_else;
 mod overflow_check_conditional;
+mod packed_ref_read;
 mod panic_in_result_fn;
 mod panic_unimplemented;
 mod partial_pub_fields;
 mod partialeq_ne_impl;
 mod partialeq_to_none;
 mod pass_by_ref_or_value;
+mod pathbuf_init_then_push;
 mod pattern_type_mismatch;
 mod permissions_set_readonly_false;
 mod precedence;
@@ -267,26 +301,32 @@
 mod raw_strings;
 mod rc_clone_in_vec_init;
 mod read_zero_byte_vec;
+mod redundant_ascii_case_guard;
 mod redundant_async_block;
 mod redundant_clone;
 mod redundant_closure_call;
+mod redundant_default_generic;
 mod redundant_else;
 mod redundant_field_names;
 mod redundant_pub_crate;
 mod redundant_slicing;
 mod redundant_static_lifetimes;
+mod redundant_turbofish;
 mod redundant_type_annotations;
 mod ref_option_ref;
 mod ref_patterns;
 mod reference;
 mod regex;
+mod repeat_vec_with_capacity;
 mod return_self_not_must_use;
 mod returns;
+mod roundtrip_bytes_swap;
 mod same_name_method;
 mod self_named_constructors;
 mod semicolon_block;
 mod semicolon_if_nothing_returned;
 mod serde_api;
+mod set_contains_or_insert;
 mod shadow;
 mod significant_drop_tightening;
 mod single_call_fn;
@@ -341,6 +381,7 @@
 mod vec;
 mod vec_init_then_push;
 mod visibility;
+mod widened_saturating;
 mod wildcard_imports;
 mod write;
 mod zero_div_zero;
@@ -348,7 +389,7 @@
 // end lints modules, do not remove this comment, it’s used in `update_lints`
 
 use crate::utils::conf::metadata::get_configuration_metadata;
-use crate::utils::conf::TryConf;
+use crate::utils::conf::{LintLevel, TryConf};
 pub use crate::utils::conf::{lookup_conf_file, Conf};
 use crate::utils::FindAll;
 
@@ -369,35 +410,35 @@ pub fn register_pre_expansion_lints(store: &mut rustc_lint::LintStore, sess: &Se
 }
 
 #[doc(hidden)]
-pub fn read_conf(sess: &Session, path: &io::Result<(Option<PathBuf>, Vec<String>)>) -> Conf {
+pub fn read_conf(sess: &Session, path: &io::Result<(Vec<PathBuf>, Vec<String>)>) -> Conf {
     if let Ok((_, warnings)) = path {
         for warning in warnings {
             sess.warn(warning.clone());
         }
     }
-    let file_name = match path {
-        Ok((Some(path), _)) => path,
-        Ok((None, _)) => return Conf::default(),
+    let file_names = match path {
+        Ok((paths, _)) if paths.is_empty() => return Conf::default(),
+        Ok((paths, _)) => paths,
         Err(error) => {
             sess.err(format!("error finding Clippy's configuration file: {error}"));
             return Conf::default();
         },
     };
 
-    let TryConf { conf, errors, warnings } = utils::conf::read(sess, file_name);
+    let TryConf { conf, errors, warnings } = utils::conf::read(sess, file_names);
     // all conf errors are non-fatal, we just use the default conf in case of error
     for error in errors {
         if let Some(span) = error.span {
-            sess.span_err(
+            let mut diag = sess.struct_span_err(
                 span,
                 format!("error reading Clippy's configuration file: {}", error.message),
             );
+            if let Some(sugg) = error.suggestion {
+                diag.span_suggestion(span, sugg.message, sugg.suggestion, Applicability::MaybeIncorrect);
+            }
+            diag.emit();
         } else {
-            sess.err(format!(
-                "error reading Clippy's configuration file `{}`: {}",
-                file_name.display(),
-                error.message
-            ));
+            sess.err(format!("error reading Clippy's configuration file {}", error.message));
         }
     }
 
@@ -408,17 +449,35 @@ pub fn read_conf(sess: &Session, path: &io::Result<(Option<PathBuf>, Vec<String>
                 format!("error reading Clippy's configuration file: {}", warning.message),
             );
         } else {
-            sess.warn(format!(
-                "error reading Clippy's configuration file `{}`: {}",
-                file_name.display(),
-                warning.message
-            ));
+            sess.warn(format!("error reading Clippy's configuration file {}", warning.message));
         }
     }
 
     conf
 }
 
+/// Returns the levels set in the `[lints]` tables of the configuration files, to be passed to rustc
+/// before the command-line lint options so that those take precedence.
+///
+/// Used in `./src/driver.rs`.
+pub fn conf_lint_opts(path: &io::Result<(Vec<PathBuf>, Vec<String>)>) -> Vec<(String, Level)> {
+    let Ok((paths, _)) = path else {
+        return Vec::new();
+    };
+    utils::conf::read_lint_levels(paths)
+        .into_iter()
+        .map(|(name, level)| {
+            let level = match level {
+                LintLevel::Allow => Level::Allow,
+                LintLevel::Warn => Level::Warn,
+                LintLevel::Deny => Level::Deny,
+                LintLevel::Forbid => Level::Forbid,
+            };
+            (format!("clippy::{name}"), level)
+        })
+        .collect()
+}
+
 #[derive(Default)]
 struct RegistrationGroups {
     all: Vec<LintId>,
@@ -475,6 +534,22 @@ fn is_all(self) -> bool {
         matches!(self, Correctness | Suspicious | Style | Complexity | Perf)
     }
 
+    fn name(self) -> &'static str {
+        match self {
+            Cargo => "cargo",
+            Complexity => "complexity",
+            Correctness => "correctness",
+            Nursery => "nursery",
+            Pedantic => "pedantic",
+            Perf => "perf",
+            Restriction => "restriction",
+            Style => "style",
+            Suspicious => "suspicious",
+            #[cfg(feature = "internal")]
+            Internal => "internal",
+        }
+    }
+
     fn group(self, groups: &mut RegistrationGroups) -> &mut Vec<LintId> {
         match self {
             Cargo => &mut groups.cargo,
@@ -499,7 +574,12 @@ pub(crate) struct LintInfo {
     explanation: &'static str,
 }
 
+/// Prints the documentation of a lint, given as `clippy::name` or `name`.
+///
+/// Used in `./src/main.rs` and `./src/driver.rs`.
 pub fn explain(name: &str) -> i32 {
+    let name = name.to_ascii_lowercase();
+    let name = name.strip_prefix("clippy::").unwrap_or(&name).replace('-', "_");
     let target = format!("clippy::{}", name.to_ascii_uppercase());
     if let Some(info) = declared_lints::LINTS.iter().find(|info| info.lint.name == target) {
         println!("{}", info.explanation);
@@ -519,6 +599,14 @@ pub fn explain(name: &str) -> i32 {
         0
     } else {
         println!("unknown lint: {name}");
+        let suggestion = declared_lints::LINTS
+            .iter()
+            .map(|info| info.lint.name_lower()["clippy::".len()..].to_owned())
+            .filter_map(|lint| Some((edit_distance(&lint, &name, name.len().max(3) / 3)?, lint)))
+            .min_by_key(|&(distance, _)| distance);
+        if let Some((_, lint)) = suggestion {
+            println!("did you mean `{lint}`?");
+        }
         1
     }
 }
@@ -538,6 +626,13 @@ fn register_categories(store: &mut rustc_lint::LintStore) {
 
     store.register_lints(&lints);
     groups.register(store);
+
+    clippy_utils::diagnostics::set_lint_groups(
+        declared_lints::LINTS
+            .iter()
+            .map(|info| (info.lint.name, info.category.name()))
+            .collect(),
+    );
 }
 
 /// Register all lints and lint groups with the rustc plugin registry
@@ -651,6 +746,7 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
     let avoid_breaking_exported_api = conf.avoid_breaking_exported_api;
     let allow_expect_in_tests = conf.allow_expect_in_tests;
     let allow_unwrap_in_tests = conf.allow_unwrap_in_tests;
+    let or_fun_call_cheap_fns = conf.or_fun_call_cheap_fns.clone();
     let suppress_restriction_lint_in_const = conf.suppress_restriction_lint_in_const;
     store.register_late_pass(move |_| Box::new(approx_const::ApproxConstant::new(msrv())));
     store.register_late_pass(move |_| {
@@ -659,9 +755,11 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
             msrv(),
             allow_expect_in_tests,
             allow_unwrap_in_tests,
+            or_fun_call_cheap_fns.clone(),
         ))
     });
-    store.register_late_pass(move |_| Box::new(matches::Matches::new(msrv())));
+    let single_match_with_else = conf.single_match_with_else;
+    store.register_late_pass(move |_| Box::new(matches::Matches::new(msrv(), single_match_with_else)));
     let matches_for_let_else = conf.matches_for_let_else;
     store.register_early_pass(move || Box::new(manual_non_exhaustive::ManualNonExhaustiveStruct::new(msrv())));
     store.register_late_pass(move |_| Box::new(manual_non_exhaustive::ManualNonExhaustiveEnum::new(msrv())));
@@ -686,7 +784,8 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
             msrv(),
         ))
     });
-    store.register_late_pass(|_| Box::<shadow::Shadow>::default());
+    let allowed_shadowed_names = conf.allowed_shadowed_names.clone();
+    store.register_late_pass(move |_| Box::new(shadow::Shadow::new(&allowed_shadowed_names)));
     store.register_late_pass(|_| Box::new(unit_types::UnitTypes));
     store.register_late_pass(move |_| Box::new(loops::Loops::new(msrv())));
     store.register_late_pass(|_| Box::<main_recursion::MainRecursion>::default());
@@ -839,17 +938,19 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
     });
     store.register_early_pass(|| Box::new(tabs_in_doc_comments::TabsInDocComments));
     let upper_case_acronyms_aggressive = conf.upper_case_acronyms_aggressive;
+    let max_acronym_length_allowed = conf.max_acronym_length_allowed;
     store.register_late_pass(move |_| {
         Box::new(upper_case_acronyms::UpperCaseAcronyms::new(
             avoid_breaking_exported_api,
             upper_case_acronyms_aggressive,
+            max_acronym_length_allowed,
         ))
     });
     store.register_late_pass(|_| Box::<default::Default>::default());
     store.register_late_pass(move |_| Box::new(unused_self::UnusedSelf::new(avoid_breaking_exported_api)));
     store.register_late_pass(|_| Box::new(mutable_debug_assertion::DebugAssertWithMutCall));
     store.register_late_pass(|_| Box::new(exit::Exit));
-    store.register_late_pass(|_| Box::new(to_digit_is_some::ToDigitIsSome));
+    store.register_late_pass(move |_| Box::new(to_digit_is_some::ToDigitIsSome::new(msrv())));
     let array_size_threshold = u128::from(conf.array_size_threshold);
     store.register_late_pass(move |_| Box::new(large_stack_arrays::LargeStackArrays::new(array_size_threshold)));
     store.register_late_pass(move |_| Box::new(large_const_arrays::LargeConstArrays::new(array_size_threshold)));
@@ -909,7 +1010,7 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
     store.register_late_pass(move |_| Box::new(if_then_some_else_none::IfThenSomeElseNone::new(msrv())));
     store.register_late_pass(|_| Box::new(bool_assert_comparison::BoolAssertComparison));
     store.register_early_pass(move || Box::new(module_style::ModStyle));
-    store.register_late_pass(|_| Box::new(unused_async::UnusedAsync));
+    store.register_late_pass(|_| Box::<unused_async::UnusedAsync>::default());
     let disallowed_types = conf.disallowed_types.clone();
     store.register_late_pass(move |_| Box::new(disallowed_types::DisallowedTypes::new(disallowed_types.clone())));
     let import_renames = conf.enforced_import_renames.clone();
@@ -1078,6 +1179,55 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
     store.register_early_pass(|| Box::new(visibility::Visibility));
     store.register_late_pass(move |_| Box::new(tuple_array_conversions::TupleArrayConversions { msrv: msrv() }));
     store.register_late_pass(|_| Box::new(manual_float_methods::ManualFloatMethods));
+    store.register_late_pass(|_| Box::new(if_some_unwrap::IfSomeUnwrap));
+    store.register_late_pass(|_| Box::new(manual_unzip::ManualUnzip));
+    store.register_late_pass(|_| Box::<needless_arc_clone::NeedlessArcClone>::default());
+    store.register_late_pass(|_| Box::new(widened_saturating::WidenedSaturating));
+    store.register_late_pass(|_| Box::new(from_utf8_literal::FromUtf8Literal));
+    store.register_late_pass(|_| Box::new(manual_div_floor::ManualDivFloor));
+    store.register_late_pass(|_| Box::new(manual_sum::ManualSum));
+    store.register_late_pass(|_| Box::new(redundant_ascii_case_guard::RedundantAsciiCaseGuard));
+    store.register_late_pass(|_| Box::new(hashset_single_contains::HashsetSingleContains));
+    store.register_late_pass(|_| Box::new(manual_get_or_insert::ManualGetOrInsert));
+    store.register_late_pass(|_| Box::new(manual_retain_swap_remove::ManualRetainSwapRemove));
+    store.register_late_pass(|_| Box::new(manual_option_and::ManualOptionAnd));
+    store.register_late_pass(move |_| {
+        Box::new(needless_box_return::NeedlessBoxReturn::new(
+            avoid_breaking_exported_api,
+            unnecessary_box_size,
+        ))
+    });
+    store.register_late_pass(|_| Box::new(manual_checked_div::ManualCheckedDiv));
+    store.register_late_pass(|_| Box::new(manual_ptr_offset::ManualPtrOffset));
+    store.register_late_pass(|_| Box::new(manual_array_try_into::ManualArrayTryInto));
+    let excessive_match_nesting_threshold = conf.excessive_match_nesting_threshold;
+    store.register_late_pass(move |_| {
+        Box::new(excessive_match_nesting::ExcessiveMatchNesting::new(
+            excessive_match_nesting_threshold,
+        ))
+    });
+    store.register_late_pass(move |_| Box::new(manual_option_xor::ManualOptionXor::new(msrv())));
+    store.register_late_pass(|_| Box::new(manual_leading_zeros::ManualLeadingZeros));
+    store.register_late_pass(|_| Box::new(manual_min_max::ManualMinMax));
+    store.register_late_pass(|_| Box::new(manual_iter_last::ManualIterLast));
+    store.register_late_pass(|_| Box::new(redundant_turbofish::RedundantTurbofish));
+    store.register_late_pass(|_| Box::new(manual_slice_contains::ManualSliceContains));
+    store.register_late_pass(|_| Box::new(manual_count_ones::ManualCountOnes));
+    store.register_late_pass(|_| Box::new(collect_then_sort::CollectThenSort));
+    store.register_late_pass(|_| Box::new(as_ref_then_clone::AsRefThenClone));
+    store.register_late_pass(|_| Box::new(clamped_index::ClampedIndex));
+    store.register_late_pass(|_| Box::new(manual_position::ManualPosition));
+    store.register_late_pass(|_| Box::new(packed_ref_read::PackedRefRead));
+    store.register_late_pass(|_| Box::new(roundtrip_bytes_swap::RoundtripBytesSwap));
+    store.register_late_pass(|_| Box::new(redundant_default_generic::RedundantDefaultGeneric));
+    store.register_late_pass(|_| Box::new(manual_wraparound_index::ManualWraparoundIndex));
+    store.register_late_pass(|_| Box::new(boxed_slice_roundtrip::BoxedSliceRoundtrip));
+    store.register_late_pass(|_| Box::<pathbuf_init_then_push::PathbufInitThenPush<'_>>::default());
+    store.register_late_pass(move |_| Box::new(manual_option_insert::ManualOptionInsert::new(msrv())));
+    store.register_late_pass(|_| Box::new(repeat_vec_with_capacity::RepeatVecWithCapacity));
+    store.register_late_pass(|_| Box::new(manual_slice_reverse::ManualSliceReverse));
+    store.register_late_pass(|_| Box::new(needless_drop_at_scope_end::NeedlessDropAtScopeEnd));
+    store.register_late_pass(|_| Box::new(set_contains_or_insert::SetContainsOrInsert));
     // add lints here, do not remove this comment, it's used in `new_lint`
 }
 
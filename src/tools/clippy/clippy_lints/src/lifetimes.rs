@@ -14,7 +14,7 @@
     Impl, ImplItem, ImplItemKind, Item, ItemKind, Lifetime, LifetimeName, LifetimeParamKind, Node, PolyTraitRef,
     PredicateOrigin, TraitFn, TraitItem, TraitItemKind, Ty, TyKind, WherePredicate,
 };
-use rustc_lint::{LateContext, LateLintPass, LintContext};
+use rustc_lint::{LateContext, LateLintPass, Lint, LintContext};
 use rustc_middle::hir::nested_filter as middle_nested_filter;
 use rustc_middle::lint::in_external_macro;
 use rustc_session::{declare_lint_pass, declare_tool_lint};
@@ -87,7 +87,45 @@
     "unused lifetimes in function definitions"
 }
 
-declare_lint_pass!(Lifetimes => [NEEDLESS_LIFETIMES, EXTRA_UNUSED_LIFETIMES]);
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for lifetime parameters of trait impls which are used only once in the impl
+    /// header and nowhere in the impl items, and so can be replaced by `'_` or elided.
+    ///
+    /// ### Why is this bad?
+    /// Naming a lifetime that is never referred to again makes the impl look more complicated
+    /// than it is.
+    ///
+    /// ### Known problems
+    /// - We bail out if the impl has bounds or a `where` clause mentioning lifetimes, as these
+    /// constrain the lifetime.
+    ///
+    /// ### Example
+    /// ```rust
+    /// # struct Wrapper<'a>(&'a str);
+    /// impl<'a> std::fmt::Display for Wrapper<'a> {
+    ///     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    ///         f.write_str(self.0)
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// Use instead:
+    /// ```rust
+    /// # struct Wrapper<'a>(&'a str);
+    /// impl std::fmt::Display for Wrapper<'_> {
+    ///     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    ///         f.write_str(self.0)
+    ///     }
+    /// }
+    /// ```
+    #[clippy::version = "1.73.0"]
+    pub NEEDLESS_LIFETIME_IN_IMPL_TRAIT_RETURN,
+    nursery,
+    "using explicit lifetimes in trait impl headers when they could be elided"
+}
+
+declare_lint_pass!(Lifetimes => [NEEDLESS_LIFETIMES, EXTRA_UNUSED_LIFETIMES, NEEDLESS_LIFETIME_IN_IMPL_TRAIT_RETURN]);
 
 impl<'tcx> LateLintPass<'tcx> for Lifetimes {
     fn check_item(&mut self, cx: &LateContext<'tcx>, item: &'tcx Item<'_>) {
@@ -96,6 +134,7 @@ fn check_item(&mut self, cx: &LateContext<'tcx>, item: &'tcx Item<'_>) {
         } else if let ItemKind::Impl(impl_) = item.kind {
             if !item.span.from_expansion() {
                 report_extra_impl_lifetimes(cx, impl_);
+                report_elidable_impl_lifetimes(cx, impl_, item.span);
             }
         }
     }
@@ -190,43 +229,16 @@ fn check_fn_inner<'tcx>(
             return;
         }
 
-        let lts = elidable_lts
-            .iter()
-            // In principle, the result of the call to `Node::ident` could be `unwrap`ped, as `DefId` should refer to a
-            // `Node::GenericParam`.
-            .filter_map(|&def_id| cx.tcx.hir().get_by_def_id(def_id).ident())
-            .map(|ident| ident.to_string())
-            .collect::<Vec<_>>()
-            .join(", ");
-
-        span_lint_and_then(
+        // async functions have usages whose spans point at the lifetime declaration which messes up
+        // suggestions
+        let include_suggestions = !sig.header.is_async();
+        report_elidable_lifetimes(
             cx,
             NEEDLESS_LIFETIMES,
-            elidable_lts
-                .iter()
-                .map(|&lt| cx.tcx.def_span(lt))
-                .chain(usages.iter().filter_map(|usage| {
-                    if let LifetimeName::Param(def_id) = usage.res
-                        && elidable_lts.contains(&def_id)
-                    {
-                        return Some(usage.ident.span);
-                    }
-
-                    None
-                }))
-                .collect_vec(),
-            &format!("the following explicit lifetimes could be elided: {lts}"),
-            |diag| {
-                if sig.header.is_async() {
-                    // async functions have usages whose spans point at the lifetime declaration which messes up
-                    // suggestions
-                    return;
-                };
-
-                if let Some(suggestions) = elision_suggestions(cx, generics, &elidable_lts, &usages) {
-                    diag.multipart_suggestion("elide the lifetimes", suggestions, Applicability::MachineApplicable);
-                }
-            },
+            generics,
+            &elidable_lts,
+            &usages,
+            include_suggestions,
         );
     }
 
@@ -235,6 +247,52 @@ fn check_fn_inner<'tcx>(
     }
 }
 
+fn report_elidable_lifetimes(
+    cx: &LateContext<'_>,
+    lint: &'static Lint,
+    generics: &Generics<'_>,
+    elidable_lts: &[LocalDefId],
+    usages: &[Lifetime],
+    include_suggestions: bool,
+) {
+    let lts = elidable_lts
+        .iter()
+        // In principle, the result of the call to `Node::ident` could be `unwrap`ped, as `DefId` should refer to a
+        // `Node::GenericParam`.
+        .filter_map(|&def_id| cx.tcx.hir().get_by_def_id(def_id).ident())
+        .map(|ident| ident.to_string())
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    span_lint_and_then(
+        cx,
+        lint,
+        elidable_lts
+            .iter()
+            .map(|&lt| cx.tcx.def_span(lt))
+            .chain(usages.iter().filter_map(|usage| {
+                if let LifetimeName::Param(def_id) = usage.res
+                    && elidable_lts.contains(&def_id)
+                {
+                    return Some(usage.ident.span);
+                }
+
+                None
+            }))
+            .collect_vec(),
+        &format!("the following explicit lifetimes could be elided: {lts}"),
+        |diag| {
+            if !include_suggestions {
+                return;
+            };
+
+            if let Some(suggestions) = elision_suggestions(cx, generics, elidable_lts, usages) {
+                diag.multipart_suggestion("elide the lifetimes", suggestions, Applicability::MachineApplicable);
+            }
+        },
+    );
+}
+
 fn elision_suggestions(
     cx: &LateContext<'_>,
     generics: &Generics<'_>,
@@ -698,6 +756,75 @@ fn report_extra_impl_lifetimes<'tcx>(cx: &LateContext<'tcx>, impl_: &'tcx Impl<'
     }
 }
 
+/// Checks for lifetimes of trait impls which are used only once in the impl header and nowhere in
+/// the impl items, such as the `'a` in `impl<'a> Trait for Type<'a>`.
+fn report_elidable_impl_lifetimes<'tcx>(cx: &LateContext<'tcx>, impl_: &'tcx Impl<'_>, span: Span) {
+    let Some(ref trait_ref) = impl_.of_trait else {
+        return;
+    };
+    // Lifetimes in bounds and `where` clauses constrain the impl and can't be elided.
+    if in_external_macro(cx.sess(), span) || has_where_lifetimes(cx, impl_.generics) {
+        return;
+    }
+
+    let mut header_visitor = RefVisitor::new(cx);
+    header_visitor.visit_trait_ref(trait_ref);
+    header_visitor.visit_ty(impl_.self_ty);
+    if header_visitor.abort() || !header_visitor.nested_elision_site_lts.is_empty() {
+        return;
+    }
+    let usages = header_visitor.lts;
+    if usages.iter().any(|usage| !usage.ident.span.eq_ctxt(span)) {
+        return;
+    }
+
+    let explicit_lts = impl_
+        .generics
+        .params
+        .iter()
+        .filter(|param| {
+            matches!(
+                param.kind,
+                GenericParamKind::Lifetime {
+                    kind: LifetimeParamKind::Explicit
+                }
+            )
+        })
+        .collect::<Vec<_>>();
+    let candidates = named_lifetime_occurrences(&usages)
+        .into_iter()
+        .filter_map(|(def_id, occurrences)| {
+            let param = explicit_lts.iter().find(|param| param.def_id == def_id)?;
+            (occurrences == 1).then_some((param.name.ident().name, param.span))
+        })
+        .collect::<FxHashMap<_, _>>();
+    if candidates.is_empty() {
+        return;
+    }
+
+    // Lifetimes the impl items refer to need a name.
+    let mut checker = LifetimeChecker::<middle_nested_filter::All>::new(cx, candidates);
+    for item in impl_.items {
+        walk_impl_item_ref(&mut checker, item);
+    }
+
+    let elidable_lts = explicit_lts
+        .iter()
+        .filter(|param| checker.map.contains_key(&param.name.ident().name))
+        .map(|param| param.def_id)
+        .collect::<Vec<_>>();
+    if !elidable_lts.is_empty() {
+        report_elidable_lifetimes(
+            cx,
+            NEEDLESS_LIFETIME_IN_IMPL_TRAIT_RETURN,
+            impl_.generics,
+            &elidable_lts,
+            &usages,
+            true,
+        );
+    }
+}
+
 struct BodyLifetimeChecker {
     lifetimes_used_in_body: bool,
 }
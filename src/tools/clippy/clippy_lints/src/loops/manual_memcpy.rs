@@ -1,5 +1,5 @@
 use super::{IncrementVisitor, InitializeVisitor, MANUAL_MEMCPY};
-use clippy_utils::diagnostics::span_lint_and_sugg;
+use clippy_utils::diagnostics::{span_lint, span_lint_and_sugg};
 use clippy_utils::source::snippet;
 use clippy_utils::sugg::Sugg;
 use clippy_utils::ty::is_copy;
@@ -24,11 +24,12 @@ pub(super) fn check<'tcx>(
     body: &'tcx Expr<'_>,
     expr: &'tcx Expr<'_>,
 ) -> bool {
+    let (range, adapter) = peel_range_adapter(arg);
     if let Some(higher::Range {
         start: Some(start),
         end: Some(end),
         limits,
-    }) = higher::Range::hir(arg)
+    }) = higher::Range::hir(range)
     {
         // the var must be a single name
         if let PatKind::Binding(_, canonical_id, _, _) = pat.kind {
@@ -43,7 +44,11 @@ pub(super) fn check<'tcx>(
             let mut iter_b = None;
 
             if let ExprKind::Block(block, _) = body.kind {
-                if let Some(loop_counters) = get_loop_counters(cx, block, expr) {
+                // Loop counters always count upwards by one, so they only line up with the range
+                // when it isn't reversed or strided.
+                if adapter == RangeAdapter::None
+                    && let Some(loop_counters) = get_loop_counters(cx, block, expr)
+                {
                     starts.extend(loop_counters);
                 }
                 iter_a = Some(get_assignments(block, &starts));
@@ -84,15 +89,25 @@ pub(super) fn check<'tcx>(
                 .map(|v| v.join("\n    "));
 
             if let Some(big_sugg) = big_sugg {
-                span_lint_and_sugg(
-                    cx,
-                    MANUAL_MEMCPY,
-                    expr.span,
-                    "it looks like you're manually copying between slices",
-                    "try replacing the loop by",
-                    big_sugg,
-                    Applicability::Unspecified,
-                );
+                if adapter == RangeAdapter::StepBy {
+                    // A strided copy can't be expressed as a single slice copy.
+                    span_lint(
+                        cx,
+                        MANUAL_MEMCPY,
+                        expr.span,
+                        "it looks like you're manually copying between slices",
+                    );
+                } else {
+                    span_lint_and_sugg(
+                        cx,
+                        MANUAL_MEMCPY,
+                        expr.span,
+                        "it looks like you're manually copying between slices",
+                        "try replacing the loop by",
+                        big_sugg,
+                        Applicability::Unspecified,
+                    );
+                }
                 return true;
             }
         }
@@ -100,6 +115,25 @@ pub(super) fn check<'tcx>(
     false
 }
 
+/// An iterator adapter applied to the range of the loop.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum RangeAdapter {
+    None,
+    /// `(start..end).rev()`, which copies the same elements in the opposite order.
+    Rev,
+    /// `(start..end).step_by(k)`, which only copies every `k`-th element.
+    StepBy,
+}
+
+/// Strips a `.rev()` or `.step_by(k)` call from the loop's iterator expression.
+fn peel_range_adapter<'tcx>(arg: &'tcx Expr<'tcx>) -> (&'tcx Expr<'tcx>, RangeAdapter) {
+    match arg.kind {
+        ExprKind::MethodCall(path, recv, [], _) if path.ident.name == sym!(rev) => (recv, RangeAdapter::Rev),
+        ExprKind::MethodCall(path, recv, [_], _) if path.ident.name == sym!(step_by) => (recv, RangeAdapter::StepBy),
+        _ => (arg, RangeAdapter::None),
+    }
+}
+
 fn build_manual_memcpy_suggestion<'tcx>(
     cx: &LateContext<'tcx>,
     start: &Expr<'_>,
@@ -0,0 +1,123 @@
+use clippy_utils::consts::{constant, Constant};
+use clippy_utils::diagnostics::span_lint_and_help;
+use clippy_utils::higher::Range;
+use clippy_utils::path_to_local_id;
+use clippy_utils::source::snippet;
+use rustc_ast::ast::RangeLimits;
+use rustc_hir::{BindingAnnotation, Block, Expr, ExprKind, HirId, Local, PatKind, Stmt, StmtKind};
+use rustc_lint::{LateContext, LateLintPass, LintContext};
+use rustc_middle::lint::in_external_macro;
+use rustc_middle::ty::{self, Ty};
+use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_span::Symbol;
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for arrays which are initialized with a placeholder value and then immediately
+    /// filled from a slice of exactly the same length with `copy_from_slice`.
+    ///
+    /// ### Why is this bad?
+    /// Converting the slice with `try_into` does the same in one step, without a mutable
+    /// binding or a dummy initial value.
+    ///
+    /// ### Example
+    /// ```rust
+    /// # let bytes = [1_u8, 2, 3, 4, 5, 6];
+    /// let mut header = [0u8; 4];
+    /// header.copy_from_slice(&bytes[0..4]);
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # let bytes = [1_u8, 2, 3, 4, 5, 6];
+    /// let header: [u8; 4] = bytes[0..4].try_into().unwrap();
+    /// ```
+    #[clippy::version = "1.73.0"]
+    pub MANUAL_TRY_INTO_ARRAY_FROM_SLICE,
+    nursery,
+    "filling a fresh array with `copy_from_slice` instead of converting the slice with `try_into`"
+}
+declare_lint_pass!(ManualArrayTryInto => [MANUAL_TRY_INTO_ARRAY_FROM_SLICE]);
+
+impl<'tcx> LateLintPass<'tcx> for ManualArrayTryInto {
+    fn check_block(&mut self, cx: &LateContext<'tcx>, block: &'tcx Block<'tcx>) {
+        if in_external_macro(cx.sess(), block.span) {
+            return;
+        }
+        for window in block.stmts.windows(2) {
+            if let [local_stmt, copy_stmt] = window
+                && let StmtKind::Local(local) = local_stmt.kind
+                && let Some((binding_id, name, arr_ty)) = array_local(cx, local)
+                && let ty::Array(_, len) = *arr_ty.kind()
+                && let Some(len) = len.try_eval_target_usize(cx.tcx, cx.param_env)
+                && let Some(slice) = copy_from_slice(cx, copy_stmt, binding_id, len)
+                && !local_stmt.span.from_expansion()
+                && !copy_stmt.span.from_expansion()
+            {
+                span_lint_and_help(
+                    cx,
+                    MANUAL_TRY_INTO_ARRAY_FROM_SLICE,
+                    local_stmt.span.to(copy_stmt.span),
+                    "this array is only initialized to be filled from a slice of the same length",
+                    None,
+                    &format!(
+                        "use `let {name}: {arr_ty} = {}.try_into().unwrap();`",
+                        snippet(cx, slice.span, "..")
+                    ),
+                );
+            }
+        }
+    }
+}
+
+/// Matches `let mut arr = [init; N];`, returning the binding, its name and the array type.
+fn array_local<'tcx>(cx: &LateContext<'tcx>, local: &'tcx Local<'tcx>) -> Option<(HirId, Symbol, Ty<'tcx>)> {
+    if let PatKind::Binding(BindingAnnotation::MUT, binding_id, ident, None) = local.pat.kind
+        && let Some(init) = local.init
+        && let ExprKind::Repeat(..) = init.kind
+        && local.els.is_none()
+    {
+        Some((binding_id, ident.name, cx.typeck_results().pat_ty(local.pat)))
+    } else {
+        None
+    }
+}
+
+/// Matches `arr.copy_from_slice(&slice[a..b]);` where the range has constant bounds covering
+/// exactly `len` elements, returning the indexing expression.
+fn copy_from_slice<'tcx>(
+    cx: &LateContext<'tcx>,
+    stmt: &'tcx Stmt<'tcx>,
+    binding_id: HirId,
+    len: u64,
+) -> Option<&'tcx Expr<'tcx>> {
+    if let StmtKind::Semi(expr) = stmt.kind
+        && let ExprKind::MethodCall(path, recv, [arg], _) = expr.kind
+        && path.ident.name.as_str() == "copy_from_slice"
+        && path_to_local_id(recv, binding_id)
+        && let ExprKind::AddrOf(_, _, slice) = arg.kind
+        && let ExprKind::Index(_, index) = slice.kind
+        && let Some(range) = Range::hir(index)
+    {
+        let typeck = cx.typeck_results();
+        let start = match range.start {
+            Some(start) => constant_u128(cx, typeck, start)?,
+            None => 0,
+        };
+        let end = constant_u128(cx, typeck, range.end?)?;
+        let end = match range.limits {
+            RangeLimits::HalfOpen => end,
+            RangeLimits::Closed => end.checked_add(1)?,
+        };
+        (end.checked_sub(start)? == u128::from(len)).then_some(slice)
+    } else {
+        None
+    }
+}
+
+fn constant_u128<'tcx>(cx: &LateContext<'tcx>, typeck: &ty::TypeckResults<'tcx>, expr: &Expr<'_>) -> Option<u128> {
+    if let Some(Constant::Int(value)) = constant(cx, typeck, expr) {
+        Some(value)
+    } else {
+        None
+    }
+}
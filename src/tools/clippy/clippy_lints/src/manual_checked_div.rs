@@ -0,0 +1,95 @@
+use clippy_utils::diagnostics::span_lint_and_sugg;
+use clippy_utils::source::snippet_with_applicability;
+use clippy_utils::sugg::Sugg;
+use clippy_utils::{higher, is_integer_literal, is_res_lang_ctor, path_res, peel_blocks, SpanlessEq};
+use rustc_errors::Applicability;
+use rustc_hir::LangItem::{OptionNone, OptionSome};
+use rustc_hir::{BinOpKind, Expr, ExprKind};
+use rustc_lint::{LateContext, LateLintPass, LintContext};
+use rustc_middle::lint::in_external_macro;
+use rustc_middle::ty;
+use rustc_session::{declare_lint_pass, declare_tool_lint};
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for integer divisions guarded by a check for a zero divisor, which return `None`
+    /// when the divisor is zero.
+    ///
+    /// ### Why is this bad?
+    /// `checked_div` and `checked_rem` do this directly. For signed integers, they also return
+    /// `None` for `MIN / -1`, which overflows and panics.
+    ///
+    /// ### Example
+    /// ```rust
+    /// # let (a, b) = (4_u32, 2_u32);
+    /// let c = if b != 0 { Some(a / b) } else { None };
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # let (a, b) = (4_u32, 2_u32);
+    /// let c = a.checked_div(b);
+    /// ```
+    #[clippy::version = "1.73.0"]
+    pub MANUAL_CHECKED_DIV,
+    complexity,
+    "checking for a zero divisor before dividing instead of using `checked_div` or `checked_rem`"
+}
+declare_lint_pass!(ManualCheckedDiv => [MANUAL_CHECKED_DIV]);
+
+impl<'tcx> LateLintPass<'tcx> for ManualCheckedDiv {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) {
+        if let Some(higher::If {
+            cond,
+            then,
+            r#else: Some(r#else),
+        }) = higher::If::hir(expr)
+            && !in_external_macro(cx.sess(), expr.span)
+            && !expr.span.from_expansion()
+            && let ExprKind::Binary(op, lhs, rhs) = cond.kind
+            && let Some(divisor) = if is_integer_literal(rhs, 0) {
+                Some(lhs)
+            } else if is_integer_literal(lhs, 0) {
+                Some(rhs)
+            } else {
+                None
+            }
+            && let Some((some, none)) = match op.node {
+                BinOpKind::Ne => Some((then, r#else)),
+                BinOpKind::Eq => Some((r#else, then)),
+                _ => None,
+            }
+            && is_res_lang_ctor(cx, path_res(cx, peel_blocks(none)), OptionNone)
+            && let ExprKind::Call(some_ctor, [div]) = peel_blocks(some).kind
+            && is_res_lang_ctor(cx, path_res(cx, some_ctor), OptionSome)
+            && let ExprKind::Binary(div_op, dividend, div_divisor) = div.kind
+            && let Some(method) = match div_op.node {
+                BinOpKind::Div => Some("checked_div"),
+                BinOpKind::Rem => Some("checked_rem"),
+                _ => None,
+            }
+            && !div.span.from_expansion()
+            && let ty = cx.typeck_results().expr_ty(dividend)
+            && matches!(ty.kind(), ty::Int(_) | ty::Uint(_))
+            && ty == cx.typeck_results().expr_ty(div_divisor)
+            && SpanlessEq::new(cx).deny_side_effects().eq_expr(divisor, div_divisor)
+        {
+            let mut app = Applicability::MachineApplicable;
+            let dividend = Sugg::hir_with_applicability(cx, dividend, "..", &mut app).maybe_par();
+            let divisor = snippet_with_applicability(cx, div_divisor.span, "..", &mut app);
+            let help = if ty.is_signed() {
+                format!("use `{method}`, which also returns `None` instead of panicking on overflow")
+            } else {
+                format!("use `{method}`")
+            };
+            span_lint_and_sugg(
+                cx,
+                MANUAL_CHECKED_DIV,
+                expr.span,
+                &format!("manual implementation of `{method}`"),
+                &help,
+                format!("{dividend}.{method}({divisor})"),
+                app,
+            );
+        }
+    }
+}
@@ -0,0 +1,149 @@
+use clippy_utils::consts::{constant, Constant};
+use clippy_utils::diagnostics::span_lint_and_help;
+use clippy_utils::source::snippet;
+use clippy_utils::{higher, path_to_local, path_to_local_id, peel_blocks_with_stmt};
+use rustc_hir::{BinOpKind, Expr, ExprKind, HirId, StmtKind};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::ty;
+use rustc_session::{declare_lint_pass, declare_tool_lint};
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for `while` loops which count the set bits of an unsigned integer by adding its
+    /// lowest bit to a counter and shifting it right by one bit until it is zero.
+    ///
+    /// ### Why is this bad?
+    /// `count_ones` does the same in a single instruction on most targets.
+    ///
+    /// ### Example
+    /// ```rust
+    /// # let mut x = 12_u32;
+    /// let mut count = 0;
+    /// while x != 0 {
+    ///     count += x & 1;
+    ///     x >>= 1;
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # let x = 12_u32;
+    /// let count = x.count_ones();
+    /// ```
+    #[clippy::version = "1.73.0"]
+    pub MANUAL_COUNT_ONES,
+    nursery,
+    "counting the set bits of an integer with a loop instead of `count_ones`"
+}
+declare_lint_pass!(ManualCountOnes => [MANUAL_COUNT_ONES]);
+
+impl<'tcx> LateLintPass<'tcx> for ManualCountOnes {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) {
+        if let Some(higher::While { condition, body, .. }) = higher::While::hir(expr)
+            && !expr.span.from_expansion()
+            && let Some(value) = nonzero_check(cx, condition)
+            && let ty::Uint(_) = cx.typeck_results().expr_ty(value).kind()
+            && let Some(value_id) = path_to_local(value)
+            && let ExprKind::Block(block, None) = body.kind
+            && block.expr.is_none()
+            && let [first, second] = block.stmts
+            && let StmtKind::Semi(first) | StmtKind::Expr(first) = first.kind
+            && let StmtKind::Semi(second) | StmtKind::Expr(second) = second.kind
+            // The lowest bit has to be counted before it is shifted out
+            && is_lowest_bit_count(cx, first, value_id)
+            && is_shift_by_one(cx, second, value_id)
+        {
+            span_lint_and_help(
+                cx,
+                MANUAL_COUNT_ONES,
+                expr.span,
+                "manual implementation of `count_ones`",
+                None,
+                &format!("consider using `{}.count_ones()`", snippet(cx, value.span, "..")),
+            );
+        }
+    }
+}
+
+/// Matches `x != 0` and `x > 0`, and returns `x`.
+fn nonzero_check<'tcx>(cx: &LateContext<'tcx>, cond: &'tcx Expr<'tcx>) -> Option<&'tcx Expr<'tcx>> {
+    let ExprKind::Binary(op, lhs, rhs) = cond.kind else {
+        return None;
+    };
+    match (
+        op.node,
+        constant(cx, cx.typeck_results(), lhs),
+        constant(cx, cx.typeck_results(), rhs),
+    ) {
+        (BinOpKind::Ne | BinOpKind::Lt, Some(Constant::Int(0)), None) => Some(rhs),
+        (BinOpKind::Ne | BinOpKind::Gt, None, Some(Constant::Int(0))) => Some(lhs),
+        _ => None,
+    }
+}
+
+/// Matches `x >>= 1`.
+fn is_shift_by_one(cx: &LateContext<'_>, expr: &Expr<'_>, value_id: HirId) -> bool {
+    matches!(
+        expr.kind,
+        ExprKind::AssignOp(op, target, amount)
+            if op.node == BinOpKind::Shr
+                && path_to_local_id(target, value_id)
+                && constant(cx, cx.typeck_results(), amount) == Some(Constant::Int(1))
+    )
+}
+
+/// Matches `count += x & 1`, optionally with a cast of `x & 1`, and
+/// `if x & 1 == 1 { count += 1 }` where `count` isn't `x`.
+fn is_lowest_bit_count(cx: &LateContext<'_>, expr: &Expr<'_>, value_id: HirId) -> bool {
+    if let Some(higher::If {
+        cond,
+        then,
+        r#else: None,
+    }) = higher::If::hir(expr)
+        && let ExprKind::Binary(op, lhs, rhs) = cond.kind
+        && let Some(masked) = match (
+            op.node,
+            constant(cx, cx.typeck_results(), lhs),
+            constant(cx, cx.typeck_results(), rhs),
+        ) {
+            (BinOpKind::Eq, Some(Constant::Int(1)), None) | (BinOpKind::Ne, Some(Constant::Int(0)), None) => {
+                Some(rhs)
+            },
+            (BinOpKind::Eq, None, Some(Constant::Int(1))) | (BinOpKind::Ne, None, Some(Constant::Int(0))) => {
+                Some(lhs)
+            },
+            _ => None,
+        }
+        && is_lowest_bit(cx, masked, value_id)
+        && let ExprKind::AssignOp(op, target, amount) = peel_blocks_with_stmt(then).kind
+        && op.node == BinOpKind::Add
+        && !path_to_local_id(target, value_id)
+        && constant(cx, cx.typeck_results(), amount) == Some(Constant::Int(1))
+    {
+        return true;
+    }
+
+    if let ExprKind::AssignOp(op, target, amount) = expr.kind
+        && op.node == BinOpKind::Add
+        && !path_to_local_id(target, value_id)
+    {
+        let amount = match amount.kind {
+            ExprKind::Cast(inner, _) => inner,
+            _ => amount,
+        };
+        is_lowest_bit(cx, amount, value_id)
+    } else {
+        false
+    }
+}
+
+/// Matches `x & 1` and `1 & x`.
+fn is_lowest_bit(cx: &LateContext<'_>, expr: &Expr<'_>, value_id: HirId) -> bool {
+    matches!(
+        expr.kind,
+        ExprKind::Binary(op, lhs, rhs) if op.node == BinOpKind::BitAnd
+            && ((path_to_local_id(lhs, value_id)
+                && constant(cx, cx.typeck_results(), rhs) == Some(Constant::Int(1)))
+                || (constant(cx, cx.typeck_results(), lhs) == Some(Constant::Int(1))
+                    && path_to_local_id(rhs, value_id)))
+    )
+}
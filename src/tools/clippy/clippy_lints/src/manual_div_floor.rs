@@ -0,0 +1,113 @@
+use clippy_utils::diagnostics::span_lint_and_help;
+use clippy_utils::sugg::Sugg;
+use clippy_utils::{higher, is_integer_literal, peel_blocks, SpanlessEq};
+use rustc_hir::{BinOpKind, Expr, ExprKind};
+use rustc_lint::{LateContext, LateLintPass, LintContext};
+use rustc_middle::lint::in_external_macro;
+use rustc_middle::ty;
+use rustc_session::{declare_lint_pass, declare_tool_lint};
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for a manual implementation of floor division on signed integers, which
+    /// corrects the result of `/` when the division isn't exact and the operands have
+    /// different signs.
+    ///
+    /// ### Why is this bad?
+    /// `div_floor` does the same thing and states the intent directly.
+    ///
+    /// ### Known problems
+    /// `div_floor` is unstable, so this is only linted with `#![feature(int_roundings)]`.
+    ///
+    /// ### Example
+    /// ```rust,ignore
+    /// let q = if a % b != 0 && (a < 0) != (b < 0) { a / b - 1 } else { a / b };
+    /// ```
+    /// Use instead:
+    /// ```rust,ignore
+    /// let q = a.div_floor(b);
+    /// ```
+    #[clippy::version = "1.73.0"]
+    pub MANUAL_DIV_FLOOR,
+    nursery,
+    "manually implementing floor division of signed integers"
+}
+
+declare_lint_pass!(ManualDivFloor => [MANUAL_DIV_FLOOR]);
+
+impl<'tcx> LateLintPass<'tcx> for ManualDivFloor {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
+        if let Some(higher::If {
+            cond,
+            then,
+            r#else: Some(r#else),
+        }) = higher::If::hir(expr)
+            && cx.tcx.features().active(sym!(int_roundings))
+            && !in_external_macro(cx.sess(), expr.span)
+            && !expr.span.from_expansion()
+            && let Some((lhs, rhs)) = division(peel_blocks(r#else))
+            // Unsigned division already rounds down.
+            && matches!(cx.typeck_results().expr_ty(lhs).kind(), ty::Int(_))
+            && let ExprKind::Binary(op, corrected, one) = peel_blocks(then).kind
+            && op.node == BinOpKind::Sub
+            && is_integer_literal(one, 1)
+            && let Some((then_lhs, then_rhs)) = division(corrected)
+            && SpanlessEq::new(cx).eq_expr(lhs, then_lhs)
+            && SpanlessEq::new(cx).eq_expr(rhs, then_rhs)
+            && let ExprKind::Binary(op, first, second) = cond.kind
+            && op.node == BinOpKind::And
+            && (is_floor_correction(cx, first, second, lhs, rhs) || is_floor_correction(cx, second, first, lhs, rhs))
+        {
+            let lhs = Sugg::hir(cx, lhs, "..").maybe_par();
+            let rhs = Sugg::hir(cx, rhs, "..");
+            span_lint_and_help(
+                cx,
+                MANUAL_DIV_FLOOR,
+                expr.span,
+                "manual implementation of floor division",
+                None,
+                &format!("use `{lhs}.div_floor({rhs})` instead"),
+            );
+        }
+    }
+}
+
+/// Matches `lhs / rhs`.
+fn division<'tcx>(expr: &'tcx Expr<'tcx>) -> Option<(&'tcx Expr<'tcx>, &'tcx Expr<'tcx>)> {
+    if let ExprKind::Binary(op, lhs, rhs) = expr.kind
+        && op.node == BinOpKind::Div
+    {
+        Some((lhs, rhs))
+    } else {
+        None
+    }
+}
+
+/// Checks for `lhs % rhs != 0` followed by `(lhs < 0) != (rhs < 0)`.
+fn is_floor_correction(
+    cx: &LateContext<'_>,
+    inexact: &Expr<'_>,
+    signs_differ: &Expr<'_>,
+    lhs: &Expr<'_>,
+    rhs: &Expr<'_>,
+) -> bool {
+    let is_negative = |e: &Expr<'_>, operand: &Expr<'_>| {
+        matches!(e.kind, ExprKind::Binary(op, left, zero)
+            if op.node == BinOpKind::Lt && is_integer_literal(zero, 0) && SpanlessEq::new(cx).eq_expr(left, operand))
+    };
+
+    if let ExprKind::Binary(op, rem, zero) = inexact.kind
+        && op.node == BinOpKind::Ne
+        && is_integer_literal(zero, 0)
+        && let ExprKind::Binary(op, rem_lhs, rem_rhs) = rem.kind
+        && op.node == BinOpKind::Rem
+        && SpanlessEq::new(cx).eq_expr(rem_lhs, lhs)
+        && SpanlessEq::new(cx).eq_expr(rem_rhs, rhs)
+        && let ExprKind::Binary(op, left, right) = signs_differ.kind
+        && op.node == BinOpKind::Ne
+    {
+        (is_negative(left, lhs) && is_negative(right, rhs)) || (is_negative(left, rhs) && is_negative(right, lhs))
+    } else {
+        false
+    }
+}
@@ -0,0 +1,127 @@
+use clippy_utils::diagnostics::span_lint_and_help;
+use clippy_utils::eager_or_lazy::switch_to_eager_eval;
+use clippy_utils::source::snippet;
+use clippy_utils::ty::is_type_diagnostic_item;
+use clippy_utils::{higher, is_res_lang_ctor, path_res, peel_blocks_with_stmt, SpanlessEq};
+use rustc_hir::LangItem::OptionSome;
+use rustc_hir::{Block, Expr, ExprKind, StmtKind, UnOp};
+use rustc_lint::{LateContext, LateLintPass, LintContext};
+use rustc_middle::lint::in_external_macro;
+use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_span::sym;
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for an `Option` which is set to `Some` if it is `None` and then unwrapped with
+    /// `as_mut().unwrap()`.
+    ///
+    /// ### Why is this bad?
+    /// `Option::get_or_insert_with` does the same thing without the `unwrap`, which can't
+    /// panic here but makes readers check that.
+    ///
+    /// ### Example
+    /// ```rust
+    /// # fn compute() -> u32 { 1 }
+    /// # let mut opt = None;
+    /// if opt.is_none() {
+    ///     opt = Some(compute());
+    /// }
+    /// let value = opt.as_mut().unwrap();
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # fn compute() -> u32 { 1 }
+    /// # let mut opt = None;
+    /// let value = opt.get_or_insert_with(compute);
+    /// ```
+    #[clippy::version = "1.73.0"]
+    pub MANUAL_OPTION_GET_OR_INSERT,
+    nursery,
+    "setting an `Option` if it is `None` and then unwrapping it instead of using `get_or_insert_with`"
+}
+declare_lint_pass!(ManualGetOrInsert => [MANUAL_OPTION_GET_OR_INSERT]);
+
+impl<'tcx> LateLintPass<'tcx> for ManualGetOrInsert {
+    fn check_block(&mut self, cx: &LateContext<'tcx>, block: &'tcx Block<'tcx>) {
+        for (i, stmt) in block.stmts.iter().enumerate() {
+            if let StmtKind::Expr(if_expr) | StmtKind::Semi(if_expr) = stmt.kind
+                && !in_external_macro(cx.sess(), stmt.span)
+                && !stmt.span.from_expansion()
+                && let Some(higher::If {
+                    cond,
+                    then,
+                    r#else: None,
+                }) = higher::If::hir(if_expr)
+                && let ExprKind::MethodCall(is_none, opt, [], _) = cond.kind
+                && is_none.ident.name == sym!(is_none)
+                && is_type_diagnostic_item(cx, cx.typeck_results().expr_ty(opt), sym::Option)
+                && let ExprKind::Assign(place, some, _) = peel_blocks_with_stmt(then).kind
+                && let ExprKind::Call(ctor, [value]) = some.kind
+                && is_res_lang_ctor(cx, path_res(cx, ctor), OptionSome)
+                && let Some((unwrap, unwrapped, true)) = next_expr(block, i).and_then(find_unwrap)
+                && !unwrap.span.from_expansion()
+                // The same place has to be checked, assigned and unwrapped.
+                && SpanlessEq::new(cx).deny_side_effects().eq_expr(opt, peel_deref(place))
+                && SpanlessEq::new(cx).deny_side_effects().eq_expr(opt, unwrapped)
+            {
+                let opt = snippet(cx, opt.span, "..");
+                // `get_or_insert` evaluates the value even if the option is `Some`, so only use it if
+                // that's cheap and has no side effects.
+                let sugg = if switch_to_eager_eval(cx, value) {
+                    format!("{opt}.get_or_insert({})", snippet(cx, value.span, ".."))
+                } else if let ExprKind::Call(func, []) = value.kind
+                    && let ExprKind::Path(_) = func.kind
+                {
+                    format!("{opt}.get_or_insert_with({})", snippet(cx, func.span, ".."))
+                } else {
+                    format!("{opt}.get_or_insert_with(|| {})", snippet(cx, value.span, ".."))
+                };
+                span_lint_and_help(
+                    cx,
+                    MANUAL_OPTION_GET_OR_INSERT,
+                    stmt.span.to(unwrap.span),
+                    "manual implementation of `Option::get_or_insert`",
+                    None,
+                    &format!("remove the `if` and use `{sugg}`"),
+                );
+            }
+        }
+    }
+}
+
+/// Returns the expression of the statement following the `i`th one of the block, or the block's
+/// trailing expression if it's the last one.
+pub(crate) fn next_expr<'tcx>(block: &'tcx Block<'tcx>, i: usize) -> Option<&'tcx Expr<'tcx>> {
+    match block.stmts.get(i + 1) {
+        Some(stmt) => match stmt.kind {
+            StmtKind::Local(local) => local.init,
+            StmtKind::Expr(e) | StmtKind::Semi(e) => Some(e),
+            StmtKind::Item(_) => None,
+        },
+        None => block.expr,
+    }
+}
+
+/// Finds `<opt>.as_mut().unwrap()` or `<opt>.as_ref().unwrap()` in the receivers of a method call
+/// chain, returning the call to `unwrap`, `<opt>` and whether `as_mut` is used.
+pub(crate) fn find_unwrap<'tcx>(mut expr: &'tcx Expr<'tcx>) -> Option<(&'tcx Expr<'tcx>, &'tcx Expr<'tcx>, bool)> {
+    while let ExprKind::MethodCall(path, recv, _, _) = expr.kind {
+        if path.ident.name == sym::unwrap
+            && let ExprKind::MethodCall(as_method, opt, [], _) = recv.kind
+            && matches!(as_method.ident.as_str(), "as_mut" | "as_ref")
+        {
+            return Some((expr, opt, as_method.ident.name == sym!(as_mut)));
+        }
+        expr = recv;
+    }
+    None
+}
+
+/// Removes the dereference of `*opt = ..` when assigning through a reference.
+pub(crate) fn peel_deref<'tcx>(expr: &'tcx Expr<'tcx>) -> &'tcx Expr<'tcx> {
+    if let ExprKind::Unary(UnOp::Deref, inner) = expr.kind {
+        inner
+    } else {
+        expr
+    }
+}
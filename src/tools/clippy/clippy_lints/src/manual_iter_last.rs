@@ -0,0 +1,109 @@
+use clippy_utils::diagnostics::span_lint_and_help;
+use clippy_utils::higher::ForLoop;
+use clippy_utils::sugg::Sugg;
+use clippy_utils::ty::{implements_trait, is_type_diagnostic_item};
+use clippy_utils::{is_res_lang_ctor, path_res, path_to_local_id, peel_blocks_with_stmt};
+use rustc_hir::LangItem::{OptionNone, OptionSome};
+use rustc_hir::{BindingAnnotation, Block, BorrowKind, Expr, ExprKind, Local, Mutability, PatKind, StmtKind};
+use rustc_lint::{LateContext, LateLintPass, LintContext};
+use rustc_middle::lint::in_external_macro;
+use rustc_middle::ty;
+use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_span::sym;
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for a variable initialized to `None` which a `for` loop then overwrites with every
+    /// element of an iterable.
+    ///
+    /// ### Why is this bad?
+    /// `Iterator::last` does the same thing without the mutable variable. Slices, arrays and
+    /// `Vec`s have a `last` method of their own which doesn't need to go through all elements.
+    ///
+    /// ### Example
+    /// ```rust
+    /// # let xs = vec![1, 2, 3];
+    /// let mut last = None;
+    /// for x in &xs {
+    ///     last = Some(x);
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # let xs = vec![1, 2, 3];
+    /// let last = xs.last();
+    /// ```
+    #[clippy::version = "1.73.0"]
+    pub MANUAL_ITERATOR_LAST,
+    nursery,
+    "overwriting a variable with every element of an iterable in a loop instead of using `last`"
+}
+declare_lint_pass!(ManualIterLast => [MANUAL_ITERATOR_LAST]);
+
+impl<'tcx> LateLintPass<'tcx> for ManualIterLast {
+    fn check_block(&mut self, cx: &LateContext<'tcx>, block: &'tcx Block<'tcx>) {
+        for window in block.stmts.windows(2) {
+            if let [init_stmt, loop_stmt] = window
+                && !in_external_macro(cx.sess(), init_stmt.span)
+                && !init_stmt.span.from_expansion()
+                && !loop_stmt.span.from_expansion()
+                && let StmtKind::Local(Local {
+                    pat,
+                    init: Some(init),
+                    els: None,
+                    ..
+                }) = init_stmt.kind
+                && let PatKind::Binding(BindingAnnotation::MUT, last_id, last_name, None) = pat.kind
+                && is_res_lang_ctor(cx, path_res(cx, init), OptionNone)
+                && let StmtKind::Expr(loop_expr) | StmtKind::Semi(loop_expr) = loop_stmt.kind
+                && let Some(for_loop) = ForLoop::hir(loop_expr)
+                && let PatKind::Binding(_, elem_id, _, None) = for_loop.pat.kind
+                && let ExprKind::Assign(lhs, rhs, _) = peel_blocks_with_stmt(for_loop.body).kind
+                && path_to_local_id(lhs, last_id)
+                // Only the element itself, e.g. not `Some(x * 2)`
+                && let ExprKind::Call(some, [elem]) = rhs.kind
+                && is_res_lang_ctor(cx, path_res(cx, some), OptionSome)
+                && path_to_local_id(elem, elem_id)
+            {
+                let help = if let Some(slice) = borrowed_slice(cx, for_loop.arg) {
+                    format!(
+                        "consider using the `last` method of the slice, which doesn't go through all elements: \
+                        `let {last_name} = {}.last();`",
+                        Sugg::hir(cx, slice, "..").maybe_par(),
+                    )
+                } else {
+                    let iter = if let Some(iter_id) = cx.tcx.get_diagnostic_item(sym::Iterator)
+                        && implements_trait(cx, cx.typeck_results().expr_ty(for_loop.arg), iter_id, &[])
+                    {
+                        Sugg::hir(cx, for_loop.arg, "..").maybe_par().to_string()
+                    } else {
+                        format!("{}.into_iter()", Sugg::hir(cx, for_loop.arg, "..").maybe_par())
+                    };
+                    format!("consider using `last`: `let {last_name} = {iter}.last();`")
+                };
+                span_lint_and_help(
+                    cx,
+                    MANUAL_ITERATOR_LAST,
+                    init_stmt.span.to(loop_stmt.span),
+                    "manual implementation of `Iterator::last`",
+                    None,
+                    &help,
+                );
+            }
+        }
+    }
+}
+
+/// Matches `&xs`, `xs.iter()` and a shared reference `xs` where `xs` is a slice, an array or a
+/// `Vec`, and returns `xs`. The elements are borrowed in all cases, so `xs.last()` returns the same
+/// value.
+fn borrowed_slice<'tcx>(cx: &LateContext<'tcx>, arg: &'tcx Expr<'tcx>) -> Option<&'tcx Expr<'tcx>> {
+    let slice = match arg.kind {
+        ExprKind::AddrOf(BorrowKind::Ref, Mutability::Not, slice) => slice,
+        ExprKind::MethodCall(path, recv, [], _) if path.ident.name == sym::iter => recv,
+        _ if matches!(cx.typeck_results().expr_ty(arg).kind(), ty::Ref(_, _, Mutability::Not)) => arg,
+        _ => return None,
+    };
+    let ty = cx.typeck_results().expr_ty(slice).peel_refs();
+    (ty.is_slice() || ty.is_array() || is_type_diagnostic_item(cx, ty, sym::Vec)).then_some(slice)
+}
@@ -0,0 +1,136 @@
+use clippy_utils::consts::{constant, Constant};
+use clippy_utils::diagnostics::span_lint_and_help;
+use clippy_utils::source::snippet;
+use clippy_utils::{higher, path_to_local, path_to_local_id};
+use rustc_hir::{BinOpKind, Expr, ExprKind, HirId, StmtKind};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::ty;
+use rustc_middle::ty::layout::IntegerExt;
+use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_target::abi::Integer;
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for `while` loops which count the leading or trailing zero bits of an unsigned
+    /// integer by shifting it one bit at a time.
+    ///
+    /// ### Why is this bad?
+    /// `leading_zeros` and `trailing_zeros` do the same in a single instruction on most targets.
+    ///
+    /// ### Known problems
+    /// These loops never terminate if the integer is zero, while the methods return its bit width.
+    ///
+    /// ### Example
+    /// ```rust
+    /// # let mut x = 12_u32;
+    /// let mut count = 0;
+    /// while (x & 0x8000_0000) == 0 {
+    ///     x <<= 1;
+    ///     count += 1;
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # let x = 12_u32;
+    /// let count = x.leading_zeros();
+    /// ```
+    #[clippy::version = "1.73.0"]
+    pub MANUAL_LEADING_ZEROS,
+    nursery,
+    "counting leading or trailing zero bits with a loop instead of `leading_zeros` or `trailing_zeros`"
+}
+declare_lint_pass!(ManualLeadingZeros => [MANUAL_LEADING_ZEROS]);
+
+impl<'tcx> LateLintPass<'tcx> for ManualLeadingZeros {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) {
+        if let Some(higher::While { condition, body, .. }) = higher::While::hir(expr)
+            && !expr.span.from_expansion()
+            && let Some((value, shift_op)) = tested_bit(cx, condition)
+            && let ExprKind::Block(block, None) = body.kind
+            && block.expr.is_none()
+            && let [first, second] = block.stmts
+            && let StmtKind::Semi(first) | StmtKind::Expr(first) = first.kind
+            && let StmtKind::Semi(second) | StmtKind::Expr(second) = second.kind
+            && let Some(value_id) = path_to_local(value)
+            && ((is_shift_by_one(cx, first, value_id, shift_op) && is_increment(cx, second, value_id))
+                || (is_shift_by_one(cx, second, value_id, shift_op) && is_increment(cx, first, value_id)))
+        {
+            let method = if shift_op == BinOpKind::Shl {
+                "leading_zeros"
+            } else {
+                "trailing_zeros"
+            };
+            span_lint_and_help(
+                cx,
+                MANUAL_LEADING_ZEROS,
+                expr.span,
+                &format!("manual implementation of `{method}`"),
+                None,
+                &format!(
+                    "consider using `{}.{method}()`, which returns the bit width instead of looping forever for `0`",
+                    snippet(cx, value.span, ".."),
+                ),
+            );
+        }
+    }
+}
+
+/// Matches `(x & MASK) == 0` where `MASK` selects either the highest or the lowest bit of the
+/// unsigned integer `x`. Returns `x` and the shift which moves the next bit into the tested one.
+fn tested_bit<'tcx>(cx: &LateContext<'tcx>, cond: &'tcx Expr<'tcx>) -> Option<(&'tcx Expr<'tcx>, BinOpKind)> {
+    let ExprKind::Binary(op, lhs, rhs) = cond.kind else {
+        return None;
+    };
+    if op.node != BinOpKind::Eq {
+        return None;
+    }
+    let masked = match (constant(cx, cx.typeck_results(), lhs), constant(cx, cx.typeck_results(), rhs)) {
+        (Some(Constant::Int(0)), None) => rhs,
+        (None, Some(Constant::Int(0))) => lhs,
+        _ => return None,
+    };
+    let ExprKind::Binary(op, lhs, rhs) = masked.kind else {
+        return None;
+    };
+    if op.node != BinOpKind::BitAnd {
+        return None;
+    }
+    let (value, mask) = match (constant(cx, cx.typeck_results(), lhs), constant(cx, cx.typeck_results(), rhs)) {
+        (None, Some(Constant::Int(mask))) => (lhs, mask),
+        (Some(Constant::Int(mask)), None) => (rhs, mask),
+        _ => return None,
+    };
+    let ty::Uint(uint_ty) = *cx.typeck_results().expr_ty(value).kind() else {
+        return None;
+    };
+    let bits = Integer::from_uint_ty(&cx.tcx, uint_ty).size().bits();
+    if mask == 1 << (bits - 1) {
+        Some((value, BinOpKind::Shl))
+    } else if mask == 1 {
+        Some((value, BinOpKind::Shr))
+    } else {
+        None
+    }
+}
+
+/// Matches `x <<= 1` or `x >>= 1`, depending on `shift_op`.
+fn is_shift_by_one(cx: &LateContext<'_>, expr: &Expr<'_>, value_id: HirId, shift_op: BinOpKind) -> bool {
+    matches!(
+        expr.kind,
+        ExprKind::AssignOp(op, target, amount)
+            if op.node == shift_op
+                && path_to_local_id(target, value_id)
+                && constant(cx, cx.typeck_results(), amount) == Some(Constant::Int(1))
+    )
+}
+
+/// Matches `count += 1` where `count` isn't the shifted value.
+fn is_increment(cx: &LateContext<'_>, expr: &Expr<'_>, value_id: HirId) -> bool {
+    matches!(
+        expr.kind,
+        ExprKind::AssignOp(op, target, amount)
+            if op.node == BinOpKind::Add
+                && !path_to_local_id(target, value_id)
+                && constant(cx, cx.typeck_results(), amount) == Some(Constant::Int(1))
+    )
+}
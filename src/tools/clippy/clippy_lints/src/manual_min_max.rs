@@ -0,0 +1,105 @@
+use clippy_utils::diagnostics::{span_lint_and_help, span_lint_and_sugg};
+use clippy_utils::sugg::Sugg;
+use clippy_utils::ty::implements_trait;
+use clippy_utils::{eq_expr_value, higher, in_constant, is_else_clause, peel_blocks};
+use rustc_errors::Applicability;
+use rustc_hir::{BinOpKind, Expr, ExprKind};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_span::sym;
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for `if` expressions which compare two values and return the larger or the smaller
+    /// one of them.
+    ///
+    /// ### Why is this bad?
+    /// `max` and `min` state the intent directly.
+    ///
+    /// ### Known problems
+    /// For floats, `max` and `min` return the other value if one of them is NaN, unlike the
+    /// comparison. The lint doesn't offer an automatic fix for them.
+    ///
+    /// ### Example
+    /// ```rust
+    /// # let (a, b) = (1, 2);
+    /// let larger = if a > b { a } else { b };
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # let (a, b) = (1, 2);
+    /// let larger = b.max(a);
+    /// ```
+    #[clippy::version = "1.73.0"]
+    pub MANUAL_MIN_MAX_CHAIN,
+    complexity,
+    "comparing two values in an `if` to pick the larger or smaller one, instead of using `max` or `min`"
+}
+declare_lint_pass!(ManualMinMax => [MANUAL_MIN_MAX_CHAIN]);
+
+impl<'tcx> LateLintPass<'tcx> for ManualMinMax {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) {
+        if let Some(higher::If {
+            cond,
+            then,
+            r#else: Some(r#else),
+        }) = higher::If::hir(expr)
+            && let ExprKind::Binary(op, lhs, rhs) = cond.kind
+            && !expr.span.from_expansion()
+            && let Some((larger, smaller, strict)) = match op.node {
+                BinOpKind::Gt => Some((lhs, rhs, true)),
+                BinOpKind::Ge => Some((lhs, rhs, false)),
+                BinOpKind::Lt => Some((rhs, lhs, true)),
+                BinOpKind::Le => Some((rhs, lhs, false)),
+                _ => None,
+            }
+            && let then = peel_blocks(then)
+            && let r#else = peel_blocks(r#else)
+            && let Some(method) = if eq_expr_value(cx, then, larger) && eq_expr_value(cx, r#else, smaller) {
+                Some("max")
+            } else if eq_expr_value(cx, then, smaller) && eq_expr_value(cx, r#else, larger) {
+                Some("min")
+            } else {
+                None
+            }
+            && let ty = cx.typeck_results().expr_ty(lhs)
+            && ty == cx.typeck_results().expr_ty(rhs)
+            && !in_constant(cx, expr.hir_id)
+        {
+            // When both values are equal, the `if` returns the `then` branch for `>=` and `<=` and
+            // the `else` branch otherwise. `max` returns its argument in that case, `min` returns
+            // its receiver.
+            let (on_equal, other) = if strict { (r#else, then) } else { (then, r#else) };
+            let (recv, arg) = if method == "max" {
+                (other, on_equal)
+            } else {
+                (on_equal, other)
+            };
+
+            let mut app = Applicability::MachineApplicable;
+            let ctxt = expr.span.ctxt();
+            let recv = Sugg::hir_with_context(cx, recv, ctxt, "..", &mut app).maybe_par();
+            let arg = Sugg::hir_with_context(cx, arg, ctxt, "..", &mut app);
+            let mut sugg = format!("{recv}.{method}({arg})");
+            if is_else_clause(cx.tcx, expr) {
+                sugg = format!("{{ {sugg} }}");
+            }
+
+            let msg = &format!("manual implementation of `{method}`");
+            if ty.is_floating_point() {
+                span_lint_and_help(
+                    cx,
+                    MANUAL_MIN_MAX_CHAIN,
+                    expr.span,
+                    msg,
+                    None,
+                    &format!("consider using `{sugg}`, but note that it doesn't return NaN if only one value is NaN"),
+                );
+            } else if let Some(ord) = cx.tcx.get_diagnostic_item(sym::Ord)
+                && implements_trait(cx, ty, ord, &[])
+            {
+                span_lint_and_sugg(cx, MANUAL_MIN_MAX_CHAIN, expr.span, msg, "try", sugg, app);
+            }
+        }
+    }
+}
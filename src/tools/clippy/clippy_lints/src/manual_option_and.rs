@@ -0,0 +1,172 @@
+use clippy_utils::diagnostics::{span_lint_and_help, span_lint_and_sugg};
+use clippy_utils::eager_or_lazy::switch_to_eager_eval;
+use clippy_utils::source::snippet_with_applicability;
+use clippy_utils::sugg::Sugg;
+use clippy_utils::ty::is_type_diagnostic_item;
+use clippy_utils::visitors::{for_each_expr_with_closures, Descend};
+use clippy_utils::{higher, is_res_lang_ctor, path_res, peel_blocks, SpanlessEq};
+use core::ops::ControlFlow;
+use rustc_errors::Applicability;
+use rustc_hir::LangItem::OptionNone;
+use rustc_hir::{Expr, ExprKind, QPath};
+use rustc_lint::{LateContext, LateLintPass, LintContext};
+use rustc_middle::lint::in_external_macro;
+use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_span::{sym, Span, Symbol};
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for `if a.is_some() { b } else { None }` where `b` is another `Option`.
+    ///
+    /// ### Why is this bad?
+    /// `Option::and` and `Option::and_then` express this directly.
+    ///
+    /// ### Example
+    /// ```rust
+    /// # let a = Some(1);
+    /// # let b = Some(2);
+    /// let c = if a.is_some() { b } else { None };
+    /// let d = if a.is_some() { Some(a.unwrap() + 1) } else { None };
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # let a = Some(1);
+    /// # let b = Some(2);
+    /// let c = a.and(b);
+    /// let d = a.and_then(|a| Some(a + 1));
+    /// ```
+    #[clippy::version = "1.73.0"]
+    pub MANUAL_OPTION_AND,
+    complexity,
+    "checking if an `Option` is `Some` to return another `Option` instead of using `and` or `and_then`"
+}
+declare_lint_pass!(ManualOptionAnd => [MANUAL_OPTION_AND]);
+
+impl<'tcx> LateLintPass<'tcx> for ManualOptionAnd {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) {
+        if let Some(higher::If {
+            cond,
+            then,
+            r#else: Some(r#else),
+        }) = higher::If::hir(expr)
+            && !in_external_macro(cx.sess(), expr.span)
+            && !expr.span.from_expansion()
+            && let ExprKind::MethodCall(is_some, opt, [], _) = cond.kind
+            && is_some.ident.name == sym!(is_some)
+            && is_type_diagnostic_item(cx, cx.typeck_results().expr_ty(opt), sym::Option)
+            && is_res_lang_ctor(cx, path_res(cx, peel_blocks(r#else)), OptionNone)
+            && let then = peel_blocks(then)
+            && !then.span.from_expansion()
+        {
+            let mut app = Applicability::MaybeIncorrect;
+            if let Some((method, sugg)) = suggestion(cx, opt, then, &mut app) {
+                span_lint_and_sugg(
+                    cx,
+                    MANUAL_OPTION_AND,
+                    expr.span,
+                    &format!("manual implementation of `Option::{method}`"),
+                    "try",
+                    sugg,
+                    app,
+                );
+            } else {
+                span_lint_and_help(
+                    cx,
+                    MANUAL_OPTION_AND,
+                    expr.span,
+                    "manual implementation of `Option::and_then`",
+                    None,
+                    "use `Option::and_then`, taking the value of the checked `Option` as the closure argument",
+                );
+            }
+        }
+    }
+}
+
+/// Builds the call replacing the `if`, along with the name of the method called. Returns `None`
+/// if the value of `then` can't be turned into a closure body.
+fn suggestion<'tcx>(
+    cx: &LateContext<'tcx>,
+    opt: &Expr<'_>,
+    then: &'tcx Expr<'tcx>,
+    app: &mut Applicability,
+) -> Option<(&'static str, String)> {
+    let unwraps = unwraps_of(cx, opt, then)?;
+    let opt_sugg = Sugg::hir_with_applicability(cx, opt, "..", app).maybe_par();
+    let then_snip = snippet_with_applicability(cx, then.span, "..", app);
+    if unwraps.is_empty() {
+        return Some(if switch_to_eager_eval(cx, then) {
+            ("and", format!("{opt_sugg}.and({then_snip})"))
+        } else {
+            // `and` would evaluate the value even if the `Option` is `None`.
+            ("and_then", format!("{opt_sugg}.and_then(|_| {then_snip})"))
+        });
+    }
+
+    let name = binding_name(opt);
+    if mentions_name(cx, then, name, &unwraps) {
+        return None;
+    }
+    let mut body = String::new();
+    let mut pos = 0;
+    for span in unwraps {
+        body.push_str(then_snip.get(pos..(span.lo() - then.span.lo()).to_usize())?);
+        body.push_str(name.as_str());
+        pos = (span.hi() - then.span.lo()).to_usize();
+    }
+    body.push_str(then_snip.get(pos..)?);
+    Some(("and_then", format!("{opt_sugg}.and_then(|{name}| {body})")))
+}
+
+/// Finds the spans of the calls to `<opt>.unwrap()` in `expr`, in source order. Returns `None` if
+/// `opt` is used some other way, or the calls can't be replaced in the snippet of `expr`.
+fn unwraps_of<'tcx>(cx: &LateContext<'tcx>, opt: &Expr<'_>, expr: &'tcx Expr<'tcx>) -> Option<Vec<Span>> {
+    let mut unwraps = Vec::new();
+    for_each_expr_with_closures(cx, expr, |e| {
+        if let ExprKind::MethodCall(path, recv, [], _) = e.kind
+            && path.ident.name == sym::unwrap
+            && SpanlessEq::new(cx).deny_side_effects().eq_expr(recv, opt)
+        {
+            if e.span.ctxt() != expr.span.ctxt() {
+                return ControlFlow::Break(());
+            }
+            unwraps.push(e.span);
+            ControlFlow::Continue(Descend::No)
+        } else if SpanlessEq::new(cx).deny_side_effects().eq_expr(e, opt) {
+            ControlFlow::Break(())
+        } else {
+            ControlFlow::Continue(Descend::Yes)
+        }
+    })
+    .is_none()
+    .then(|| {
+        unwraps.sort_by_key(|span| span.lo());
+        unwraps
+    })
+}
+
+/// The name of the closure argument replacing `<opt>.unwrap()`, taken from the checked `Option`.
+fn binding_name(opt: &Expr<'_>) -> Symbol {
+    match opt.kind {
+        ExprKind::Path(QPath::Resolved(None, path)) if let [segment] = path.segments => segment.ident.name,
+        ExprKind::Field(_, ident) => ident.name,
+        _ => sym!(x),
+    }
+}
+
+/// Checks whether `expr` refers to anything named `name`, which the closure argument would shadow.
+/// Uses of the checked `Option` which are replaced are ignored.
+fn mentions_name<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>, name: Symbol, unwraps: &[Span]) -> bool {
+    for_each_expr_with_closures(cx, expr, |e| {
+        if let ExprKind::Path(QPath::Resolved(None, path)) = e.kind
+            && let [segment] = path.segments
+            && segment.ident.name == name
+            && !unwraps.iter().any(|span| span.contains(e.span))
+        {
+            ControlFlow::Break(())
+        } else {
+            ControlFlow::Continue(())
+        }
+    })
+    .is_some()
+}
@@ -0,0 +1,98 @@
+use crate::manual_get_or_insert::{find_unwrap, next_expr, peel_deref};
+use clippy_utils::diagnostics::span_lint_and_help;
+use clippy_utils::msrvs::{self, Msrv};
+use clippy_utils::source::snippet;
+use clippy_utils::ty::is_type_diagnostic_item;
+use clippy_utils::{is_res_lang_ctor, path_res, SpanlessEq};
+use rustc_hir::LangItem::OptionSome;
+use rustc_hir::{Block, ExprKind, StmtKind};
+use rustc_lint::{LateContext, LateLintPass, LintContext};
+use rustc_middle::lint::in_external_macro;
+use rustc_session::{declare_tool_lint, impl_lint_pass};
+use rustc_span::sym;
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for an `Option` which is set to `Some` and then unwrapped with `as_mut().unwrap()`
+    /// or `as_ref().unwrap()`.
+    ///
+    /// ### Why is this bad?
+    /// `Option::insert` sets the value and returns a reference to it, without an `unwrap` that
+    /// can't panic here but makes readers check that.
+    ///
+    /// ### Known problems
+    /// `insert` returns a mutable reference, so the `Option` stays mutably borrowed while the
+    /// reference is used. This matters when replacing `as_ref().unwrap()`.
+    ///
+    /// ### Example
+    /// ```rust
+    /// let mut opt = None;
+    /// opt = Some(vec![1]);
+    /// opt.as_mut().unwrap().push(2);
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// let mut opt = None;
+    /// opt.insert(vec![1]).push(2);
+    /// ```
+    #[clippy::version = "1.73.0"]
+    pub MANUAL_OPTION_INSERT,
+    complexity,
+    "assigning `Some` to an `Option` and then unwrapping it instead of using `insert`"
+}
+
+pub struct ManualOptionInsert {
+    msrv: Msrv,
+}
+
+impl ManualOptionInsert {
+    #[must_use]
+    pub fn new(msrv: Msrv) -> Self {
+        Self { msrv }
+    }
+}
+
+impl_lint_pass!(ManualOptionInsert => [MANUAL_OPTION_INSERT]);
+
+impl<'tcx> LateLintPass<'tcx> for ManualOptionInsert {
+    fn check_block(&mut self, cx: &LateContext<'tcx>, block: &'tcx Block<'tcx>) {
+        if !self.msrv.meets(msrvs::OPTION_INSERT) {
+            return;
+        }
+        for (i, stmt) in block.stmts.iter().enumerate() {
+            if let StmtKind::Semi(assign) = stmt.kind
+                && !in_external_macro(cx.sess(), stmt.span)
+                && !stmt.span.from_expansion()
+                && let ExprKind::Assign(place, some, _) = assign.kind
+                && let ExprKind::Call(ctor, [value]) = some.kind
+                && is_res_lang_ctor(cx, path_res(cx, ctor), OptionSome)
+                && let Some((unwrap, opt, is_mut)) = next_expr(block, i).and_then(find_unwrap)
+                && !unwrap.span.from_expansion()
+                && is_type_diagnostic_item(cx, cx.typeck_results().expr_ty(opt).peel_refs(), sym::Option)
+                // The same place has to be assigned and unwrapped.
+                && SpanlessEq::new(cx).deny_side_effects().eq_expr(opt, peel_deref(place))
+            {
+                let sugg = format!(
+                    "{}.insert({})",
+                    snippet(cx, opt.span, ".."),
+                    snippet(cx, value.span, "..")
+                );
+                let help = if is_mut {
+                    format!("remove the assignment and use `{sugg}`")
+                } else {
+                    format!("remove the assignment and use `{sugg}`, which borrows the `Option` mutably")
+                };
+                span_lint_and_help(
+                    cx,
+                    MANUAL_OPTION_INSERT,
+                    stmt.span.to(unwrap.span),
+                    "manual implementation of `Option::insert`",
+                    None,
+                    &help,
+                );
+            }
+        }
+    }
+
+    extract_msrv_attr!(LateContext);
+}
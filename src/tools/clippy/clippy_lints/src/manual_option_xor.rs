@@ -0,0 +1,189 @@
+use clippy_utils::diagnostics::span_lint_and_sugg;
+use clippy_utils::msrvs::{self, Msrv};
+use clippy_utils::source::snippet_with_context;
+use clippy_utils::sugg::Sugg;
+use clippy_utils::ty::is_type_diagnostic_item;
+use clippy_utils::{is_res_lang_ctor, path_res, peel_blocks};
+use rustc_errors::Applicability;
+use rustc_hir::def::Res;
+use rustc_hir::LangItem::{OptionNone, OptionSome};
+use rustc_hir::{Arm, BindingAnnotation, Expr, ExprKind, MatchSource, Pat, PatKind, QPath};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_session::{declare_tool_lint, impl_lint_pass};
+use rustc_span::{sym, Symbol};
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for `match` expressions on a tuple of two `Option`s which return the value of
+    /// whichever one is `Some` if only one of them is, and `None` otherwise.
+    ///
+    /// ### Why is this bad?
+    /// This is exactly what `Option::xor` does.
+    ///
+    /// ### Example
+    /// ```rust
+    /// # let a = Some(1);
+    /// # let b = None;
+    /// let c = match (a, b) {
+    ///     (Some(x), None) => Some(x),
+    ///     (None, Some(y)) => Some(y),
+    ///     _ => None,
+    /// };
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # let a = Some(1);
+    /// # let b = None;
+    /// let c = a.xor(b);
+    /// ```
+    #[clippy::version = "1.73.0"]
+    pub MANUAL_OPTION_XOR,
+    complexity,
+    "manually reimplementing `Option::xor`"
+}
+
+pub struct ManualOptionXor {
+    msrv: Msrv,
+}
+
+impl ManualOptionXor {
+    #[must_use]
+    pub fn new(msrv: Msrv) -> Self {
+        Self { msrv }
+    }
+}
+
+impl_lint_pass!(ManualOptionXor => [MANUAL_OPTION_XOR]);
+
+impl<'tcx> LateLintPass<'tcx> for ManualOptionXor {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) {
+        if let ExprKind::Match(scrutinee, arms, MatchSource::Normal) = expr.kind
+            && let ExprKind::Tup([left, right]) = scrutinee.kind
+            && !expr.span.from_expansion()
+            && let left_ty = cx.typeck_results().expr_ty(left)
+            && is_type_diagnostic_item(cx, left_ty, sym::Option)
+            && left_ty == cx.typeck_results().expr_ty(right)
+            && is_xor_match(cx, arms)
+            && self.msrv.meets(msrvs::OPTION_XOR)
+        {
+            let mut app = Applicability::MachineApplicable;
+            let left = Sugg::hir_with_context(cx, left, expr.span.ctxt(), "..", &mut app);
+            let (right, _) = snippet_with_context(cx, right.span, expr.span.ctxt(), "..", &mut app);
+            span_lint_and_sugg(
+                cx,
+                MANUAL_OPTION_XOR,
+                expr.span,
+                "this `match` reimplements `Option::xor`",
+                "try",
+                format!("{}.xor({right})", left.maybe_par()),
+                app,
+            );
+        }
+    }
+
+    extract_msrv_attr!(LateContext);
+}
+
+/// A pattern matching a tuple of two `Option`s.
+enum TuplePat {
+    /// `(Some(x), None)`, with the name of the binding.
+    Left(Symbol),
+    /// `(None, Some(x))`, with the name of the binding.
+    Right(Symbol),
+    /// `(Some(_), Some(_))`
+    BothSome,
+    /// `(None, None)`
+    BothNone,
+    /// `_` or `(_, _)`
+    Wild,
+}
+
+/// Checks whether the arms return the unmodified value of the only `Some` and `None` for every
+/// other combination.
+fn is_xor_match(cx: &LateContext<'_>, arms: &[Arm<'_>]) -> bool {
+    let (mut left, mut right, mut both_some, mut both_none) = (false, false, false, false);
+    for (i, arm) in arms.iter().enumerate() {
+        if arm.guard.is_some() {
+            return false;
+        }
+        let body = peel_blocks(arm.body);
+        let is_none = is_res_lang_ctor(cx, path_res(cx, body), OptionNone);
+        let value = some_value(cx, body);
+        if !is_none && value.is_none() {
+            return false;
+        }
+        for pat in alternatives(arm.pat) {
+            match (tuple_pat(cx, pat), value) {
+                (Some(TuplePat::Left(name)), Some(value)) if name == value => left = true,
+                (Some(TuplePat::Right(name)), Some(value)) if name == value => right = true,
+                (Some(TuplePat::BothSome), None) => both_some = true,
+                (Some(TuplePat::BothNone), None) => both_none = true,
+                // A wildcard before the other arms would shadow them.
+                (Some(TuplePat::Wild), None) if i == arms.len() - 1 => (both_some, both_none) = (true, true),
+                _ => return false,
+            }
+        }
+    }
+    left && right && both_some && both_none
+}
+
+fn alternatives<'a, 'tcx>(pat: &'a Pat<'tcx>) -> &'a [Pat<'tcx>] {
+    if let PatKind::Or(pats) = pat.kind {
+        pats
+    } else {
+        std::slice::from_ref(pat)
+    }
+}
+
+fn tuple_pat(cx: &LateContext<'_>, pat: &Pat<'_>) -> Option<TuplePat> {
+    match pat.kind {
+        PatKind::Wild => Some(TuplePat::Wild),
+        PatKind::Tuple([left, right], None) => match (option_pat(cx, left)?, option_pat(cx, right)?) {
+            (OptionPat::Wild, OptionPat::Wild) => Some(TuplePat::Wild),
+            (OptionPat::Some(Some(name)), OptionPat::None) => Some(TuplePat::Left(name)),
+            (OptionPat::None, OptionPat::Some(Some(name))) => Some(TuplePat::Right(name)),
+            (OptionPat::Some(None), OptionPat::Some(None)) => Some(TuplePat::BothSome),
+            (OptionPat::None, OptionPat::None) => Some(TuplePat::BothNone),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+enum OptionPat {
+    /// `Some(x)` with the name of the binding, or `Some(_)`.
+    Some(Option<Symbol>),
+    None,
+    Wild,
+}
+
+fn option_pat(cx: &LateContext<'_>, pat: &Pat<'_>) -> Option<OptionPat> {
+    match pat.kind {
+        PatKind::Wild => Some(OptionPat::Wild),
+        PatKind::Path(_) if is_res_lang_ctor(cx, path_res(cx, pat), OptionNone) => Some(OptionPat::None),
+        PatKind::TupleStruct(ref qpath, [inner], None)
+            if is_res_lang_ctor(cx, cx.qpath_res(qpath, pat.hir_id), OptionSome) =>
+        {
+            match inner.kind {
+                PatKind::Wild => Some(OptionPat::Some(None)),
+                PatKind::Binding(BindingAnnotation::NONE, _, ident, None) => Some(OptionPat::Some(Some(ident.name))),
+                _ => None,
+            }
+        },
+        _ => None,
+    }
+}
+
+/// Returns the name of the local in `Some(x)`.
+fn some_value(cx: &LateContext<'_>, expr: &Expr<'_>) -> Option<Symbol> {
+    if let ExprKind::Call(ctor, [arg]) = expr.kind
+        && is_res_lang_ctor(cx, path_res(cx, ctor), OptionSome)
+        && let ExprKind::Path(QPath::Resolved(None, path)) = arg.kind
+        && let [segment] = path.segments
+        && matches!(path.res, Res::Local(_))
+    {
+        Some(segment.ident.name)
+    } else {
+        None
+    }
+}
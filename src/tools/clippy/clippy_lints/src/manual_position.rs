@@ -0,0 +1,154 @@
+use clippy_utils::diagnostics::span_lint_and_help;
+use clippy_utils::source::snippet;
+use clippy_utils::visitors::is_local_used;
+use clippy_utils::{higher, is_res_lang_ctor, is_trait_method, path_res, path_to_local_id};
+use rustc_hir::LangItem::{OptionNone, OptionSome};
+use rustc_hir::{BindingAnnotation, Block, Expr, ExprKind, HirId, Local, Pat, PatKind, Stmt, StmtKind};
+use rustc_lint::{LateContext, LateLintPass, LintContext};
+use rustc_middle::lint::in_external_macro;
+use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_span::sym;
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for `for` loops over an enumerated iterator which store the index or the element of
+    /// the first match in an `Option` and break.
+    ///
+    /// ### Why is this bad?
+    /// `Iterator::position` and `Iterator::find` do the same and say what the loop is for.
+    ///
+    /// ### Example
+    /// ```rust
+    /// # let xs = [1, 2, 3];
+    /// let mut idx = None;
+    /// for (i, x) in xs.iter().enumerate() {
+    ///     if *x == 2 {
+    ///         idx = Some(i);
+    ///         break;
+    ///     }
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # let xs = [1, 2, 3];
+    /// let idx = xs.iter().position(|x| *x == 2);
+    /// ```
+    #[clippy::version = "1.73.0"]
+    pub MANUAL_ITERATOR_POSITION,
+    nursery,
+    "searching for the first match with an enumerated `for` loop instead of `position` or `find`"
+}
+declare_lint_pass!(ManualPosition => [MANUAL_ITERATOR_POSITION]);
+
+impl<'tcx> LateLintPass<'tcx> for ManualPosition {
+    fn check_block(&mut self, cx: &LateContext<'tcx>, block: &'tcx Block<'tcx>) {
+        for window in block.stmts.windows(2) {
+            if let [local, stmt] = window
+                && let StmtKind::Expr(for_loop) | StmtKind::Semi(for_loop) = stmt.kind
+            {
+                check_search(cx, local, for_loop);
+            }
+        }
+        if let (Some(local), Some(for_loop)) = (block.stmts.last(), block.expr) {
+            check_search(cx, local, for_loop);
+        }
+    }
+}
+
+fn check_search<'tcx>(cx: &LateContext<'tcx>, local: &'tcx Stmt<'tcx>, expr: &'tcx Expr<'tcx>) {
+    if let StmtKind::Local(Local {
+        pat,
+        init: Some(init),
+        els: None,
+        ty: None,
+        ..
+    }) = local.kind
+        && let PatKind::Binding(BindingAnnotation::MUT, result_id, _, None) = pat.kind
+        && is_res_lang_ctor(cx, path_res(cx, init), OptionNone)
+        && !in_external_macro(cx.sess(), local.span)
+        && !expr.span.from_expansion()
+        && let Some(higher::ForLoop {
+            pat: loop_pat,
+            arg,
+            body,
+            loop_id,
+            ..
+        }) = higher::ForLoop::hir(expr)
+        && let PatKind::Tuple([index_pat, elem_pat], dotdot) = loop_pat.kind
+        && dotdot.as_opt_usize().is_none()
+        && let Some(index_id) = binding_or_wild(index_pat)
+        && let ExprKind::MethodCall(enumerate, iter, [], _) = arg.kind
+        && enumerate.ident.name == sym!(enumerate)
+        && is_trait_method(cx, arg, sym::Iterator)
+        && let Some([if_expr]) = block_exprs(body).as_deref()
+        && let Some(higher::If { cond, then, r#else: None }) = higher::If::hir(if_expr)
+        && let Some([assign, brk]) = block_exprs(then).as_deref()
+        && let ExprKind::Break(dest, None) = brk.kind
+        && dest.target_id == Ok(loop_id)
+        && let ExprKind::Assign(target, value, _) = assign.kind
+        && path_to_local_id(target, result_id)
+        && let ExprKind::Call(some, [stored]) = value.kind
+        && is_res_lang_ctor(cx, path_res(cx, some), OptionSome)
+        // With the index the match can't be found by `position` or `find` alone
+        && index_id.map_or(true, |id| !is_local_used(cx, cond, id))
+        && !is_local_used(cx, cond, result_id)
+    {
+        let (method, help) = if index_id.is_some_and(|id| path_to_local_id(stored, id)) {
+            (
+                "position",
+                format!(
+                    "consider using `{}.position(|{}| {})`",
+                    snippet(cx, iter.span, ".."),
+                    snippet(cx, elem_pat.span, ".."),
+                    snippet(cx, cond.span, ".."),
+                ),
+            )
+        } else if let PatKind::Binding(_, elem_id, _, None) = elem_pat.kind
+            && path_to_local_id(stored, elem_id)
+        {
+            (
+                "find",
+                format!(
+                    "consider using `{}.find(..)`, whose closure takes the element by reference",
+                    snippet(cx, iter.span, ".."),
+                ),
+            )
+        } else {
+            return;
+        };
+        span_lint_and_help(
+            cx,
+            MANUAL_ITERATOR_POSITION,
+            local.span.to(expr.span),
+            &format!("manual implementation of `Iterator::{method}`"),
+            None,
+            &help,
+        );
+    }
+}
+
+/// Returns `Some(Some(id))` for a simple binding and `Some(None)` for `_`.
+fn binding_or_wild(pat: &Pat<'_>) -> Option<Option<HirId>> {
+    match pat.kind {
+        PatKind::Binding(_, id, _, None) => Some(Some(id)),
+        PatKind::Wild => Some(None),
+        _ => None,
+    }
+}
+
+/// Returns the statements and the trailing expression of a block expression, if it only contains
+/// expressions.
+fn block_exprs<'tcx>(expr: &'tcx Expr<'tcx>) -> Option<Vec<&'tcx Expr<'tcx>>> {
+    let ExprKind::Block(block, None) = expr.kind else {
+        return None;
+    };
+    block
+        .stmts
+        .iter()
+        .map(|stmt| match stmt.kind {
+            StmtKind::Expr(e) | StmtKind::Semi(e) => Some(e),
+            _ => None,
+        })
+        .chain(block.expr.map(Some))
+        .collect()
+}
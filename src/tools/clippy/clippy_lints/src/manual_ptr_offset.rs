@@ -0,0 +1,164 @@
+use clippy_utils::diagnostics::span_lint_and_help;
+use clippy_utils::source::snippet;
+use clippy_utils::sugg::Sugg;
+use clippy_utils::ty::approx_ty_size;
+use rustc_hir::{BinOpKind, Expr, ExprKind, Mutability};
+use rustc_lint::{LateContext, LateLintPass, LintContext};
+use rustc_middle::lint::in_external_macro;
+use rustc_middle::ty::{self, Ty, TypeAndMut};
+use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_span::sym;
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for raw pointers which are offset by casting them to `usize`, doing the arithmetic
+    /// on the integer and casting the result back to a pointer.
+    ///
+    /// ### Why is this bad?
+    /// `wrapping_add` and `wrapping_sub` do the same, but scale the offset by the size of the
+    /// pointee and keep the provenance of the original pointer, so the result can still be used
+    /// to access the same allocation.
+    ///
+    /// ### Example
+    /// ```rust
+    /// # let v = [1_u32, 2, 3];
+    /// # let ptr = v.as_ptr();
+    /// let second = (ptr as usize + std::mem::size_of::<u32>()) as *const u32;
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # let v = [1_u32, 2, 3];
+    /// # let ptr = v.as_ptr();
+    /// let second = ptr.wrapping_add(1);
+    /// ```
+    #[clippy::version = "1.73.0"]
+    pub MANUAL_WRAPPING_OFFSET,
+    pedantic,
+    "offsetting a pointer by casting it to `usize` instead of using `wrapping_add` or `wrapping_sub`"
+}
+declare_lint_pass!(ManualPtrOffset => [MANUAL_WRAPPING_OFFSET]);
+
+impl<'tcx> LateLintPass<'tcx> for ManualPtrOffset {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) {
+        if let ExprKind::Cast(int_expr, target_hir_ty) = expr.kind
+            && !in_external_macro(cx.sess(), expr.span)
+            && !expr.span.from_expansion()
+            && let typeck = cx.typeck_results()
+            && let target_ty = typeck.expr_ty(expr)
+            && target_ty.is_unsafe_ptr()
+            && typeck.expr_ty(int_expr).is_usize()
+            && let Some((ptr, offset, method)) = ptr_int_arithmetic(cx, int_expr)
+            && let ptr_ty = typeck.expr_ty(ptr)
+            && let ty::RawPtr(TypeAndMut { ty: pointee, mutbl }) = *ptr_ty.kind()
+            && !int_expr.span.from_expansion()
+        {
+            let ptr_sugg = Sugg::hir(cx, ptr, "..");
+            let target_snip = snippet(cx, target_hir_ty.span, "..");
+            let help = if let Some(count) = scaled_count(cx, offset, pointee) {
+                if target_ty == ptr_ty {
+                    format!("use `{}.{method}({count})`", ptr_sugg.maybe_par())
+                } else {
+                    format!("use `{}.{method}({count}) as {target_snip}`", ptr_sugg.maybe_par())
+                }
+            } else {
+                let offset = snippet(cx, offset.span, "..");
+                if approx_ty_size(cx, pointee) == 1 && target_ty == ptr_ty {
+                    format!("use `{}.{method}({offset})`", ptr_sugg.maybe_par())
+                } else {
+                    // The offset is in bytes, so it has to be applied to a byte pointer.
+                    let byte_ptr = match mutbl {
+                        Mutability::Mut => "*mut u8",
+                        Mutability::Not => "*const u8",
+                    };
+                    format!(
+                        "use `{}.{method}({offset}) as {target_snip}`",
+                        ptr_sugg.as_ty(byte_ptr).maybe_par()
+                    )
+                }
+            };
+            span_lint_and_help(
+                cx,
+                MANUAL_WRAPPING_OFFSET,
+                expr.span,
+                "manual pointer offset through an integer cast",
+                None,
+                &help,
+            );
+        }
+    }
+}
+
+/// Matches `ptr as usize + offset`, `ptr as usize - offset` and their `wrapping_add` and
+/// `wrapping_sub` forms, returning the pointer, the offset and the pointer method to use.
+fn ptr_int_arithmetic<'tcx>(
+    cx: &LateContext<'tcx>,
+    expr: &'tcx Expr<'tcx>,
+) -> Option<(&'tcx Expr<'tcx>, &'tcx Expr<'tcx>, &'static str)> {
+    let (lhs, rhs, method) = match expr.kind {
+        ExprKind::Binary(op, lhs, rhs) => match op.node {
+            BinOpKind::Add => (lhs, rhs, "wrapping_add"),
+            BinOpKind::Sub => (lhs, rhs, "wrapping_sub"),
+            _ => return None,
+        },
+        ExprKind::MethodCall(path, recv, [arg], _) => match path.ident.as_str() {
+            "wrapping_add" => (recv, arg, "wrapping_add"),
+            "wrapping_sub" => (recv, arg, "wrapping_sub"),
+            _ => return None,
+        },
+        _ => return None,
+    };
+    if let Some(ptr) = ptr_to_int_cast(cx, lhs) {
+        Some((ptr, rhs, method))
+    } else if method == "wrapping_add"
+        && let Some(ptr) = ptr_to_int_cast(cx, rhs)
+    {
+        Some((ptr, lhs, method))
+    } else {
+        None
+    }
+}
+
+/// Returns `ptr` if the expression is `ptr as usize` for a raw pointer `ptr`.
+fn ptr_to_int_cast<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) -> Option<&'tcx Expr<'tcx>> {
+    if let ExprKind::Cast(ptr, _) = expr.kind
+        && cx.typeck_results().expr_ty(ptr).is_unsafe_ptr()
+    {
+        Some(ptr)
+    } else {
+        None
+    }
+}
+
+/// Returns the element count if the offset is `count * size_of::<T>()` (in either order) or
+/// `size_of::<T>()` itself, where `T` is the pointee type.
+fn scaled_count<'tcx>(cx: &LateContext<'tcx>, offset: &'tcx Expr<'tcx>, pointee: Ty<'tcx>) -> Option<String> {
+    if is_size_of(cx, offset, pointee) {
+        return Some("1".to_string());
+    }
+    if let ExprKind::Binary(op, lhs, rhs) = offset.kind
+        && op.node == BinOpKind::Mul
+    {
+        if is_size_of(cx, rhs, pointee) {
+            Some(snippet(cx, lhs.span, "..").into_owned())
+        } else if is_size_of(cx, lhs, pointee) {
+            Some(snippet(cx, rhs.span, "..").into_owned())
+        } else {
+            None
+        }
+    } else {
+        None
+    }
+}
+
+fn is_size_of<'tcx>(cx: &LateContext<'tcx>, expr: &Expr<'_>, pointee: Ty<'tcx>) -> bool {
+    if let ExprKind::Call(func, []) = expr.kind
+        && let ExprKind::Path(ref func_qpath) = func.kind
+        && let Some(def_id) = cx.qpath_res(func_qpath, func.hir_id).opt_def_id()
+        && cx.tcx.is_diagnostic_item(sym::mem_size_of, def_id)
+        && let Some(ty) = cx.typeck_results().node_args(func.hir_id).types().next()
+    {
+        ty == pointee
+    } else {
+        false
+    }
+}
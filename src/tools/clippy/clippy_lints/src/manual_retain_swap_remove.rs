@@ -0,0 +1,128 @@
+use clippy_utils::diagnostics::span_lint_and_help;
+use clippy_utils::source::snippet;
+use clippy_utils::ty::is_type_diagnostic_item;
+use clippy_utils::{higher, is_integer_literal, path_to_local_id, peel_blocks_with_stmt, SpanlessEq};
+use rustc_ast::ast::RangeLimits;
+use rustc_hir::{Expr, ExprKind, PatKind};
+use rustc_lint::{LateContext, LateLintPass, LintContext};
+use rustc_middle::lint::in_external_macro;
+use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_span::sym;
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for loops over the indices of a `Vec` in reverse which remove the elements matching
+    /// a condition with `swap_remove`.
+    ///
+    /// ### Why is this bad?
+    /// This reimplements `Vec::retain`, which states the intent directly and can't get the
+    /// indices wrong.
+    ///
+    /// ### Known problems
+    /// `retain` keeps the remaining elements in their original order, while `swap_remove` moves
+    /// the last element into the removed slot. Code relying on the resulting order will change.
+    ///
+    /// ### Example
+    /// ```rust
+    /// # let mut v = vec![1, 2, 3];
+    /// for i in (0..v.len()).rev() {
+    ///     if v[i] % 2 == 0 {
+    ///         v.swap_remove(i);
+    ///     }
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # let mut v = vec![1, 2, 3];
+    /// v.retain(|x| x % 2 != 0);
+    /// ```
+    #[clippy::version = "1.73.0"]
+    pub MANUAL_RETAIN_VIA_DRAIN_FILTER,
+    nursery,
+    "removing the elements of a `Vec` matching a condition with `swap_remove` in a reverse loop instead of using `retain`"
+}
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for loops over the indices of a `Vec` from the start which remove elements with
+    /// `swap_remove`.
+    ///
+    /// ### Why is this bad?
+    /// `swap_remove` moves the last element into the removed slot, which the loop then skips
+    /// since it continues at the next index. The range is also computed from the length before
+    /// any removal, so indexing the `Vec` in the last iterations panics.
+    ///
+    /// ### Example
+    /// ```rust,no_run
+    /// # let mut v = vec![1, 2, 3];
+    /// for i in 0..v.len() {
+    ///     if v[i] % 2 == 0 {
+    ///         v.swap_remove(i);
+    ///     }
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # let mut v = vec![1, 2, 3];
+    /// v.retain(|x| x % 2 != 0);
+    /// ```
+    #[clippy::version = "1.73.0"]
+    pub SWAP_REMOVE_IN_FORWARD_LOOP,
+    correctness,
+    "removing elements of a `Vec` with `swap_remove` while iterating over its indices from the start"
+}
+declare_lint_pass!(ManualRetainSwapRemove => [MANUAL_RETAIN_VIA_DRAIN_FILTER, SWAP_REMOVE_IN_FORWARD_LOOP]);
+
+impl<'tcx> LateLintPass<'tcx> for ManualRetainSwapRemove {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
+        if let Some(higher::ForLoop {
+            pat, arg, body, span, ..
+        }) = higher::ForLoop::hir(expr)
+            && !in_external_macro(cx.sess(), span)
+            && !span.from_expansion()
+            && let PatKind::Binding(_, index_id, _, None) = pat.kind
+            && let (range, reversed) = match arg.kind {
+                ExprKind::MethodCall(path, recv, [], _) if path.ident.name == sym!(rev) => (recv, true),
+                _ => (arg, false),
+            }
+            && let Some(higher::Range {
+                start: Some(start),
+                end: Some(end),
+                limits: RangeLimits::HalfOpen,
+            }) = higher::Range::hir(range)
+            && is_integer_literal(start, 0)
+            && let ExprKind::MethodCall(len, vec, [], _) = end.kind
+            && len.ident.name == sym::len
+            && is_type_diagnostic_item(cx, cx.typeck_results().expr_ty(vec).peel_refs(), sym::Vec)
+            && let Some(higher::If { then, r#else: None, .. }) = higher::If::hir(peel_blocks_with_stmt(body))
+            && let ExprKind::MethodCall(swap_remove, removed_from, [removed], _) = peel_blocks_with_stmt(then).kind
+            && swap_remove.ident.name == sym!(swap_remove)
+            && path_to_local_id(removed, index_id)
+            && SpanlessEq::new(cx).deny_side_effects().eq_expr(vec, removed_from)
+        {
+            let vec = snippet(cx, vec.span, "..");
+            if reversed {
+                span_lint_and_help(
+                    cx,
+                    MANUAL_RETAIN_VIA_DRAIN_FILTER,
+                    span,
+                    "manual implementation of `Vec::retain`",
+                    None,
+                    &format!(
+                        "use `{vec}.retain(..)` keeping the elements the condition is `false` for, \
+                        note that the remaining elements keep their original order"
+                    ),
+                );
+            } else {
+                span_lint_and_help(
+                    cx,
+                    SWAP_REMOVE_IN_FORWARD_LOOP,
+                    span,
+                    "`swap_remove` while iterating over the indices from the start skips elements",
+                    None,
+                    &format!("use `{vec}.retain(..)` keeping the elements the condition is `false` for"),
+                );
+            }
+        }
+    }
+}
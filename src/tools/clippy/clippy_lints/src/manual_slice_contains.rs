@@ -0,0 +1,122 @@
+use clippy_utils::diagnostics::span_lint_and_sugg;
+use clippy_utils::eager_or_lazy::switch_to_eager_eval;
+use clippy_utils::sugg::Sugg;
+use clippy_utils::visitors::is_local_used;
+use clippy_utils::{is_trait_method, path_to_local_id, peel_blocks};
+use rustc_errors::Applicability;
+use rustc_hir::{BinOpKind, Closure, Expr, ExprKind, HirId, PatKind, UnOp};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::ty::{self, GenericArgKind, Ty};
+use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_span::sym;
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for `iter().any(..)` on a slice with a closure which compares the element to a
+    /// value.
+    ///
+    /// ### Why is this bad?
+    /// `contains` states the intent directly, and is specialized for some element types.
+    ///
+    /// ### Example
+    /// ```rust
+    /// # let xs = [1, 2, 3];
+    /// # let target = 2;
+    /// let found = xs.iter().any(|x| *x == target);
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # let xs = [1, 2, 3];
+    /// # let target = 2;
+    /// let found = xs.contains(&target);
+    /// ```
+    #[clippy::version = "1.73.0"]
+    pub MANUAL_SLICE_CONTAINS,
+    perf,
+    "using `iter().any(..)` to check whether a slice contains a value instead of `contains`"
+}
+declare_lint_pass!(ManualSliceContains => [MANUAL_SLICE_CONTAINS]);
+
+impl<'tcx> LateLintPass<'tcx> for ManualSliceContains {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) {
+        if let ExprKind::MethodCall(any_path, iter_call, [closure], _) = expr.kind
+            && any_path.ident.name == sym::any
+            && !expr.span.from_expansion()
+            && let ExprKind::MethodCall(iter_path, slice, [], _) = iter_call.kind
+            && iter_path.ident.name == sym::iter
+            && is_slice_method(cx, iter_call)
+            && is_trait_method(cx, expr, sym::Iterator)
+            && let ExprKind::Closure(&Closure { body, .. }) = closure.kind
+            && let body = cx.tcx.hir().body(body)
+            && let [param] = body.params
+            && let ExprKind::Binary(op, lhs, rhs) = peel_blocks(body.value).kind
+            && op.node == BinOpKind::Eq
+            && let slice_ty = cx.typeck_results().expr_ty_adjusted(slice).peel_refs()
+            && let ty::Slice(elem_ty) = slice_ty.kind()
+            // `contains` takes `&T`, so `[&'static str]` can't be searched for a shorter-lived `&str`
+            && !elem_ty.walk().any(|arg| matches!(arg.unpack(), GenericArgKind::Lifetime(_)))
+            && let Some((binding_id, deref)) = match param.pat.kind {
+                PatKind::Binding(_, id, _, None) => Some((id, false)),
+                PatKind::Ref(inner, _) if let PatKind::Binding(_, id, _, None) = inner.kind => Some((id, true)),
+                _ => None,
+            }
+            && let Some((target, target_by_value)) = compared_value(cx, binding_id, deref, *elem_ty, lhs, rhs)
+                .or_else(|| compared_value(cx, binding_id, deref, *elem_ty, rhs, lhs))
+            // The closure evaluates the value for every element, `contains` only once
+            && switch_to_eager_eval(cx, target)
+        {
+            let mut app = Applicability::MachineApplicable;
+            let ctxt = expr.span.ctxt();
+            let slice = Sugg::hir_with_context(cx, slice, ctxt, "..", &mut app).maybe_par();
+            let target = Sugg::hir_with_context(cx, target, ctxt, "..", &mut app);
+            let arg = if target_by_value { target.addr() } else { target };
+            span_lint_and_sugg(
+                cx,
+                MANUAL_SLICE_CONTAINS,
+                expr.span,
+                "using `iter().any(..)` to check whether a slice contains a value",
+                "try",
+                format!("{slice}.contains({arg})"),
+                app,
+            );
+        }
+    }
+}
+
+/// Checks whether the method call resolves to an inherent method of slices.
+fn is_slice_method(cx: &LateContext<'_>, expr: &Expr<'_>) -> bool {
+    cx.typeck_results()
+        .type_dependent_def_id(expr.hir_id)
+        .and_then(|id| cx.tcx.impl_of_method(id))
+        .is_some_and(|impl_id| {
+            cx.tcx.type_of(impl_id).instantiate_identity().is_slice() && cx.tcx.impl_trait_ref(impl_id).is_none()
+        })
+}
+
+/// Matches `elem == value` where `elem` is the closure's element, dereferenced if needed, and
+/// `value` doesn't depend on it. Returns `value` and whether it has the element type itself, as
+/// opposed to a reference to it.
+fn compared_value<'tcx>(
+    cx: &LateContext<'tcx>,
+    binding_id: HirId,
+    deref: bool,
+    elem_ty: Ty<'tcx>,
+    elem: &Expr<'_>,
+    value: &'tcx Expr<'tcx>,
+) -> Option<(&'tcx Expr<'tcx>, bool)> {
+    let by_value = match elem.kind {
+        // `|&x| x == value` and `|x| *x == value`
+        _ if deref && path_to_local_id(elem, binding_id) => true,
+        ExprKind::Unary(UnOp::Deref, inner) if !deref && path_to_local_id(inner, binding_id) => true,
+        // `|x| x == &value`
+        _ if !deref && path_to_local_id(elem, binding_id) => false,
+        _ => return None,
+    };
+    let value_ty = cx.typeck_results().expr_ty(value);
+    let matches_ty = if by_value {
+        value_ty == elem_ty
+    } else {
+        matches!(*value_ty.kind(), ty::Ref(_, ty, _) if ty == elem_ty)
+    };
+    (matches_ty && !is_local_used(cx, value, binding_id)).then_some((value, by_value))
+}
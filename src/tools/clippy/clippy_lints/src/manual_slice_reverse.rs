@@ -0,0 +1,167 @@
+use clippy_utils::diagnostics::span_lint_and_help;
+use clippy_utils::source::snippet;
+use clippy_utils::ty::is_type_diagnostic_item;
+use clippy_utils::{eq_expr_value, expr_or_init, higher, is_integer_literal, path_to_local_id, SpanlessEq};
+use rustc_ast::ast::RangeLimits;
+use rustc_hir::{BinOpKind, Expr, ExprKind, HirId, PatKind, StmtKind};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_span::sym;
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for loops over the first half of a slice which swap each element with the one
+    /// mirrored from the end.
+    ///
+    /// ### Why is this bad?
+    /// `slice.reverse()` does the same thing and says what is meant.
+    ///
+    /// ### Example
+    /// ```rust
+    /// let mut v = vec![1, 2, 3, 4];
+    /// for i in 0..v.len() / 2 {
+    ///     v.swap(i, v.len() - 1 - i);
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// let mut v = vec![1, 2, 3, 4];
+    /// v.reverse();
+    /// ```
+    #[clippy::version = "1.73.0"]
+    pub MANUAL_SLICE_REVERSE,
+    nursery,
+    "swapping the elements of a slice from both ends instead of using `reverse`"
+}
+declare_lint_pass!(ManualSliceReverse => [MANUAL_SLICE_REVERSE]);
+
+impl<'tcx> LateLintPass<'tcx> for ManualSliceReverse {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) {
+        if let Some(higher::ForLoop {
+            pat, arg, body, span, ..
+        }) = higher::ForLoop::hir(expr)
+            && !span.from_expansion()
+            && let PatKind::Binding(_, index, _, None) = pat.kind
+            && let Some(higher::Range {
+                start: Some(start),
+                end: Some(end),
+                limits: RangeLimits::HalfOpen,
+            }) = higher::Range::hir(arg)
+            && is_integer_literal(start, 0)
+            && let ExprKind::Binary(op, len, two) = end.kind
+            && op.node == BinOpKind::Div
+            && is_integer_literal(two, 2)
+            && let ExprKind::Block(block, None) = body.kind
+            && let Some(slice) = match (block.stmts, block.expr) {
+                ([stmt], None) => match stmt.kind {
+                    StmtKind::Semi(e) | StmtKind::Expr(e) => swapped_slice(cx, e, index, len),
+                    _ => None,
+                },
+                ([], Some(e)) => swapped_slice(cx, e, index, len),
+                ([tmp, first, second], None) => temp_swapped_slice(cx, tmp.kind, first.kind, second.kind, index, len),
+                _ => None,
+            }
+            && is_len_of(cx, len, slice)
+        {
+            span_lint_and_help(
+                cx,
+                MANUAL_SLICE_REVERSE,
+                span,
+                "this loop reverses the slice manually",
+                None,
+                &format!("use `{}.reverse()`", snippet(cx, slice.span, "..")),
+            );
+        }
+    }
+}
+
+/// Matches `slice.swap(a, b)` where `a` and `b` mirror each other, returning `slice`.
+fn swapped_slice<'tcx>(
+    cx: &LateContext<'tcx>,
+    expr: &'tcx Expr<'tcx>,
+    index: HirId,
+    len: &Expr<'_>,
+) -> Option<&'tcx Expr<'tcx>> {
+    if let ExprKind::MethodCall(path, slice, [a, b], _) = expr.kind
+        && path.ident.name == sym!(swap)
+        && cx.typeck_results().expr_ty_adjusted(slice).peel_refs().is_slice()
+        && are_mirrored(cx, a, b, index, len)
+    {
+        Some(slice)
+    } else {
+        None
+    }
+}
+
+/// Matches `let tmp = slice[a]; slice[a] = slice[b]; slice[b] = tmp;` where `a` and `b` mirror
+/// each other, returning `slice`.
+fn temp_swapped_slice<'tcx>(
+    cx: &LateContext<'tcx>,
+    tmp: StmtKind<'tcx>,
+    first: StmtKind<'tcx>,
+    second: StmtKind<'tcx>,
+    index: HirId,
+    len: &Expr<'_>,
+) -> Option<&'tcx Expr<'tcx>> {
+    if let StmtKind::Local(local) = tmp
+        && let PatKind::Binding(_, tmp_id, _, None) = local.pat.kind
+        && let Some(ExprKind::Index(slice, a)) = local.init.map(|init| init.kind)
+        && is_indexable(cx, slice)
+        && let StmtKind::Semi(first) = first
+        && let ExprKind::Assign(first_lhs, first_rhs, _) = first.kind
+        && let ExprKind::Index(slice1, a1) = first_lhs.kind
+        && let ExprKind::Index(slice2, b) = first_rhs.kind
+        && let StmtKind::Semi(second) = second
+        && let ExprKind::Assign(second_lhs, second_rhs, _) = second.kind
+        && let ExprKind::Index(slice3, b1) = second_lhs.kind
+        && path_to_local_id(second_rhs, tmp_id)
+        && [slice1, slice2, slice3]
+            .into_iter()
+            .all(|s| eq_expr_value(cx, slice, s))
+        && SpanlessEq::new(cx).eq_expr(a, a1)
+        && SpanlessEq::new(cx).eq_expr(b, b1)
+        && are_mirrored(cx, a, b, index, len)
+    {
+        Some(slice)
+    } else {
+        None
+    }
+}
+
+fn is_indexable(cx: &LateContext<'_>, slice: &Expr<'_>) -> bool {
+    let ty = cx.typeck_results().expr_ty(slice).peel_refs();
+    ty.is_slice() || ty.is_array() || is_type_diagnostic_item(cx, ty, sym::Vec)
+}
+
+/// Checks whether `a` and `b` are the loop index and `len - 1 - index`, in either order.
+fn are_mirrored(cx: &LateContext<'_>, a: &Expr<'_>, b: &Expr<'_>, index: HirId, len: &Expr<'_>) -> bool {
+    (path_to_local_id(a, index) && is_mirror_index(cx, b, index, len))
+        || (path_to_local_id(b, index) && is_mirror_index(cx, a, index, len))
+}
+
+/// Checks for `len - 1 - index` or `len - index - 1`.
+fn is_mirror_index(cx: &LateContext<'_>, expr: &Expr<'_>, index: HirId, len: &Expr<'_>) -> bool {
+    if let ExprKind::Binary(outer, lhs, rhs) = expr.kind
+        && outer.node == BinOpKind::Sub
+        && let ExprKind::Binary(inner, inner_lhs, inner_rhs) = lhs.kind
+        && inner.node == BinOpKind::Sub
+        // `len` may be `slice.len()` or a local initialized with it.
+        && SpanlessEq::new(cx).eq_expr(expr_or_init(cx, inner_lhs), expr_or_init(cx, len))
+    {
+        (is_integer_literal(inner_rhs, 1) && path_to_local_id(rhs, index))
+            || (path_to_local_id(inner_rhs, index) && is_integer_literal(rhs, 1))
+    } else {
+        false
+    }
+}
+
+/// Checks whether `len` is `slice.len()`, possibly through a local initialized with it.
+fn is_len_of(cx: &LateContext<'_>, len: &Expr<'_>, slice: &Expr<'_>) -> bool {
+    if let ExprKind::MethodCall(path, recv, [], _) = expr_or_init(cx, len).kind
+        && path.ident.name == sym::len
+    {
+        eq_expr_value(cx, recv, slice)
+    } else {
+        false
+    }
+}
@@ -0,0 +1,104 @@
+use clippy_utils::consts::{constant, Constant};
+use clippy_utils::diagnostics::span_lint_and_help;
+use clippy_utils::higher::ForLoop;
+use clippy_utils::sugg::Sugg;
+use clippy_utils::ty::implements_trait;
+use clippy_utils::{path_to_local_id, peel_blocks_with_stmt};
+use rustc_hir::{BinOpKind, BindingAnnotation, Block, BorrowKind, ExprKind, Local, Mutability, PatKind, StmtKind};
+use rustc_lint::{LateContext, LateLintPass, LintContext};
+use rustc_middle::lint::in_external_macro;
+use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_span::sym;
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for an accumulator initialized to zero which a `for` loop then adds every element
+    /// of an iterable to, or initialized to one and multiplied with every element.
+    ///
+    /// ### Why is this bad?
+    /// `Iterator::sum` and `Iterator::product` do the same thing without the mutable
+    /// accumulator.
+    ///
+    /// ### Example
+    /// ```rust
+    /// # let xs = vec![1, 2, 3];
+    /// let mut total = 0;
+    /// for x in &xs {
+    ///     total += x;
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # let xs = vec![1, 2, 3];
+    /// let total: i32 = xs.iter().sum();
+    /// ```
+    #[clippy::version = "1.73.0"]
+    pub MANUAL_ITERATOR_SUM,
+    nursery,
+    "summing or multiplying the elements of an iterable in a loop instead of using `sum` or `product`"
+}
+declare_lint_pass!(ManualSum => [MANUAL_ITERATOR_SUM]);
+
+impl<'tcx> LateLintPass<'tcx> for ManualSum {
+    fn check_block(&mut self, cx: &LateContext<'tcx>, block: &'tcx Block<'tcx>) {
+        for window in block.stmts.windows(2) {
+            if let [init_stmt, loop_stmt] = window
+                && !in_external_macro(cx.sess(), init_stmt.span)
+                && !init_stmt.span.from_expansion()
+                && !loop_stmt.span.from_expansion()
+                && let StmtKind::Local(Local {
+                    pat,
+                    init: Some(init),
+                    els: None,
+                    ..
+                }) = init_stmt.kind
+                && let PatKind::Binding(BindingAnnotation::MUT, acc_id, acc_name, None) = pat.kind
+                && let acc_ty = cx.typeck_results().pat_ty(pat)
+                && acc_ty.is_numeric()
+                && let StmtKind::Expr(loop_expr) | StmtKind::Semi(loop_expr) = loop_stmt.kind
+                && let Some(for_loop) = ForLoop::hir(loop_expr)
+                && let PatKind::Binding(_, elem_id, _, None) = for_loop.pat.kind
+                // The element has to be the accumulator's type or a reference to it
+                && cx.typeck_results().pat_ty(for_loop.pat).peel_refs() == acc_ty
+                && let ExprKind::AssignOp(op, lhs, rhs) = peel_blocks_with_stmt(for_loop.body).kind
+                && path_to_local_id(lhs, acc_id)
+                // Only the element itself, e.g. not `x * 2` or `*x`
+                && path_to_local_id(rhs, elem_id)
+                && let Some(init) = constant(cx, cx.typeck_results(), init)
+                && let Some(method) = match op.node {
+                    BinOpKind::Add if is_const_value(&init, 0) => Some("sum"),
+                    BinOpKind::Mul if is_const_value(&init, 1) => Some("product"),
+                    _ => None,
+                }
+            {
+                let iter = if let ExprKind::AddrOf(BorrowKind::Ref, Mutability::Not, iterable) = for_loop.arg.kind {
+                    format!("{}.iter()", Sugg::hir(cx, iterable, "..").maybe_par())
+                } else if let Some(iter_id) = cx.tcx.get_diagnostic_item(sym::Iterator)
+                    && implements_trait(cx, cx.typeck_results().expr_ty(for_loop.arg), iter_id, &[])
+                {
+                    Sugg::hir(cx, for_loop.arg, "..").maybe_par().to_string()
+                } else {
+                    format!("{}.into_iter()", Sugg::hir(cx, for_loop.arg, "..").maybe_par())
+                };
+                span_lint_and_help(
+                    cx,
+                    MANUAL_ITERATOR_SUM,
+                    init_stmt.span.to(loop_stmt.span),
+                    &format!("manual implementation of `Iterator::{method}`"),
+                    None,
+                    &format!("consider using `{method}`: `let {acc_name}: {acc_ty} = {iter}.{method}();`"),
+                );
+            }
+        }
+    }
+}
+
+#[expect(clippy::float_cmp)]
+fn is_const_value(constant: &Constant<'_>, value: u8) -> bool {
+    match *constant {
+        Constant::Int(int) => int == u128::from(value),
+        Constant::F32(float) => float == f32::from(value),
+        Constant::F64(float) => float == f64::from(value),
+        _ => false,
+    }
+}
@@ -0,0 +1,147 @@
+use clippy_utils::diagnostics::span_lint_and_help;
+use clippy_utils::higher::{get_vec_init_kind, ForLoop};
+use clippy_utils::path_to_local_id;
+use clippy_utils::source::snippet;
+use clippy_utils::ty::is_type_diagnostic_item;
+use rustc_hir::{BindingAnnotation, Block, Expr, ExprKind, HirId, Local, Pat, PatKind, Stmt, StmtKind};
+use rustc_lint::{LateContext, LateLintPass, LintContext};
+use rustc_middle::lint::in_external_macro;
+use rustc_middle::ty;
+use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_span::sym;
+use rustc_span::symbol::Ident;
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for two freshly created `Vec`s that are filled by a `for` loop pushing the
+    /// first element of each pair into one and the second element into the other.
+    ///
+    /// ### Why is this bad?
+    /// `Iterator::unzip` does the same thing in a single call.
+    ///
+    /// ### Example
+    /// ```rust
+    /// # let pairs = vec![(1, 'a'), (2, 'b')];
+    /// let mut numbers = Vec::new();
+    /// let mut chars = Vec::new();
+    /// for pair in pairs {
+    ///     numbers.push(pair.0);
+    ///     chars.push(pair.1);
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # let pairs = vec![(1, 'a'), (2, 'b')];
+    /// let (numbers, chars): (Vec<_>, Vec<_>) = pairs.into_iter().unzip();
+    /// ```
+    #[clippy::version = "1.73.0"]
+    pub MANUAL_UNZIP,
+    nursery,
+    "filling two `Vec`s from an iterator of pairs instead of using `unzip`"
+}
+declare_lint_pass!(ManualUnzip => [MANUAL_UNZIP]);
+
+impl<'tcx> LateLintPass<'tcx> for ManualUnzip {
+    fn check_block(&mut self, cx: &LateContext<'tcx>, block: &'tcx Block<'tcx>) {
+        for window in block.stmts.windows(3) {
+            if let [first, second, loop_stmt] = window
+                && !in_external_macro(cx.sess(), first.span)
+                && !first.span.from_expansion()
+                && !loop_stmt.span.from_expansion()
+                && let Some((first_id, first_name)) = fresh_vec_binding(cx, first)
+                && let Some((second_id, second_name)) = fresh_vec_binding(cx, second)
+                && let StmtKind::Expr(loop_expr) | StmtKind::Semi(loop_expr) = loop_stmt.kind
+                && let Some(for_loop) = ForLoop::hir(loop_expr)
+                && let ExprKind::Block(body, _) = for_loop.body.kind
+                && body.expr.is_none()
+                && let [push_a, push_b] = body.stmts
+                && matches!(cx.typeck_results().pat_ty(for_loop.pat).kind(), ty::Tuple(tys) if tys.len() == 2)
+                && let Some((vec_a, elem_a)) = vec_push(push_a)
+                && let Some((vec_b, elem_b)) = vec_push(push_b)
+                && let Some(idx_a) = pair_element(for_loop.pat, elem_a)
+                && let Some(idx_b) = pair_element(for_loop.pat, elem_b)
+                // Both halves of the pair have to go into different vectors
+                && idx_a != idx_b
+                && let Some(first_gets) = if path_to_local_id(vec_a, first_id) && path_to_local_id(vec_b, second_id) {
+                    Some(idx_a)
+                } else if path_to_local_id(vec_a, second_id) && path_to_local_id(vec_b, first_id) {
+                    Some(idx_b)
+                } else {
+                    None
+                }
+            {
+                let (left, right) = if first_gets == 0 {
+                    (first_name, second_name)
+                } else {
+                    (second_name, first_name)
+                };
+                span_lint_and_help(
+                    cx,
+                    MANUAL_UNZIP,
+                    first.span.to(loop_stmt.span),
+                    "filling two `Vec`s from pairs manually",
+                    None,
+                    &format!(
+                        "consider using `unzip`: `let ({left}, {right}): (Vec<_>, Vec<_>) = {}.into_iter().unzip();`",
+                        snippet(cx, for_loop.arg.span, ".."),
+                    ),
+                );
+            }
+        }
+    }
+}
+
+/// Matches `let mut name = Vec::new();` (or any other `Vec` initialization), returning the
+/// binding's id and name.
+fn fresh_vec_binding<'tcx>(cx: &LateContext<'tcx>, stmt: &'tcx Stmt<'tcx>) -> Option<(HirId, Ident)> {
+    if let StmtKind::Local(Local {
+        pat,
+        init: Some(init),
+        els: None,
+        ..
+    }) = stmt.kind
+        && let PatKind::Binding(BindingAnnotation::MUT, id, name, None) = pat.kind
+        && get_vec_init_kind(cx, init).is_some()
+        && is_type_diagnostic_item(cx, cx.typeck_results().expr_ty(init), sym::Vec)
+    {
+        Some((id, name))
+    } else {
+        None
+    }
+}
+
+/// Matches `vec.push(elem);`, returning `vec` and `elem`.
+fn vec_push<'tcx>(stmt: &'tcx Stmt<'tcx>) -> Option<(&'tcx Expr<'tcx>, &'tcx Expr<'tcx>)> {
+    if let StmtKind::Semi(expr) = stmt.kind
+        && let ExprKind::MethodCall(path, recv, [arg], _) = expr.kind
+        && path.ident.as_str() == "push"
+    {
+        Some((recv, arg))
+    } else {
+        None
+    }
+}
+
+/// Returns which element of the pair bound by `pat` is used as `elem`, if any. This is either
+/// `pair.0`/`pair.1` for a single binding or `a`/`b` for a `(a, b)` tuple pattern.
+fn pair_element(pat: &Pat<'_>, elem: &Expr<'_>) -> Option<usize> {
+    match pat.kind {
+        PatKind::Binding(_, id, _, None) => {
+            if let ExprKind::Field(base, field) = elem.kind
+                && path_to_local_id(base, id)
+            {
+                match field.as_str() {
+                    "0" => Some(0),
+                    "1" => Some(1),
+                    _ => None,
+                }
+            } else {
+                None
+            }
+        },
+        PatKind::Tuple([a, b], dotdot) if dotdot.as_opt_usize().is_none() => [a, b]
+            .iter()
+            .position(|p| matches!(p.kind, PatKind::Binding(_, id, _, None) if path_to_local_id(elem, id))),
+        _ => None,
+    }
+}
@@ -0,0 +1,99 @@
+use clippy_utils::diagnostics::span_lint_and_help;
+use clippy_utils::source::snippet;
+use clippy_utils::{eq_expr_value, higher, is_integer_literal, peel_blocks_with_stmt};
+use rustc_hir::{BinOpKind, Block, Expr, ExprKind, Stmt, StmtKind};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_session::{declare_lint_pass, declare_tool_lint};
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for an index which is incremented and then reset to zero once it reaches a bound,
+    /// like `i += 1; if i >= len { i = 0; }`.
+    ///
+    /// ### Why is this bad?
+    /// `i = (i + 1) % len` says in one statement that the index wraps around.
+    ///
+    /// ### Known problems
+    /// The remainder panics if `len` is zero, where the `if` sets the index to zero, and it
+    /// differs if the index was already out of bounds. A division can also be slower than the
+    /// branch.
+    ///
+    /// ### Example
+    /// ```rust
+    /// # let len = 3;
+    /// # let mut i = 0;
+    /// i += 1;
+    /// if i >= len {
+    ///     i = 0;
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # let len = 3;
+    /// # let mut i = 0;
+    /// i = (i + 1) % len;
+    /// ```
+    #[clippy::version = "1.73.0"]
+    pub MANUAL_CHECKED_ADD_IN_INDEX_INCREMENT,
+    pedantic,
+    "incrementing an index and resetting it to zero at a bound instead of using `%`"
+}
+declare_lint_pass!(ManualWraparoundIndex => [MANUAL_CHECKED_ADD_IN_INDEX_INCREMENT]);
+
+impl<'tcx> LateLintPass<'tcx> for ManualWraparoundIndex {
+    fn check_block(&mut self, cx: &LateContext<'tcx>, block: &'tcx Block<'tcx>) {
+        for window in block.stmts.windows(2) {
+            if let [increment, stmt] = window
+                && let StmtKind::Expr(reset) | StmtKind::Semi(reset) = stmt.kind
+            {
+                check_wraparound(cx, increment, reset);
+            }
+        }
+        if let (Some(increment), Some(reset)) = (block.stmts.last(), block.expr) {
+            check_wraparound(cx, increment, reset);
+        }
+    }
+}
+
+fn check_wraparound<'tcx>(cx: &LateContext<'tcx>, increment: &'tcx Stmt<'tcx>, reset: &'tcx Expr<'tcx>) {
+    if let StmtKind::Semi(increment_expr) = increment.kind
+        && let ExprKind::AssignOp(op, index, one) = increment_expr.kind
+        && op.node == BinOpKind::Add
+        && is_integer_literal(one, 1)
+        && !increment.span.from_expansion()
+        && increment.span.ctxt() == reset.span.ctxt()
+        && let Some(higher::If {
+            cond,
+            then,
+            r#else: None,
+        }) = higher::If::hir(reset)
+        && let Some(bound) = reached_bound(cx, cond, index)
+        && let ExprKind::Assign(target, zero, _) = peel_blocks_with_stmt(then).kind
+        && is_integer_literal(zero, 0)
+        && eq_expr_value(cx, target, index)
+    {
+        let index = snippet(cx, index.span, "..");
+        let bound = snippet(cx, bound.span, "..");
+        span_lint_and_help(
+            cx,
+            MANUAL_CHECKED_ADD_IN_INDEX_INCREMENT,
+            increment.span.to(reset.span),
+            "manual wraparound of an index",
+            None,
+            &format!("if `{bound}` can't be zero, consider using `{index} = ({index} + 1) % {bound}`"),
+        );
+    }
+}
+
+/// Returns the bound of a condition such as `index >= bound`, `index == bound` or `bound <= index`.
+fn reached_bound<'tcx>(cx: &LateContext<'tcx>, cond: &'tcx Expr<'tcx>, index: &Expr<'_>) -> Option<&'tcx Expr<'tcx>> {
+    if let ExprKind::Binary(op, left, right) = cond.kind {
+        match op.node {
+            BinOpKind::Ge | BinOpKind::Eq if eq_expr_value(cx, left, index) => Some(right),
+            BinOpKind::Le | BinOpKind::Eq if eq_expr_value(cx, right, index) => Some(left),
+            _ => None,
+        }
+    } else {
+        None
+    }
+}
@@ -0,0 +1,69 @@
+use clippy_utils::diagnostics::span_lint_and_sugg;
+use clippy_utils::source::span_contains_comment;
+use clippy_utils::sugg::Sugg;
+use clippy_utils::{get_parent_expr, is_lint_allowed};
+use rustc_ast::LitKind;
+use rustc_errors::Applicability;
+use rustc_hir::{Arm, Expr, ExprKind, Pat, PatKind};
+use rustc_lint::{LateContext, LintContext};
+
+use super::match_like_matches::find_bool_lit;
+use super::NEEDLESS_MATCH_ON_BOOL_RETURNING_BOOL;
+
+/// Returns whether the lint was emitted, in which case the other lints about returning `bool`s
+/// from a match are skipped.
+pub(crate) fn check(cx: &LateContext<'_>, ex: &Expr<'_>, arms: &[Arm<'_>], expr: &Expr<'_>) -> bool {
+    if !is_lint_allowed(cx, NEEDLESS_MATCH_ON_BOOL_RETURNING_BOOL, expr.hir_id)
+        && let [first, second] = arms
+        && first.guard.is_none()
+        && second.guard.is_none()
+        && cx.typeck_results().expr_ty(ex).is_bool()
+        && let Some(first_pat) = bool_pat(first.pat)
+        // The second arm covers the other value
+        && (matches!(second.pat.kind, PatKind::Wild) || bool_pat(second.pat) == Some(!first_pat))
+        && let Some(first_value) = find_bool_lit(first.body)
+        && let Some(second_value) = find_bool_lit(second.body)
+        // `true => true, false => true` is a constant, not the scrutinee
+        && first_value != second_value
+        && !span_contains_comment(cx.sess().source_map(), expr.span)
+    {
+        let mut app = Applicability::MachineApplicable;
+        let scrutinee = Sugg::hir_with_context(cx, ex, expr.span.ctxt(), "..", &mut app);
+        let (msg, sugg) = if first_pat == first_value {
+            let sugg = if get_parent_expr(cx, expr).is_some() {
+                scrutinee.maybe_par()
+            } else {
+                scrutinee
+            };
+            ("this match returns the `bool` it matches on", sugg.to_string())
+        } else {
+            (
+                "this match returns the negation of the `bool` it matches on",
+                (!scrutinee).to_string(),
+            )
+        };
+        span_lint_and_sugg(
+            cx,
+            NEEDLESS_MATCH_ON_BOOL_RETURNING_BOOL,
+            expr.span,
+            msg,
+            "try",
+            sugg,
+            app,
+        );
+        true
+    } else {
+        false
+    }
+}
+
+fn bool_pat(pat: &Pat<'_>) -> Option<bool> {
+    if let PatKind::Lit(lit) = pat.kind
+        && let ExprKind::Lit(lit) = lit.kind
+        && let LitKind::Bool(b) = lit.node
+    {
+        Some(b)
+    } else {
+        None
+    }
+}
@@ -1,7 +1,7 @@
 use crate::map_unit_fn::OPTION_MAP_UNIT_FN;
 use crate::matches::MATCH_AS_REF;
 use clippy_utils::source::{snippet_with_applicability, snippet_with_context};
-use clippy_utils::sugg::Sugg;
+use clippy_utils::sugg::{has_total_ord_operands, Sugg};
 use clippy_utils::ty::{is_copy, is_type_diagnostic_item, peel_mid_ty_refs_is_mutable, type_is_unsafe_function};
 use clippy_utils::{
     can_move_expr_to_closure, is_else_clause, is_lint_allowed, is_res_lang_ctor, path_res, path_to_local_id,
@@ -240,7 +240,11 @@ pub fn to_snippet_with_context(
         app: &mut Applicability,
     ) -> Sugg<'tcx> {
         let sugg = Sugg::hir_with_context(cx, self.expr, ctxt, "..", app);
-        if self.needs_negated { !sugg } else { sugg }
+        if self.needs_negated {
+            sugg.negate(has_total_ord_operands(cx, self.expr))
+        } else {
+            sugg
+        }
     }
 }
 
@@ -1,7 +1,7 @@
 use super::REDUNDANT_PATTERN_MATCHING;
 use clippy_utils::diagnostics::span_lint_and_sugg;
 use clippy_utils::source::snippet_with_applicability;
-use clippy_utils::{is_lint_allowed, is_wild, span_contains_comment};
+use clippy_utils::{is_lint_allowed, is_wild, peel_blocks, span_contains_comment};
 use rustc_ast::{Attribute, LitKind};
 use rustc_errors::Applicability;
 use rustc_hir::{Arm, BorrowKind, Expr, ExprKind, Guard, Pat, PatKind, QPath};
@@ -83,14 +83,14 @@ fn find_matches_sugg<'a, 'b, I>(
         if let Some((_, last_pat_opt, last_expr, _)) = iter.next_back();
         let iter_without_last = iter.clone();
         if let Some((first_attrs, _, first_expr, first_guard)) = iter.next();
-        if let Some(b0) = find_bool_lit(&first_expr.kind);
-        if let Some(b1) = find_bool_lit(&last_expr.kind);
+        if let Some(b0) = find_bool_lit(first_expr);
+        if let Some(b1) = find_bool_lit(last_expr);
         if b0 != b1;
         if first_guard.is_none() || iter.len() == 0;
         if first_attrs.is_empty();
         if iter
             .all(|arm| {
-                find_bool_lit(&arm.2.kind).map_or(false, |b| b == b0) && arm.3.is_none() && arm.0.is_empty()
+                find_bool_lit(arm.2).map_or(false, |b| b == b0) && arm.3.is_none() && arm.0.is_empty()
             });
         then {
             if let Some(last_pat) = last_pat_opt {
@@ -153,29 +153,14 @@ fn find_matches_sugg<'a, 'b, I>(
 }
 
 /// Extract a `bool` or `{ bool }`
-fn find_bool_lit(ex: &ExprKind<'_>) -> Option<bool> {
-    match ex {
-        ExprKind::Lit(Spanned {
-            node: LitKind::Bool(b), ..
-        }) => Some(*b),
-        ExprKind::Block(
-            rustc_hir::Block {
-                stmts: &[],
-                expr: Some(exp),
-                ..
-            },
-            _,
-        ) => {
-            if let ExprKind::Lit(Spanned {
-                node: LitKind::Bool(b), ..
-            }) = exp.kind
-            {
-                Some(*b)
-            } else {
-                None
-            }
-        },
-        _ => None,
+pub(super) fn find_bool_lit(ex: &Expr<'_>) -> Option<bool> {
+    if let ExprKind::Lit(Spanned {
+        node: LitKind::Bool(b), ..
+    }) = peel_blocks(ex).kind
+    {
+        Some(*b)
+    } else {
+        None
     }
 }
 
@@ -18,7 +18,7 @@
 #[expect(clippy::too_many_lines)]
 pub(super) fn check<'tcx>(cx: &LateContext<'tcx>, arms: &'tcx [Arm<'_>]) {
     let hash = |&(_, arm): &(usize, &Arm<'_>)| -> u64 {
-        let mut h = SpanlessHash::new(cx);
+        let mut h = SpanlessHash::new(cx).eval_consts(true);
         h.hash_expr(arm.body);
         h.finish()
     };
@@ -93,6 +93,7 @@ pub(super) fn check<'tcx>(cx: &LateContext<'tcx>, arms: &'tcx [Arm<'_>]) {
                 && lhs.guard.is_none()
                 && rhs.guard.is_none()
                 && SpanlessEq::new(cx)
+                    .eval_consts(true)
                     .expr_fallback(eq_fallback)
                     .eq_expr(lhs.body, rhs.body)
                 // these checks could be removed to allow unused bindings
@@ -1,3 +1,4 @@
+mod bool_match_identity;
 mod collapsible_match;
 mod infallible_destructuring_match;
 mod manual_filter;
@@ -64,6 +65,11 @@
     ///     bar(foo);
     /// }
     /// ```
+    ///
+    /// ### Configuration
+    /// With `single-match-with-else` enabled, matches whose wildcard arm consists of a
+    /// single expression or statement are linted as well, suggesting `if let .. else ..`.
+    /// Wildcard arms with larger bodies are covered by `single_match_else`.
     #[clippy::version = "pre 1.29.0"]
     pub SINGLE_MATCH,
     style,
@@ -936,17 +942,51 @@
     "reimplementation of `filter`"
 }
 
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for matches on a `bool` whose arms return `true` and `false`, like
+    /// `match b { true => true, false => false }` or `match b { true => false, _ => true }`.
+    ///
+    /// ### Why is this bad?
+    /// The match is just the `bool` itself, or its negation.
+    ///
+    /// ### Example
+    /// ```rust
+    /// # let b = true;
+    /// let same = match b {
+    ///     true => true,
+    ///     false => false,
+    /// };
+    /// let negated = match b {
+    ///     true => false,
+    ///     false => true,
+    /// };
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # let b = true;
+    /// let same = b;
+    /// let negated = !b;
+    /// ```
+    #[clippy::version = "1.73.0"]
+    pub NEEDLESS_MATCH_ON_BOOL_RETURNING_BOOL,
+    complexity,
+    "a match on a `bool` which returns the `bool` or its negation"
+}
+
 #[derive(Default)]
 pub struct Matches {
     msrv: Msrv,
+    single_match_with_else: bool,
     infallible_destructuring_match_linted: bool,
 }
 
 impl Matches {
     #[must_use]
-    pub fn new(msrv: Msrv) -> Self {
+    pub fn new(msrv: Msrv, single_match_with_else: bool) -> Self {
         Self {
             msrv,
+            single_match_with_else,
             ..Matches::default()
         }
     }
@@ -978,6 +1018,7 @@ pub fn new(msrv: Msrv) -> Self {
     TRY_ERR,
     MANUAL_MAP,
     MANUAL_FILTER,
+    NEEDLESS_MATCH_ON_BOOL_RETURNING_BOOL,
 ]);
 
 impl<'tcx> LateLintPass<'tcx> for Matches {
@@ -1012,17 +1053,25 @@ fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
 
             if !from_expansion && !contains_cfg_arm(cx, expr, ex, arms) {
                 if source == MatchSource::Normal {
-                    if !(self.msrv.meets(msrvs::MATCHES_MACRO) && match_like_matches::check_match(cx, expr, ex, arms)) {
+                    // `needless_match` and `match_like_matches_macro` would suggest the scrutinee or
+                    // `matches!` for some of the matches linted here
+                    let bool_identity = bool_match_identity::check(cx, ex, arms, expr);
+                    if !bool_identity
+                        && !(self.msrv.meets(msrvs::MATCHES_MACRO)
+                            && match_like_matches::check_match(cx, expr, ex, arms))
+                    {
                         match_same_arms::check(cx, arms);
                     }
 
                     redundant_pattern_match::check_match(cx, expr, ex, arms);
-                    single_match::check(cx, ex, arms, expr);
+                    single_match::check(cx, ex, arms, expr, self.single_match_with_else);
                     match_bool::check(cx, ex, arms, expr);
                     overlapping_arms::check(cx, ex, arms);
                     match_wild_enum::check(cx, ex, arms);
                     match_as_ref::check(cx, ex, arms, expr);
-                    needless_match::check_match(cx, ex, arms, expr);
+                    if !bool_identity {
+                        needless_match::check_match(cx, ex, arms, expr);
+                    }
                     match_on_vec_items::check(cx, ex);
                     match_str_case_mismatch::check(cx, ex, arms);
 
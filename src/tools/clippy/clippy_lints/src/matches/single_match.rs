@@ -1,11 +1,13 @@
 use clippy_utils::diagnostics::span_lint_and_sugg;
 use clippy_utils::source::{expr_block, get_source_text, snippet};
 use clippy_utils::ty::{implements_trait, is_type_diagnostic_item, peel_mid_ty_refs};
-use clippy_utils::{is_lint_allowed, is_unit_expr, is_wild, peel_blocks, peel_hir_pat_refs, peel_n_hir_expr_refs};
+use clippy_utils::{
+    get_parent_expr, is_lint_allowed, is_unit_expr, is_wild, peel_blocks, peel_hir_pat_refs, peel_n_hir_expr_refs,
+};
 use core::cmp::max;
 use rustc_errors::Applicability;
 use rustc_hir::{Arm, BindingAnnotation, Block, Expr, ExprKind, Pat, PatKind};
-use rustc_lint::LateContext;
+use rustc_lint::{LateContext, Lint};
 use rustc_middle::ty::{self, Ty};
 use rustc_span::{sym, Span};
 
@@ -28,7 +30,7 @@ fn empty_arm_has_comment(cx: &LateContext<'_>, span: Span) -> bool {
 }
 
 #[rustfmt::skip]
-pub(crate) fn check(cx: &LateContext<'_>, ex: &Expr<'_>, arms: &[Arm<'_>], expr: &Expr<'_>) {
+pub(crate) fn check(cx: &LateContext<'_>, ex: &Expr<'_>, arms: &[Arm<'_>], expr: &Expr<'_>, with_else: bool) {
     if arms.len() == 2 && arms[0].guard.is_none() && arms[1].guard.is_none() {
         if expr.span.from_expansion() {
             // Don't lint match expressions present in
@@ -41,24 +43,25 @@ pub(crate) fn check(cx: &LateContext<'_>, ex: &Expr<'_>, arms: &[Arm<'_>], expr:
             return;
         }
         let els = arms[1].body;
-        let els = if is_unit_expr(peel_blocks(els)) && !empty_arm_has_comment(cx, els.span) {
-            None
-        } else if let ExprKind::Block(Block { stmts, expr: block_expr, .. }, _) = els.kind {
-            if stmts.len() == 1 && block_expr.is_none() || stmts.is_empty() && block_expr.is_some() {
-                // single statement/expr "else" block, don't lint
-                return;
-            }
+        let (lint, els) = if is_unit_expr(peel_blocks(els)) && !empty_arm_has_comment(cx, els.span) {
+            (SINGLE_MATCH, None)
+        } else if let ExprKind::Block(Block { stmts, expr: block_expr, .. }, _) = els.kind
+            && !(stmts.len() == 1 && block_expr.is_none() || stmts.is_empty() && block_expr.is_some())
+        {
             // block with 2+ statements or 1 expr and 1+ statement
-            Some(els)
+            (SINGLE_MATCH_ELSE, Some(els))
+        } else if with_else && !is_unit_expr(peel_blocks(els)) {
+            // single statement/expr "else" arm, only linted if configured
+            (SINGLE_MATCH, Some(els))
         } else {
-            // not a block or an emtpy block w/ comments, don't lint
+            // single statement/expr "else" arm, don't lint unless configured
             return;
         };
 
         let ty = cx.typeck_results().expr_ty(ex);
         if *ty.kind() != ty::Bool || is_lint_allowed(cx, MATCH_BOOL, ex.hir_id) {
-            check_single_pattern(cx, ex, arms, expr, els);
-            check_opt_like(cx, ex, arms, expr, ty, els);
+            check_single_pattern(cx, ex, arms, expr, lint, els);
+            check_opt_like(cx, ex, arms, expr, ty, lint, els);
         }
     }
 }
@@ -68,10 +71,11 @@ fn check_single_pattern(
     ex: &Expr<'_>,
     arms: &[Arm<'_>],
     expr: &Expr<'_>,
+    lint: &'static Lint,
     els: Option<&Expr<'_>>,
 ) {
     if is_wild(arms[1].pat) {
-        report_single_pattern(cx, ex, arms, expr, els);
+        report_single_pattern(cx, ex, arms, expr, lint, els);
     }
 }
 
@@ -80,9 +84,9 @@ fn report_single_pattern(
     ex: &Expr<'_>,
     arms: &[Arm<'_>],
     expr: &Expr<'_>,
+    lint: &'static Lint,
     els: Option<&Expr<'_>>,
 ) {
-    let lint = if els.is_some() { SINGLE_MATCH_ELSE } else { SINGLE_MATCH };
     let ctxt = expr.span.ctxt();
     let mut app = Applicability::HasPlaceholders;
     let els_str = els.map_or(String::new(), |els| {
@@ -135,22 +139,42 @@ fn report_single_pattern(
             (msg, sugg)
         }
     };
+    // An `if` at the start of a statement ends at its closing brace, so e.g. `match x { .. }.foo()`
+    // needs parentheses around the replacement to keep the method call attached.
+    let sugg = if els.is_some() && needs_parens(cx, expr) {
+        format!("({sugg})")
+    } else {
+        sugg
+    };
 
     span_lint_and_sugg(cx, lint, expr.span, msg, "try", sugg, app);
 }
 
+/// Checks if `expr` is used as the operand of a postfix or binary expression, where an `if .. else`
+/// expression would need to be parenthesized.
+fn needs_parens(cx: &LateContext<'_>, expr: &Expr<'_>) -> bool {
+    get_parent_expr(cx, expr).map_or(false, |parent| match parent.kind {
+        ExprKind::MethodCall(_, recv, ..) => recv.hir_id == expr.hir_id,
+        ExprKind::Field(base, _) | ExprKind::Index(base, _) => base.hir_id == expr.hir_id,
+        ExprKind::Binary(_, lhs, _) | ExprKind::AssignOp(_, lhs, _) => lhs.hir_id == expr.hir_id,
+        ExprKind::Cast(..) | ExprKind::Type(..) => true,
+        _ => false,
+    })
+}
+
 fn check_opt_like<'a>(
     cx: &LateContext<'a>,
     ex: &Expr<'_>,
     arms: &[Arm<'_>],
     expr: &Expr<'_>,
     ty: Ty<'a>,
+    lint: &'static Lint,
     els: Option<&Expr<'_>>,
 ) {
     // We don't want to lint if the second arm contains an enum which could
     // have more variants in the future.
     if form_exhaustive_matches(cx, ty, arms[0].pat, arms[1].pat) {
-        report_single_pattern(cx, ex, arms, expr, els);
+        report_single_pattern(cx, ex, arms, expr, lint, els);
     }
 }
 
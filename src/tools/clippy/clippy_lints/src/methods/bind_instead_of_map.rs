@@ -1,8 +1,8 @@
 use super::{contains_return, BIND_INSTEAD_OF_MAP};
 use clippy_utils::diagnostics::{multispan_sugg_with_applicability, span_lint_and_sugg, span_lint_and_then};
-use clippy_utils::peel_blocks;
 use clippy_utils::source::{snippet, snippet_with_context};
 use clippy_utils::visitors::find_all_ret_expressions;
+use clippy_utils::{path_to_local_id, peel_blocks};
 use if_chain::if_chain;
 use rustc_errors::Applicability;
 use rustc_hir as hir;
@@ -51,6 +51,17 @@ fn no_op_msg(cx: &LateContext<'_>) -> Option<String> {
         ))
     }
 
+    fn no_op_closure_msg(cx: &LateContext<'_>) -> Option<String> {
+        let variant_id = cx.tcx.lang_items().get(Self::VARIANT_LANG_ITEM)?;
+        let item_id = cx.tcx.parent(variant_id);
+        Some(format!(
+            "using `{}.{}(|x| {}(x))`, which is a no-op",
+            cx.tcx.item_name(item_id),
+            Self::BAD_METHOD_NAME,
+            cx.tcx.item_name(variant_id),
+        ))
+    }
+
     fn lint_msg(cx: &LateContext<'_>) -> Option<String> {
         let variant_id = cx.tcx.lang_items().get(Self::VARIANT_LANG_ITEM)?;
         let item_id = cx.tcx.parent(variant_id);
@@ -63,6 +74,39 @@ fn lint_msg(cx: &LateContext<'_>) -> Option<String> {
         ))
     }
 
+    /// Lint use of `_.and_then(|x| Some(x))`, which is the same as `_.and_then(Some)`
+    fn lint_closure_identity(
+        cx: &LateContext<'_>,
+        expr: &hir::Expr<'_>,
+        recv: &hir::Expr<'_>,
+        closure_body: &hir::Body<'_>,
+    ) -> bool {
+        if_chain! {
+            if let [param] = closure_body.params;
+            if let hir::PatKind::Binding(_, id, _, None) = param.pat.kind;
+            if let hir::ExprKind::Call(some_expr, [inner_expr]) = peel_blocks(closure_body.value).kind;
+            if let hir::ExprKind::Path(QPath::Resolved(_, path)) = some_expr.kind;
+            if Self::is_variant(cx, path.res);
+            if path_to_local_id(inner_expr, id);
+            if cx.typeck_results().expr_adjustments(inner_expr).is_empty();
+            if let Some(msg) = Self::no_op_closure_msg(cx);
+            then {
+                span_lint_and_sugg(
+                    cx,
+                    BIND_INSTEAD_OF_MAP,
+                    expr.span,
+                    &msg,
+                    "use the expression directly",
+                    snippet(cx, recv.span, "..").into(),
+                    Applicability::MachineApplicable,
+                );
+                true
+            } else {
+                false
+            }
+        }
+    }
+
     fn lint_closure_autofixable(
         cx: &LateContext<'_>,
         expr: &hir::Expr<'_>,
@@ -151,7 +195,9 @@ fn check(cx: &LateContext<'_>, expr: &hir::Expr<'_>, recv: &hir::Expr<'_>, arg:
                 let closure_body = cx.tcx.hir().body(body);
                 let closure_expr = peel_blocks(closure_body.value);
 
-                if Self::lint_closure_autofixable(cx, expr, recv, closure_expr, fn_decl_span) {
+                if Self::lint_closure_identity(cx, expr, recv, closure_body)
+                    || Self::lint_closure_autofixable(cx, expr, recv, closure_expr, fn_decl_span)
+                {
                     true
                 } else {
                     Self::lint_closure(cx, expr, closure_expr)
@@ -1,17 +1,21 @@
 use crate::methods::utils::derefs_to_slice;
 use clippy_utils::diagnostics::span_lint_and_sugg;
+use clippy_utils::sugg::Sugg;
 use clippy_utils::ty::is_type_diagnostic_item;
 use if_chain::if_chain;
 use rustc_errors::Applicability;
 use rustc_hir as hir;
 use rustc_lint::LateContext;
+use rustc_middle::ty;
 use rustc_span::sym;
 
 use super::ITER_CLONED_COLLECT;
 
 pub(super) fn check<'tcx>(cx: &LateContext<'tcx>, method_name: &str, expr: &hir::Expr<'_>, recv: &'tcx hir::Expr<'_>) {
+    if !is_type_diagnostic_item(cx, cx.typeck_results().expr_ty(expr), sym::Vec) {
+        return;
+    }
     if_chain! {
-        if is_type_diagnostic_item(cx, cx.typeck_results().expr_ty(expr), sym::Vec);
         if let Some(slice) = derefs_to_slice(cx, recv, cx.typeck_results().expr_ty(recv));
         if let Some(to_replace) = expr.span.trim_start(slice.span.source_callsite());
 
@@ -26,6 +30,35 @@ pub(super) fn check<'tcx>(cx: &LateContext<'tcx>, method_name: &str, expr: &hir:
                 ".to_vec()".to_string(),
                 Applicability::MachineApplicable,
             );
+            return;
+        }
+    }
+    if_chain! {
+        if let hir::ExprKind::MethodCall(path, deque, [], _) = recv.kind;
+        if path.ident.name == sym::iter;
+        let deque_ty = cx.typeck_results().expr_ty(deque);
+        if is_type_diagnostic_item(cx, deque_ty.peel_refs(), sym::VecDeque);
+        if !expr.span.from_expansion();
+
+        then {
+            let mut app = Applicability::MachineApplicable;
+            let mut deque_sugg = Sugg::hir_with_context(cx, deque, expr.span.ctxt(), "..", &mut app);
+            // `clone` has to be called on the `VecDeque` itself, not on a reference to it
+            let mut peeled_ty = deque_ty;
+            while let ty::Ref(_, inner, _) = peeled_ty.kind() {
+                deque_sugg = deque_sugg.deref();
+                peeled_ty = *inner;
+            }
+            span_lint_and_sugg(
+                cx,
+                ITER_CLONED_COLLECT,
+                expr.span,
+                &format!("called `iter().{method_name}().collect()` on a `VecDeque` to create a `Vec`. Converting a clone \
+                of it with `Vec::from` is more readable"),
+                "try",
+                format!("Vec::from({}.clone())", deque_sugg.maybe_par()),
+                app,
+            );
         }
     }
 }
@@ -1,6 +1,6 @@
 use clippy_utils::diagnostics::span_lint_and_sugg;
 use clippy_utils::source::snippet;
-use clippy_utils::{get_expr_use_or_unification_node, is_no_std_crate, is_res_lang_ctor, path_res};
+use clippy_utils::{expr_use_ctxt, is_no_std_crate, is_res_lang_ctor, path_res, ExprUseNode};
 
 use rustc_errors::Applicability;
 use rustc_hir::LangItem::{OptionNone, OptionSome};
@@ -40,18 +40,14 @@ pub(super) fn check(cx: &LateContext<'_>, expr: &Expr<'_>, method_name: &str, re
         _ => return,
     };
 
-    let is_unified = match get_expr_use_or_unification_node(cx.tcx, expr) {
-        Some((Node::Expr(parent), child_id)) => match parent.kind {
-            ExprKind::If(e, _, _) | ExprKind::Match(e, _, _) if e.hir_id == child_id => false,
-            ExprKind::If(_, _, _)
-            | ExprKind::Match(_, _, _)
-            | ExprKind::Closure(_)
-            | ExprKind::Ret(_)
-            | ExprKind::Break(_, _) => true,
-            _ => false,
+    let is_unified = match expr_use_ctxt(cx, expr) {
+        Some(ExprUseNode::Return | ExprUseNode::Break(_) | ExprUseNode::Field(_)) | None => true,
+        Some(ExprUseNode::Other(node)) => match node {
+            Node::Expr(parent) => matches!(parent.kind, ExprKind::If(..) | ExprKind::Match(..)),
+            Node::Stmt(_) | Node::Local(_) => false,
+            _ => true,
         },
-        Some((Node::Stmt(_) | Node::Local(_), _)) => false,
-        _ => true,
+        Some(_) => false,
     };
 
     if is_unified {
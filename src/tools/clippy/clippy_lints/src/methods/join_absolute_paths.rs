@@ -0,0 +1,64 @@
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::source::snippet;
+use clippy_utils::ty::is_type_diagnostic_item;
+use rustc_ast::ast::LitKind;
+use rustc_errors::Applicability;
+use rustc_hir::{Expr, ExprKind};
+use rustc_lint::{LateContext, LintContext};
+use rustc_span::symbol::sym;
+
+use super::JOIN_ABSOLUTE_PATHS;
+
+pub(super) fn check<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>, join_arg: &'tcx Expr<'tcx>) {
+    if let Some(method_id) = cx.typeck_results().type_dependent_def_id(expr.hir_id)
+        && let Some(impl_id) = cx.tcx.impl_of_method(method_id)
+        && is_type_diagnostic_item(cx, cx.tcx.type_of(impl_id).instantiate_identity(), sym::Path)
+        && let ExprKind::Lit(lit) = join_arg.kind
+        && let LitKind::Str(path, _) = lit.node
+        && let path = path.as_str()
+        && let Some(has_separator) = absolute_path_kind(cx, path)
+    {
+        span_lint_and_then(
+            cx,
+            JOIN_ABSOLUTE_PATHS,
+            join_arg.span,
+            "argument to `Path::join` starts with a path separator or prefix",
+            |diag| {
+                diag.note("joining an absolute path replaces the original path entirely");
+                if has_separator {
+                    diag.span_suggestion(
+                        join_arg.span,
+                        "if this is unintentional, remove the leading separator",
+                        format!("{:?}", path.trim_start_matches(|c| is_separator(cx, c))),
+                        Applicability::MaybeIncorrect,
+                    );
+                }
+                diag.span_suggestion(
+                    expr.span,
+                    "if this is intentional, use `PathBuf::from` instead",
+                    format!("PathBuf::from({})", snippet(cx, join_arg.span, "..")),
+                    Applicability::MaybeIncorrect,
+                );
+            },
+        );
+    }
+}
+
+/// Returns whether `path` starts with a separator if it's an absolute path, or one with a prefix
+/// on Windows, for the target being compiled for.
+fn absolute_path_kind(cx: &LateContext<'_>, path: &str) -> Option<bool> {
+    if path.starts_with(|c| is_separator(cx, c)) {
+        Some(true)
+    } else if cx.sess().target.is_like_windows
+        && let [drive, b':', ..] = path.as_bytes()
+        && drive.is_ascii_alphabetic()
+    {
+        Some(false)
+    } else {
+        None
+    }
+}
+
+fn is_separator(cx: &LateContext<'_>, c: char) -> bool {
+    c == '/' || (c == '\\' && cx.sess().target.is_like_windows)
+}
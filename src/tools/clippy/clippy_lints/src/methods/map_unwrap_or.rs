@@ -3,6 +3,7 @@
 use clippy_utils::source::snippet;
 use clippy_utils::ty::is_type_diagnostic_item;
 use clippy_utils::usage::mutated_variables;
+use clippy_utils::visitors::is_local_used;
 use rustc_errors::Applicability;
 use rustc_hir as hir;
 use rustc_lint::LateContext;
@@ -25,20 +26,34 @@ pub(super) fn check<'tcx>(
     let is_option = is_type_diagnostic_item(cx, cx.typeck_results().expr_ty(recv), sym::Option);
     let is_result = is_type_diagnostic_item(cx, cx.typeck_results().expr_ty(recv), sym::Result);
 
-    if is_result && !msrv.meets(msrvs::RESULT_MAP_OR_ELSE) {
-        return false;
-    }
+    // Before `Result::map_or_else`, `ok().map_or_else(..)` can be used if `<g>` ignores the error.
+    let via_ok = is_result && !msrv.meets(msrvs::RESULT_MAP_OR_ELSE);
+    let unwrap_snippet = if via_ok {
+        match ignored_error_closure_body(cx, unwrap_arg) {
+            Some(body) => format!("|| {}", snippet(cx, body.span, "..")),
+            None => return false,
+        }
+    } else {
+        snippet(cx, unwrap_arg.span, "..").into_owned()
+    };
 
     if is_option || is_result {
-        // Don't make a suggestion that may fail to compile due to mutably borrowing
-        // the same variable twice.
-        let map_mutated_vars = mutated_variables(recv, cx);
-        let unwrap_mutated_vars = mutated_variables(unwrap_arg, cx);
-        if let (Some(map_mutated_vars), Some(unwrap_mutated_vars)) = (map_mutated_vars, unwrap_mutated_vars) {
-            if map_mutated_vars.intersection(&unwrap_mutated_vars).next().is_some() {
-                return false;
-            }
-        } else {
+        // Don't make a suggestion that may fail to compile due to borrowing the same variable
+        // mutably in one closure and in any way in the other. Mutable borrows held by the
+        // receiver also live as long as the closures.
+        let (Some(recv_mutated_vars), Some(map_mutated_vars), Some(unwrap_mutated_vars)) = (
+            mutated_variables(recv, cx),
+            mutated_variables(map_arg, cx),
+            mutated_variables(unwrap_arg, cx),
+        ) else {
+            return false;
+        };
+        if recv_mutated_vars
+            .iter()
+            .chain(&map_mutated_vars)
+            .any(|&id| is_local_used(cx, unwrap_arg, id))
+            || unwrap_mutated_vars.iter().any(|&id| is_local_used(cx, map_arg, id))
+        {
             return false;
         }
 
@@ -46,13 +61,15 @@ pub(super) fn check<'tcx>(
         let msg = if is_option {
             "called `map(<f>).unwrap_or_else(<g>)` on an `Option` value. This can be done more directly by calling \
             `map_or_else(<g>, <f>)` instead"
-        } else {
+        } else if !via_ok {
             "called `map(<f>).unwrap_or_else(<g>)` on a `Result` value. This can be done more directly by calling \
             `.map_or_else(<g>, <f>)` instead"
+        } else {
+            "called `map(<f>).unwrap_or_else(<g>)` on a `Result` value. This can be done more directly by calling \
+            `.ok().map_or_else(<g>, <f>)` instead"
         };
         // get snippets for args to map() and unwrap_or_else()
         let map_snippet = snippet(cx, map_arg.span, "..");
-        let unwrap_snippet = snippet(cx, unwrap_arg.span, "..");
         // lint, with note if neither arg is > 1 line and both map() and
         // unwrap_or_else() have the same span
         let multiline = map_snippet.lines().count() > 1 || unwrap_snippet.lines().count() > 1;
@@ -65,7 +82,11 @@ pub(super) fn check<'tcx>(
                 expr.span,
                 msg,
                 "try",
-                format!("{var_snippet}.map_or_else({unwrap_snippet}, {map_snippet})"),
+                if via_ok {
+                    format!("{var_snippet}.ok().map_or_else({unwrap_snippet}, {map_snippet})")
+                } else {
+                    format!("{var_snippet}.map_or_else({unwrap_snippet}, {map_snippet})")
+                },
                 Applicability::MachineApplicable,
             );
             return true;
@@ -77,3 +98,20 @@ pub(super) fn check<'tcx>(
 
     false
 }
+
+/// Gets the body of the closure `|_| body`, which doesn't use the error it's called with.
+fn ignored_error_closure_body<'tcx>(cx: &LateContext<'tcx>, expr: &hir::Expr<'_>) -> Option<&'tcx hir::Expr<'tcx>> {
+    if let hir::ExprKind::Closure(&hir::Closure { body, .. }) = expr.kind
+        && let body = cx.tcx.hir().body(body)
+        && let [param] = body.params
+        && match param.pat.kind {
+            hir::PatKind::Wild => true,
+            hir::PatKind::Binding(_, id, _, None) => !is_local_used(cx, body.value, id),
+            _ => false,
+        }
+    {
+        Some(body.value)
+    } else {
+        None
+    }
+}
@@ -46,6 +46,7 @@
 mod iter_skip_next;
 mod iter_with_drain;
 mod iterator_step_by_zero;
+mod join_absolute_paths;
 mod manual_next_back;
 mod manual_ok_or;
 mod manual_saturating_arithmetic;
@@ -114,9 +115,10 @@
 use clippy_utils::diagnostics::{span_lint, span_lint_and_help};
 use clippy_utils::msrvs::{self, Msrv};
 use clippy_utils::ty::{contains_ty_adt_constructor_opaque, implements_trait, is_copy, is_type_diagnostic_item};
-use clippy_utils::{contains_return, is_bool, is_trait_method, iter_input_pats, return_ty};
+use clippy_utils::{contains_return, def_path_def_ids, is_bool, is_trait_method, iter_input_pats, return_ty};
 use if_chain::if_chain;
 use rustc_hir as hir;
+use rustc_hir::def_id::DefIdSet;
 use rustc_hir::{Expr, ExprKind, Node, Stmt, StmtKind, TraitItem, TraitItemKind};
 use rustc_hir_analysis::hir_ty_to_ty;
 use rustc_lint::{LateContext, LateLintPass, LintContext};
@@ -501,14 +503,18 @@
 declare_clippy_lint! {
     /// ### What it does
     /// Checks for usage of `option.map(_).unwrap_or(_)` or `option.map(_).unwrap_or_else(_)` or
-    /// `result.map(_).unwrap_or_else(_)`.
+    /// `result.map(_).unwrap_or(_)` or `result.map(_).unwrap_or_else(_)`.
     ///
     /// ### Why is this bad?
     /// Readability, these can be written more concisely (resp.) as
-    /// `option.map_or(_, _)`, `option.map_or_else(_, _)` and `result.map_or_else(_, _)`.
+    /// `option.map_or(_, _)`, `option.map_or_else(_, _)`, `result.map_or(_, _)` and
+    /// `result.map_or_else(_, _)`.
     ///
     /// ### Known problems
-    /// The order of the arguments is not in execution order
+    /// The order of the arguments is not in execution order.
+    ///
+    /// Before Rust 1.41, `Result` has no `map_or` or `map_or_else`, so `result.ok()` is called
+    /// first. This is only suggested if the `unwrap_or_else` closure ignores the error.
     ///
     /// ### Examples
     /// ```rust
@@ -595,7 +601,8 @@
     ///
     /// ### Why is this bad?
     /// Readability, this can be written more concisely as
-    /// `_.map(|x| y)` or `_.map_err(|x| y)`.
+    /// `_.map(|x| y)` or `_.map_err(|x| y)`. When `y` is `x` itself, e.g.
+    /// `_.and_then(|x| Some(x))` or `_.or_else(Err)`, the call can be removed altogether.
     ///
     /// ### Example
     /// ```rust
@@ -1410,11 +1417,11 @@
 
 declare_clippy_lint! {
     /// ### What it does
-    /// Checks for the use of `.cloned().collect()` on slice to
-    /// create a `Vec`.
+    /// Checks for the use of `.cloned().collect()` or `.copied().collect()` on a slice or a
+    /// `VecDeque` to create a `Vec`.
     ///
     /// ### Why is this bad?
-    /// `.to_vec()` is clearer
+    /// `.to_vec()` is clearer. For a `VecDeque`, converting a clone of it with `Vec::from` is.
     ///
     /// ### Example
     /// ```rust
@@ -1946,6 +1953,9 @@
     /// ### What it does
     /// Checks for usage of `filter_map(|x| x)`.
     ///
+    /// The other spellings of `flatten` for an iterator of `Option`s are linted by
+    /// `flat_map_identity` and `option_filter_map`.
+    ///
     /// ### Why is this bad?
     /// Readability, this can be written more concisely by using `flatten`.
     ///
@@ -3378,11 +3388,44 @@
     "calling `Stdin::read_line`, then trying to parse it without first trimming"
 }
 
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for calls to `Path::join` with a string literal that starts with a path separator,
+    /// or with a drive prefix like `C:` when compiling for Windows.
+    ///
+    /// ### Why is this bad?
+    /// Joining an absolute path replaces the original path entirely, which is rarely intended and
+    /// can lead to path traversal bugs. Calls to `PathBuf::push` are checked by
+    /// [`path_buf_push_overwrite`](#path_buf_push_overwrite).
+    ///
+    /// ### Example
+    /// ```rust
+    /// # use std::path::{Path, PathBuf};
+    /// let path = Path::new("/bin");
+    /// let joined_path = path.join("/sh");
+    /// assert_eq!(joined_path, PathBuf::from("/sh"));
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # use std::path::{Path, PathBuf};
+    /// let path = Path::new("/bin");
+    /// let joined_path = path.join("sh");
+    /// assert_eq!(joined_path, PathBuf::from("/bin/sh"));
+    /// ```
+    #[clippy::version = "1.73.0"]
+    pub JOIN_ABSOLUTE_PATHS,
+    suspicious,
+    "calling `Path::join` with an absolute path, which replaces the original path"
+}
+
 pub struct Methods {
     avoid_breaking_exported_api: bool,
     msrv: Msrv,
     allow_expect_in_tests: bool,
     allow_unwrap_in_tests: bool,
+    or_fun_call_cheap_fns: Vec<String>,
+    /// The resolved `or_fun_call_cheap_fns`.
+    cheap_fns: DefIdSet,
 }
 
 impl Methods {
@@ -3392,12 +3435,15 @@ pub fn new(
         msrv: Msrv,
         allow_expect_in_tests: bool,
         allow_unwrap_in_tests: bool,
+        or_fun_call_cheap_fns: Vec<String>,
     ) -> Self {
         Self {
             avoid_breaking_exported_api,
             msrv,
             allow_expect_in_tests,
             allow_unwrap_in_tests,
+            or_fun_call_cheap_fns,
+            cheap_fns: DefIdSet::default(),
         }
     }
 }
@@ -3512,6 +3558,7 @@ pub fn new(
     UNNECESSARY_LITERAL_UNWRAP,
     DRAIN_COLLECT,
     MANUAL_TRY_FOLD,
+    JOIN_ABSOLUTE_PATHS,
 ]);
 
 /// Extracts a method call name, args, and `Span` of the method name.
@@ -3528,6 +3575,13 @@ fn method_call<'tcx>(
 }
 
 impl<'tcx> LateLintPass<'tcx> for Methods {
+    fn check_crate(&mut self, cx: &LateContext<'tcx>) {
+        for path in &self.or_fun_call_cheap_fns {
+            let segs: Vec<_> = path.split("::").collect();
+            self.cheap_fns.extend(def_path_def_ids(cx, &segs));
+        }
+    }
+
     fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx hir::Expr<'_>) {
         if expr.span.from_expansion() {
             return;
@@ -3541,7 +3595,15 @@ fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx hir::Expr<'_>) {
             },
             hir::ExprKind::MethodCall(method_call, receiver, args, _) => {
                 let method_span = method_call.ident.span;
-                or_fun_call::check(cx, expr, method_span, method_call.ident.as_str(), receiver, args);
+                or_fun_call::check(
+                    cx,
+                    expr,
+                    method_span,
+                    method_call.ident.as_str(),
+                    receiver,
+                    args,
+                    &self.cheap_fns,
+                );
                 expect_fun_call::check(cx, expr, method_span, method_call.ident.as_str(), receiver, args);
                 clone_on_copy::check(cx, expr, method_call.ident.name, receiver, args);
                 clone_on_ref_ptr::check(cx, expr, method_call.ident.name, receiver, args);
@@ -3831,6 +3893,8 @@ fn check_methods<'tcx>(&self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
                 ("join", [join_arg]) => {
                     if let Some(("collect", _, _, span, _)) = method_call(recv) {
                         unnecessary_join::check(cx, expr, recv, join_arg, span);
+                    } else {
+                        join_absolute_paths::check(cx, expr, join_arg);
                     }
                 },
                 ("last", []) | ("skip", [_]) => {
@@ -1,4 +1,4 @@
-use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::diagnostics::span_lint_and_multipart_sugg;
 use clippy_utils::msrvs::{self, Msrv};
 use clippy_utils::source::snippet_with_applicability;
 use clippy_utils::ty::{is_copy, is_type_diagnostic_item};
@@ -14,7 +14,7 @@
 
 use super::MAP_UNWRAP_OR;
 
-/// lint use of `map().unwrap_or()` for `Option`s
+/// lint use of `map().unwrap_or()` for `Option`s and `Result`s
 #[expect(clippy::too_many_arguments)]
 pub(super) fn check<'tcx>(
     cx: &LateContext<'tcx>,
@@ -26,8 +26,11 @@ pub(super) fn check<'tcx>(
     map_span: Span,
     msrv: &Msrv,
 ) {
-    // lint if the caller of `map()` is an `Option`
-    if is_type_diagnostic_item(cx, cx.typeck_results().expr_ty(recv), sym::Option) {
+    // lint if the caller of `map()` is an `Option` or a `Result`
+    let recv_ty = cx.typeck_results().expr_ty(recv);
+    let is_option = is_type_diagnostic_item(cx, recv_ty, sym::Option);
+    let is_result = is_type_diagnostic_item(cx, recv_ty, sym::Result);
+    if is_option || is_result {
         if !is_copy(cx, cx.typeck_results().expr_ty(unwrap_arg)) {
             // Replacing `.map(<f>).unwrap_or(<a>)` with `.map_or(<a>, <f>)` can sometimes lead to
             // borrowck errors, see #10579 for one such instance.
@@ -72,10 +75,17 @@ pub(super) fn check<'tcx>(
             return;
         }
 
-        // is_some_and is stabilised && `unwrap_or` argument is false; suggest `is_some_and` instead
-        let suggest_is_some_and = msrv.meets(msrvs::OPTION_IS_SOME_AND)
-            && matches!(&unwrap_arg.kind, ExprKind::Lit(lit)
+        // is_some_and/is_ok_and is stabilised && `unwrap_or` argument is false; suggest it instead
+        let is_false = matches!(&unwrap_arg.kind, ExprKind::Lit(lit)
             if matches!(lit.node, rustc_ast::LitKind::Bool(false)));
+        let suggest_is_some_and = is_false
+            && if is_option {
+                msrv.meets(msrvs::OPTION_IS_SOME_AND)
+            } else {
+                msrv.meets(msrvs::RESULT_IS_OK_AND)
+            };
+        // before `Result::map_or`, the `Result` has to be turned into an `Option` first
+        let via_ok = is_result && !msrv.meets(msrvs::RESULT_MAP_OR);
 
         let mut applicability = Applicability::MachineApplicable;
         // get snippet for unwrap_or()
@@ -83,49 +93,56 @@ pub(super) fn check<'tcx>(
         // lint message
         // comparing the snippet from source to raw text ("None") below is safe
         // because we already have checked the type.
-        let arg = if unwrap_snippet == "None" {
+        let unwrap_snippet_none = is_option && unwrap_snippet == "None";
+        let arg = if unwrap_snippet_none {
             "None"
         } else if suggest_is_some_and {
             "false"
         } else {
             "<a>"
         };
-        let unwrap_snippet_none = unwrap_snippet == "None";
-        let suggest = if unwrap_snippet_none {
-            "and_then(<f>)"
+        let method = if unwrap_snippet_none {
+            "and_then"
         } else if suggest_is_some_and {
-            "is_some_and(<f>)"
+            if is_option {
+                "is_some_and"
+            } else {
+                "is_ok_and"
+            }
+        } else if via_ok {
+            "ok().map_or"
+        } else {
+            "map_or"
+        };
+        let suggest = if unwrap_snippet_none || suggest_is_some_and {
+            format!("{method}(<f>)")
         } else {
-            "map_or(<a>, <f>)"
+            format!("{method}(<a>, <f>)")
         };
         let msg = &format!(
-            "called `map(<f>).unwrap_or({arg})` on an `Option` value. \
-            This can be done more directly by calling `{suggest}` instead"
+            "called `map(<f>).unwrap_or({arg})` on {} value. \
+            This can be done more directly by calling `{suggest}` instead",
+            if is_option { "an `Option`" } else { "a `Result`" }
         );
 
-        span_lint_and_then(cx, MAP_UNWRAP_OR, expr.span, msg, |diag| {
-            let map_arg_span = map_arg.span;
-
-            let mut suggestion = vec![
-                (
-                    map_span,
-                    String::from(if unwrap_snippet_none {
-                        "and_then"
-                    } else if suggest_is_some_and {
-                        "is_some_and"
-                    } else {
-                        "map_or"
-                    }),
-                ),
-                (expr.span.with_lo(unwrap_recv.span.hi()), String::new()),
-            ];
-
-            if !unwrap_snippet_none && !suggest_is_some_and {
-                suggestion.push((map_arg_span.with_hi(map_arg_span.lo()), format!("{unwrap_snippet}, ")));
-            }
+        let mut suggestion = vec![
+            (map_span, String::from(method)),
+            (expr.span.with_lo(unwrap_recv.span.hi()), String::new()),
+        ];
+
+        if !unwrap_snippet_none && !suggest_is_some_and {
+            suggestion.push((map_arg.span.shrink_to_lo(), format!("{unwrap_snippet}, ")));
+        }
 
-            diag.multipart_suggestion(format!("use `{suggest}` instead"), suggestion, applicability);
-        });
+        span_lint_and_multipart_sugg(
+            cx,
+            MAP_UNWRAP_OR,
+            expr.span,
+            msg,
+            &format!("use `{suggest}` instead"),
+            suggestion,
+            applicability,
+        );
     }
 }
 
@@ -2,10 +2,11 @@
 use clippy_utils::eager_or_lazy::switch_to_lazy_eval;
 use clippy_utils::source::snippet_with_context;
 use clippy_utils::ty::{implements_trait, is_type_diagnostic_item};
-use clippy_utils::{contains_return, is_trait_item, last_path_segment};
+use clippy_utils::{contains_return, is_trait_item, last_path_segment, path_def_id};
 use if_chain::if_chain;
 use rustc_errors::Applicability;
 use rustc_hir as hir;
+use rustc_hir::def_id::DefIdSet;
 use rustc_lint::LateContext;
 use rustc_span::source_map::Span;
 use rustc_span::symbol::{kw, sym, Symbol};
@@ -21,6 +22,7 @@ pub(super) fn check<'tcx>(
     name: &str,
     receiver: &'tcx hir::Expr<'_>,
     args: &'tcx [hir::Expr<'_>],
+    cheap_fns: &DefIdSet,
 ) {
     /// Checks for `unwrap_or(T::new())`, `unwrap_or(T::default())`,
     /// `or_insert(T::new())` or `or_insert(T::default())`.
@@ -163,12 +165,20 @@ fn check_general_case<'tcx>(
         }
     };
 
+    // Calls to functions configured as cheap, with arguments that are cheap themselves.
+    let is_cheap_call = |fun: &hir::Expr<'_>, or_args: &'tcx [hir::Expr<'_>]| {
+        path_def_id(cx, fun).map_or(false, |id| cheap_fns.contains(&id))
+            && !or_args.iter().any(|arg| switch_to_lazy_eval(cx, arg))
+    };
+
     if let [arg] = args {
         let inner_arg = extract_inner_arg(arg);
         match inner_arg.kind {
             hir::ExprKind::Call(fun, or_args) => {
                 let or_has_args = !or_args.is_empty();
-                if !check_unwrap_or_default(cx, name, fun, arg, or_has_args, expr.span, method_span) {
+                if !check_unwrap_or_default(cx, name, fun, arg, or_has_args, expr.span, method_span)
+                    && !is_cheap_call(fun, or_args)
+                {
                     let fun_span = if or_has_args { None } else { Some(fun.span) };
                     check_general_case(cx, name, method_span, receiver, arg, None, expr.span, fun_span);
                 }
@@ -183,7 +193,9 @@ fn check_general_case<'tcx>(
     // `map_or` takes two arguments
     if let [arg, lambda] = args {
         let inner_arg = extract_inner_arg(arg);
-        if let hir::ExprKind::Call(fun, or_args) = inner_arg.kind {
+        if let hir::ExprKind::Call(fun, or_args) = inner_arg.kind
+            && !is_cheap_call(fun, or_args)
+        {
             let fun_span = if or_args.is_empty() { Some(fun.span) } else { None };
             check_general_case(cx, name, method_span, receiver, arg, Some(lambda), expr.span, fun_span);
         }
@@ -14,7 +14,7 @@
 use rustc_span::hygiene::DesugaringKind;
 use rustc_span::source_map::{ExpnKind, Span};
 
-use clippy_utils::sugg::Sugg;
+use clippy_utils::sugg::{has_total_ord_operands, Sugg};
 use clippy_utils::{
     get_parent_expr, in_constant, is_integer_literal, is_lint_allowed, is_no_std_crate, iter_input_pats,
     last_path_segment, SpanlessEq,
@@ -237,7 +237,11 @@ fn check_stmt(&mut self, cx: &LateContext<'tcx>, stmt: &'tcx Stmt<'_>) {
                     stmt.span,
                     "boolean short circuit operator in statement may be clearer using an explicit test",
                     |diag| {
-                        let sugg = if binop.node == BinOpKind::Or { !sugg } else { sugg };
+                        let sugg = if binop.node == BinOpKind::Or {
+                            sugg.negate(has_total_ord_operands(cx, a))
+                        } else {
+                            sugg
+                        };
                         diag.span_suggestion(
                             stmt.span,
                             "replace it with",
@@ -0,0 +1,163 @@
+use clippy_utils::diagnostics::span_lint_and_help;
+use clippy_utils::path_to_local;
+use clippy_utils::source::snippet;
+use clippy_utils::ty::is_type_diagnostic_item;
+use rustc_data_structures::fx::FxHashMap;
+use rustc_hir::def_id::LocalDefId;
+use rustc_hir::intravisit::FnKind;
+use rustc_hir::{
+    BindingAnnotation, Body, BorrowKind, Expr, ExprKind, FnDecl, HirId, HirIdSet, Local, Mutability, Node, PatKind,
+    QPath,
+};
+use rustc_hir_typeck::expr_use_visitor as euv;
+use rustc_infer::infer::TyCtxtInferExt;
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::mir::FakeReadCause;
+use rustc_middle::ty;
+use rustc_session::{declare_tool_lint, impl_lint_pass};
+use rustc_span::{sym, Span};
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for `Arc::clone(&x)` and `Rc::clone(&x)` where the clone is bound to a variable
+    /// that is only ever borrowed.
+    ///
+    /// ### Why is this bad?
+    /// Cloning an `Arc` or `Rc` bumps its reference count. If the clone is never moved
+    /// anywhere, e.g. into a spawned thread, the original could have been borrowed instead.
+    ///
+    /// ### Example
+    /// ```rust
+    /// # use std::sync::Arc;
+    /// let shared = Arc::new(vec![1, 2, 3]);
+    /// for i in 0..3 {
+    ///     let data = Arc::clone(&shared);
+    ///     println!("{}", data[i]);
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # use std::sync::Arc;
+    /// let shared = Arc::new(vec![1, 2, 3]);
+    /// for i in 0..3 {
+    ///     println!("{}", shared[i]);
+    /// }
+    /// ```
+    #[clippy::version = "1.73.0"]
+    pub NEEDLESS_ARC_CLONE_IN_LOOP,
+    nursery,
+    "cloning an `Arc` or `Rc` whose clone is only ever borrowed"
+}
+
+#[derive(Default)]
+pub struct NeedlessArcClone {
+    /// How the locals of each function currently being checked are used.
+    usages: FxHashMap<LocalDefId, LocalUsages>,
+}
+
+impl_lint_pass!(NeedlessArcClone => [NEEDLESS_ARC_CLONE_IN_LOOP]);
+
+impl<'tcx> LateLintPass<'tcx> for NeedlessArcClone {
+    fn check_fn(
+        &mut self,
+        cx: &LateContext<'tcx>,
+        kind: FnKind<'tcx>,
+        _: &'tcx FnDecl<'_>,
+        body: &'tcx Body<'_>,
+        _: Span,
+        fn_def_id: LocalDefId,
+    ) {
+        // Uses inside of closures are reported as captures when walking the enclosing function.
+        if matches!(kind, FnKind::Closure) {
+            return;
+        }
+        let mut usages = LocalUsages::default();
+        let infcx = cx.tcx.infer_ctxt().build();
+        euv::ExprUseVisitor::new(&mut usages, &infcx, fn_def_id, cx.param_env, cx.typeck_results()).consume_body(body);
+        self.usages.insert(fn_def_id, usages);
+    }
+
+    fn check_body_post(&mut self, cx: &LateContext<'tcx>, body: &'tcx Body<'_>) {
+        self.usages.remove(&cx.tcx.hir().body_owner_def_id(body.id()));
+    }
+
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
+        if !expr.span.from_expansion()
+            && let ExprKind::Call(func, [arg]) = expr.kind
+            && let ExprKind::Path(QPath::TypeRelative(ty, segment)) = func.kind
+            && segment.ident.name == sym::clone
+            && let clone_ty = cx.typeck_results().node_type(ty.hir_id)
+            && let Some(name) = [sym::Arc, sym::Rc]
+                .into_iter()
+                .find(|&name| is_type_diagnostic_item(cx, clone_ty, name))
+            && let ExprKind::AddrOf(BorrowKind::Ref, Mutability::Not, original) = arg.kind
+            && let Some(original_id) = path_to_local(original)
+            && let Some(Node::Local(Local {
+                pat,
+                init: Some(init),
+                ..
+            })) = cx.tcx.hir().find_parent(expr.hir_id)
+            && init.hir_id == expr.hir_id
+            && let PatKind::Binding(BindingAnnotation::NONE, clone_id, _, None) = pat.kind
+            && let Some(usages) = self.usages.get(&expr.hir_id.owner.def_id)
+            && usages.is_only_borrowed(clone_id)
+            // The original has to stay untouched for as long as the clone would have been used.
+            && !usages.moved_or_mutated.contains(&original_id)
+        {
+            span_lint_and_help(
+                cx,
+                NEEDLESS_ARC_CLONE_IN_LOOP,
+                expr.span,
+                &format!("this `{name}` is cloned, but the clone is only ever borrowed"),
+                None,
+                &format!(
+                    "consider borrowing `{}` directly instead of cloning it",
+                    snippet(cx, original.span, "..")
+                ),
+            );
+        }
+    }
+}
+
+#[derive(Default)]
+struct LocalUsages {
+    /// Locals which are borrowed immutably at least once.
+    borrowed: HirIdSet,
+    /// Locals which are moved, assigned to or borrowed mutably at least once.
+    moved_or_mutated: HirIdSet,
+}
+
+impl LocalUsages {
+    fn is_only_borrowed(&self, id: HirId) -> bool {
+        self.borrowed.contains(&id) && !self.moved_or_mutated.contains(&id)
+    }
+}
+
+impl<'tcx> euv::Delegate<'tcx> for LocalUsages {
+    fn consume(&mut self, cmt: &euv::PlaceWithHirId<'tcx>, _: HirId) {
+        if let euv::PlaceBase::Local(id) = cmt.place.base {
+            self.moved_or_mutated.insert(id);
+        }
+    }
+
+    fn borrow(&mut self, cmt: &euv::PlaceWithHirId<'tcx>, _: HirId, bk: ty::BorrowKind) {
+        if let euv::PlaceBase::Local(id) = cmt.place.base {
+            if bk == ty::BorrowKind::ImmBorrow {
+                self.borrowed.insert(id);
+            } else {
+                self.moved_or_mutated.insert(id);
+            }
+        }
+    }
+
+    fn mutate(&mut self, cmt: &euv::PlaceWithHirId<'tcx>, _: HirId) {
+        if let euv::PlaceBase::Local(id) = cmt.place.base {
+            self.moved_or_mutated.insert(id);
+        }
+    }
+
+    // Initializing a binding is neither a move nor a mutation of it.
+    fn bind(&mut self, _: &euv::PlaceWithHirId<'tcx>, _: HirId) {}
+
+    fn fake_read(&mut self, _: &euv::PlaceWithHirId<'tcx>, _: FakeReadCause, _: HirId) {}
+}
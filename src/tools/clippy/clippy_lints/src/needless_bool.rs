@@ -3,8 +3,8 @@
 //! This lint is **warn** by default
 
 use clippy_utils::diagnostics::{span_lint, span_lint_and_sugg};
-use clippy_utils::source::snippet_with_applicability;
-use clippy_utils::sugg::Sugg;
+use clippy_utils::source::snippet_with_context;
+use clippy_utils::sugg::{has_total_ord_operands, Sugg};
 use clippy_utils::{
     get_parent_node, higher, is_else_clause, is_expn_of, peel_blocks, peel_blocks_with_stmt, span_extract_comment,
     SpanlessEq,
@@ -157,8 +157,12 @@ fn check_expr(&mut self, cx: &LateContext<'tcx>, e: &'tcx Expr<'_>) {
         {
             let reduce = |ret, not| {
                 let mut applicability = Applicability::MachineApplicable;
-                let snip = Sugg::hir_with_applicability(cx, cond, "<predicate>", &mut applicability);
-                let mut snip = if not { !snip } else { snip };
+                let snip = Sugg::hir_with_context(cx, cond, e.span.ctxt(), "<predicate>", &mut applicability);
+                let mut snip = if not {
+                    snip.negate(has_total_ord_operands(cx, cond))
+                } else {
+                    snip
+                };
 
                 if ret {
                     snip = snip.make_return();
@@ -212,12 +216,13 @@ fn check_expr(&mut self, cx: &LateContext<'tcx>, e: &'tcx Expr<'_>) {
                 SpanlessEq::new(cx).eq_expr(lhs_a, lhs_b)
             {
                 let mut applicability = Applicability::MachineApplicable;
-                let cond = Sugg::hir_with_applicability(cx, cond, "..", &mut applicability);
-                let lhs = snippet_with_applicability(cx, lhs_a.span, "..", &mut applicability);
+                let total_ord = has_total_ord_operands(cx, cond);
+                let cond = Sugg::hir_with_context(cx, cond, e.span.ctxt(), "..", &mut applicability);
+                let lhs = snippet_with_context(cx, lhs_a.span, e.span.ctxt(), "..", &mut applicability).0;
                 let sugg = if a == b {
                     format!("{cond}; {lhs} = {a:?};")
                 } else {
-                    format!("{lhs} = {};", if a { cond } else { !cond })
+                    format!("{lhs} = {};", if a { cond } else { cond.negate(total_ord) })
                 };
                 span_lint_and_sugg(
                     cx,
@@ -242,37 +247,37 @@ fn check_expr(&mut self, cx: &LateContext<'tcx>, e: &'tcx Expr<'_>) {
         }
 
         if let ExprKind::Binary(Spanned { node, .. }, ..) = e.kind {
-            let ignore_case = None::<(fn(_) -> _, &str)>;
-            let ignore_no_literal = None::<(fn(_, _) -> _, &str)>;
+            let ignore_case = None::<(fn(_, _) -> _, &str)>;
+            let ignore_no_literal = None::<(fn(_, _, _) -> _, &str)>;
             match node {
                 BinOpKind::Eq => {
-                    let true_case = Some((|h| h, "equality checks against true are unnecessary"));
+                    let true_case = Some((|h, _| h, "equality checks against true are unnecessary"));
                     let false_case = Some((
-                        |h: Sugg<'tcx>| !h,
+                        |h: Sugg<'tcx>, total_ord| h.negate(total_ord),
                         "equality checks against false can be replaced by a negation",
                     ));
                     check_comparison(cx, e, true_case, false_case, true_case, false_case, ignore_no_literal);
                 },
                 BinOpKind::Ne => {
                     let true_case = Some((
-                        |h: Sugg<'tcx>| !h,
+                        |h: Sugg<'tcx>, total_ord| h.negate(total_ord),
                         "inequality checks against true can be replaced by a negation",
                     ));
-                    let false_case = Some((|h| h, "inequality checks against false are unnecessary"));
+                    let false_case = Some((|h, _| h, "inequality checks against false are unnecessary"));
                     check_comparison(cx, e, true_case, false_case, true_case, false_case, ignore_no_literal);
                 },
                 BinOpKind::Lt => check_comparison(
                     cx,
                     e,
                     ignore_case,
-                    Some((|h| h, "greater than checks against false are unnecessary")),
+                    Some((|h, _| h, "greater than checks against false are unnecessary")),
                     Some((
-                        |h: Sugg<'tcx>| !h,
+                        |h: Sugg<'tcx>, total_ord| h.negate(total_ord),
                         "less than comparison against true can be replaced by a negation",
                     )),
                     ignore_case,
                     Some((
-                        |l: Sugg<'tcx>, r: Sugg<'tcx>| (!l).bit_and(&r),
+                        |l: Sugg<'tcx>, r: Sugg<'tcx>, (l_total_ord, _)| l.negate(l_total_ord).bit_and(&r),
                         "order comparisons between booleans can be simplified",
                     )),
                 ),
@@ -280,14 +285,14 @@ fn check_expr(&mut self, cx: &LateContext<'tcx>, e: &'tcx Expr<'_>) {
                     cx,
                     e,
                     Some((
-                        |h: Sugg<'tcx>| !h,
+                        |h: Sugg<'tcx>, total_ord| h.negate(total_ord),
                         "less than comparison against true can be replaced by a negation",
                     )),
                     ignore_case,
                     ignore_case,
-                    Some((|h| h, "greater than checks against false are unnecessary")),
+                    Some((|h, _| h, "greater than checks against false are unnecessary")),
                     Some((
-                        |l: Sugg<'tcx>, r: Sugg<'tcx>| l.bit_and(&(!r)),
+                        |l: Sugg<'tcx>, r: Sugg<'tcx>, (_, r_total_ord)| l.bit_and(&r.negate(r_total_ord)),
                         "order comparisons between booleans can be simplified",
                     )),
                 ),
@@ -324,11 +329,11 @@ fn one_side_is_unary_not<'tcx>(left_side: &'tcx Expr<'_>, right_side: &'tcx Expr
 fn check_comparison<'a, 'tcx>(
     cx: &LateContext<'tcx>,
     e: &'tcx Expr<'_>,
-    left_true: Option<(impl FnOnce(Sugg<'a>) -> Sugg<'a>, &str)>,
-    left_false: Option<(impl FnOnce(Sugg<'a>) -> Sugg<'a>, &str)>,
-    right_true: Option<(impl FnOnce(Sugg<'a>) -> Sugg<'a>, &str)>,
-    right_false: Option<(impl FnOnce(Sugg<'a>) -> Sugg<'a>, &str)>,
-    no_literal: Option<(impl FnOnce(Sugg<'a>, Sugg<'a>) -> Sugg<'a>, &str)>,
+    left_true: Option<(impl FnOnce(Sugg<'a>, bool) -> Sugg<'a>, &str)>,
+    left_false: Option<(impl FnOnce(Sugg<'a>, bool) -> Sugg<'a>, &str)>,
+    right_true: Option<(impl FnOnce(Sugg<'a>, bool) -> Sugg<'a>, &str)>,
+    right_false: Option<(impl FnOnce(Sugg<'a>, bool) -> Sugg<'a>, &str)>,
+    no_literal: Option<(impl FnOnce(Sugg<'a>, Sugg<'a>, (bool, bool)) -> Sugg<'a>, &str)>,
 ) {
     if let ExprKind::Binary(op, left_side, right_side) = e.kind {
         let (l_ty, r_ty) = (
@@ -344,17 +349,16 @@ fn check_comparison<'a, 'tcx>(
             if op.node == BinOpKind::Eq {
                 let expression_info = one_side_is_unary_not(left_side, right_side);
                 if expression_info.one_side_is_unary_not {
+                    let ctxt = e.span.ctxt();
+                    let left = snippet_with_context(cx, expression_info.left_span, ctxt, "..", &mut applicability).0;
+                    let right = snippet_with_context(cx, expression_info.right_span, ctxt, "..", &mut applicability).0;
                     span_lint_and_sugg(
                         cx,
                         BOOL_COMPARISON,
                         e.span,
                         "this comparison might be written more concisely",
                         "try simplifying it as shown",
-                        format!(
-                            "{} != {}",
-                            snippet_with_applicability(cx, expression_info.left_span, "..", &mut applicability),
-                            snippet_with_applicability(cx, expression_info.right_span, "..", &mut applicability)
-                        ),
+                        format!("{left} != {right}"),
                         applicability,
                     );
                 }
@@ -374,15 +378,19 @@ fn check_comparison<'a, 'tcx>(
                     suggest_bool_comparison(cx, e, left_side, applicability, m, h);
                 }),
                 (None, None) => no_literal.map_or((), |(h, m)| {
-                    let left_side = Sugg::hir_with_applicability(cx, left_side, "..", &mut applicability);
-                    let right_side = Sugg::hir_with_applicability(cx, right_side, "..", &mut applicability);
+                    let total_ord = (
+                        has_total_ord_operands(cx, left_side),
+                        has_total_ord_operands(cx, right_side),
+                    );
+                    let left_side = Sugg::hir_with_context(cx, left_side, e.span.ctxt(), "..", &mut applicability);
+                    let right_side = Sugg::hir_with_context(cx, right_side, e.span.ctxt(), "..", &mut applicability);
                     span_lint_and_sugg(
                         cx,
                         BOOL_COMPARISON,
                         e.span,
                         m,
                         "try simplifying it as shown",
-                        h(left_side, right_side).to_string(),
+                        h(left_side, right_side, total_ord).to_string(),
                         applicability,
                     );
                 }),
@@ -398,8 +406,9 @@ fn suggest_bool_comparison<'a, 'tcx>(
     expr: &Expr<'_>,
     mut app: Applicability,
     message: &str,
-    conv_hint: impl FnOnce(Sugg<'a>) -> Sugg<'a>,
+    conv_hint: impl FnOnce(Sugg<'a>, bool) -> Sugg<'a>,
 ) {
+    let total_ord = has_total_ord_operands(cx, expr);
     let hint = Sugg::hir_with_context(cx, expr, e.span.ctxt(), "..", &mut app);
     span_lint_and_sugg(
         cx,
@@ -407,7 +416,7 @@ fn suggest_bool_comparison<'a, 'tcx>(
         e.span,
         message,
         "try simplifying it as shown",
-        conv_hint(hint).to_string(),
+        conv_hint(hint, total_ord).to_string(),
         app,
     );
 }
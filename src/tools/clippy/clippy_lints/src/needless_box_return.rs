@@ -0,0 +1,135 @@
+use clippy_utils::diagnostics::span_lint_and_help;
+use clippy_utils::ty::approx_ty_size;
+use clippy_utils::visitors::find_all_ret_expressions;
+use clippy_utils::{path_def_id, return_ty};
+use rustc_hir::def_id::LocalDefId;
+use rustc_hir::intravisit::FnKind;
+use rustc_hir::{Body, Expr, ExprKind, FnDecl, FnRetTy, Impl, ItemKind, Node, QPath};
+use rustc_lint::{LateContext, LateLintPass, LintContext};
+use rustc_middle::lint::in_external_macro;
+use rustc_session::{declare_tool_lint, impl_lint_pass};
+use rustc_span::{sym, Span};
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for functions returning `Box<T>` for a sized `T`, where every returned value is
+    /// boxed with `Box::new` in the function itself.
+    ///
+    /// Trait methods and their implementations are ignored, as the signature may be required by
+    /// the trait. Types larger than `unnecessary-box-size` are also ignored, as boxing them is
+    /// likely intentional.
+    ///
+    /// ### Why is this bad?
+    /// The allocation is made only to be handed to the caller, who may not need the value to be
+    /// boxed. Returning `T` by value lets the caller decide whether to box it.
+    ///
+    /// ### Example
+    /// ```rust
+    /// fn make_name(first: &str) -> Box<String> {
+    ///     Box::new(format!("{first} Smith"))
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// fn make_name(first: &str) -> String {
+    ///     format!("{first} Smith")
+    /// }
+    /// ```
+    #[clippy::version = "1.73.0"]
+    pub NEEDLESS_BOX_IN_RETURN,
+    nursery,
+    "returning a `Box<T>` of a sized `T` which is only boxed to be returned"
+}
+
+pub struct NeedlessBoxReturn {
+    avoid_breaking_exported_api: bool,
+    maximum_size: u64,
+}
+
+impl_lint_pass!(NeedlessBoxReturn => [NEEDLESS_BOX_IN_RETURN]);
+
+impl NeedlessBoxReturn {
+    pub fn new(avoid_breaking_exported_api: bool, maximum_size: u64) -> Self {
+        Self {
+            avoid_breaking_exported_api,
+            maximum_size,
+        }
+    }
+}
+
+impl<'tcx> LateLintPass<'tcx> for NeedlessBoxReturn {
+    fn check_fn(
+        &mut self,
+        cx: &LateContext<'tcx>,
+        fn_kind: FnKind<'tcx>,
+        fn_decl: &FnDecl<'tcx>,
+        body: &Body<'tcx>,
+        span: Span,
+        def_id: LocalDefId,
+    ) {
+        match fn_kind {
+            FnKind::ItemFn(..) | FnKind::Method(..) => {
+                if self.avoid_breaking_exported_api && cx.effective_visibilities.is_exported(def_id) {
+                    return;
+                }
+            },
+            FnKind::Closure => return,
+        }
+        if in_external_macro(cx.sess(), span) || span.from_expansion() {
+            return;
+        }
+
+        // The signature of trait methods and their implementations isn't up to the function.
+        let hir_id = cx.tcx.hir().local_def_id_to_hir_id(def_id);
+        if let Some(Node::Item(item)) = cx.tcx.hir().find_parent(hir_id)
+            && matches!(
+                item.kind,
+                ItemKind::Impl(Impl { of_trait: Some(_), .. }) | ItemKind::Trait(..)
+            )
+        {
+            return;
+        }
+
+        let FnRetTy::Return(ret_hir_ty) = fn_decl.output else {
+            return;
+        };
+        let ret_ty = return_ty(cx, hir_id.expect_owner());
+        if !ret_ty.is_box() {
+            return;
+        }
+        // Trait objects and other unsized types need the box. Recursive or otherwise large types
+        // are likely boxed on purpose.
+        let boxed_ty = ret_ty.boxed_ty();
+        if !boxed_ty.is_sized(cx.tcx, cx.param_env) || approx_ty_size(cx, boxed_ty) > self.maximum_size {
+            return;
+        }
+
+        let mut any_ret = false;
+        let all_boxed_here = find_all_ret_expressions(cx, body.value, |ret_expr| {
+            any_ret = true;
+            !ret_expr.span.from_expansion() && is_box_new_call(cx, ret_expr)
+        });
+        if all_boxed_here && any_ret {
+            span_lint_and_help(
+                cx,
+                NEEDLESS_BOX_IN_RETURN,
+                ret_hir_ty.span,
+                &format!("this function boxes the returned `{boxed_ty}` only to return it"),
+                None,
+                &format!("return `{boxed_ty}` by value and remove the `Box::new` calls around the returned values"),
+            );
+        }
+    }
+}
+
+fn is_box_new_call(cx: &LateContext<'_>, expr: &Expr<'_>) -> bool {
+    if let ExprKind::Call(func, [_]) = expr.kind
+        && let ExprKind::Path(QPath::TypeRelative(ty, seg)) = func.kind
+        && seg.ident.name == sym::new
+        && let Some(ty_did) = path_def_id(cx, ty)
+    {
+        Some(ty_did) == cx.tcx.lang_items().owned_box()
+    } else {
+        false
+    }
+}
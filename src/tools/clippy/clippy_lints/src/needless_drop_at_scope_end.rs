@@ -0,0 +1,75 @@
+use clippy_utils::diagnostics::span_lint_and_help;
+use clippy_utils::ty::is_copy;
+use clippy_utils::{is_path_diagnostic_item, path_to_local_id};
+use rustc_hir::{Block, ExprKind, PatKind, StmtKind};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_span::sym;
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for `drop(x)` as the last statement of the block declaring `x`, when `x` is the
+    /// last local declared in that block.
+    ///
+    /// ### Why is this bad?
+    /// `x` is dropped at the end of the block anyway, before any other local of the block, so
+    /// the call changes nothing.
+    ///
+    /// ### Example
+    /// ```rust
+    /// # use std::sync::Mutex;
+    /// # let mutex = Mutex::new(0);
+    /// {
+    ///     let mut guard = mutex.lock().unwrap();
+    ///     *guard += 1;
+    ///     drop(guard);
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # use std::sync::Mutex;
+    /// # let mutex = Mutex::new(0);
+    /// {
+    ///     let mut guard = mutex.lock().unwrap();
+    ///     *guard += 1;
+    /// }
+    /// ```
+    #[clippy::version = "1.73.0"]
+    pub NEEDLESS_EXPLICIT_DROP_AT_SCOPE_END,
+    style,
+    "calling `drop` on a local at the end of its scope"
+}
+declare_lint_pass!(NeedlessDropAtScopeEnd => [NEEDLESS_EXPLICIT_DROP_AT_SCOPE_END]);
+
+impl<'tcx> LateLintPass<'tcx> for NeedlessDropAtScopeEnd {
+    fn check_block(&mut self, cx: &LateContext<'tcx>, block: &'tcx Block<'tcx>) {
+        if block.expr.is_none()
+            && let [.., stmt] = block.stmts
+            && let StmtKind::Semi(call) = stmt.kind
+            && !stmt.span.from_expansion()
+            && let ExprKind::Call(func, [arg]) = call.kind
+            && is_path_diagnostic_item(cx, func, sym::mem_drop)
+            // Only the last declared local is dropped first at the end of the block, so dropping
+            // any other one explicitly may be done to release it earlier.
+            && let Some(local) = block.stmts.iter().rev().find_map(|stmt| match stmt.kind {
+                StmtKind::Local(local) => Some(local),
+                _ => None,
+            })
+            && let PatKind::Binding(_, id, name, None) = local.pat.kind
+            && path_to_local_id(arg, id)
+            // Values without drop glue are left to `drop_copy` and `drop_non_drop`.
+            && let arg_ty = cx.typeck_results().expr_ty(arg)
+            && !is_copy(cx, arg_ty)
+            && arg_ty.needs_drop(cx.tcx, cx.param_env)
+        {
+            span_lint_and_help(
+                cx,
+                NEEDLESS_EXPLICIT_DROP_AT_SCOPE_END,
+                stmt.span,
+                &format!("`{name}` is dropped at the end of the block anyway"),
+                None,
+                "remove the call to `drop`",
+            );
+        }
+    }
+}
@@ -4,7 +4,7 @@
 use clippy_utils::ty::{
     implements_trait, implements_trait_with_env, is_copy, is_type_diagnostic_item, is_type_lang_item,
 };
-use clippy_utils::{get_trait_def_id, is_self, paths};
+use clippy_utils::{get_trait_def_id, is_from_proc_macro, is_self, paths};
 use if_chain::if_chain;
 use rustc_ast::ast::Attribute;
 use rustc_errors::{Applicability, Diagnostic};
@@ -88,6 +88,9 @@ fn check_fn(
         }
 
         let hir_id = cx.tcx.hir().local_def_id_to_hir_id(fn_def_id);
+        if is_from_proc_macro(cx, &(&kind, body, hir_id, span)) {
+            return;
+        }
 
         match kind {
             FnKind::ItemFn(.., header) => {
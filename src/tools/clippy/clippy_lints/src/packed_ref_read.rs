@@ -0,0 +1,98 @@
+use clippy_utils::diagnostics::span_lint_and_help;
+use clippy_utils::source::snippet;
+use clippy_utils::{get_parent_expr, match_any_def_paths, paths};
+use rustc_hir::{BorrowKind, Expr, ExprKind, UnOp};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::ty;
+use rustc_middle::ty::layout::LayoutOf;
+use rustc_session::{declare_lint_pass, declare_tool_lint};
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for reads through a raw pointer to a field of a `#[repr(packed)]` struct, like
+    /// `ptr::addr_of!(packed.field).read()`, where the field may be less aligned than its type
+    /// requires.
+    ///
+    /// ### Why is this bad?
+    /// `read`, `read_volatile` and dereferencing assume the pointer is aligned, so reading a
+    /// misaligned field through them is undefined behavior. Taking a reference to the field is
+    /// rejected by the compiler, but the raw pointer isn't.
+    ///
+    /// ### Example
+    /// ```rust,ignore
+    /// #[repr(packed)]
+    /// struct Header {
+    ///     tag: u8,
+    ///     len: u32,
+    /// }
+    ///
+    /// let len = unsafe { std::ptr::addr_of!(header.len).read() };
+    /// ```
+    /// Use instead:
+    /// ```rust,ignore
+    /// let len = unsafe { std::ptr::addr_of!(header.len).read_unaligned() };
+    /// // or copy the field, which the compiler does with an unaligned load
+    /// let len = { header.len };
+    /// ```
+    #[clippy::version = "1.73.0"]
+    pub REPR_PACKED_REFERENCE_TAKEN,
+    correctness,
+    "reading a field of a packed struct through a pointer which assumes alignment"
+}
+declare_lint_pass!(PackedRefRead => [REPR_PACKED_REFERENCE_TAKEN]);
+
+impl<'tcx> LateLintPass<'tcx> for PackedRefRead {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) {
+        let ptr = match expr.kind {
+            ExprKind::Unary(UnOp::Deref, ptr) if !is_assigned_to(cx, expr) => ptr,
+            ExprKind::MethodCall(path, ptr, [], _)
+                if matches!(path.ident.as_str(), "read" | "read_volatile")
+                    && cx.typeck_results().expr_ty(ptr).is_unsafe_ptr() =>
+            {
+                ptr
+            },
+            ExprKind::Call(func, [ptr]) => {
+                static PATHS: &[&[&str]] = &[paths::PTR_READ.as_slice(), paths::PTR_READ_VOLATILE.as_slice()];
+                if let ExprKind::Path(path) = &func.kind
+                    && let Some(def_id) = cx.qpath_res(path, func.hir_id).opt_def_id()
+                    && match_any_def_paths(cx, def_id, PATHS).is_some()
+                {
+                    ptr
+                } else {
+                    return;
+                }
+            },
+            _ => return,
+        };
+
+        if let ExprKind::AddrOf(BorrowKind::Raw, _, field) = ptr.kind
+            && let ExprKind::Field(base, name) = field.kind
+            && let ty::Adt(adt, _) = cx.typeck_results().expr_ty_adjusted(base).kind()
+            && let Some(pack) = adt.repr().pack
+            && let Ok(layout) = cx.layout_of(cx.typeck_results().expr_ty(field))
+            && layout.align.abi > pack
+        {
+            span_lint_and_help(
+                cx,
+                REPR_PACKED_REFERENCE_TAKEN,
+                expr.span,
+                "reading a field of a `#[repr(packed)]` struct through a pointer which may be misaligned",
+                None,
+                &format!(
+                    "use `read_unaligned`, or copy the field into a local first with `{{ {}.{name} }}`",
+                    snippet(cx, base.span, ".."),
+                ),
+            );
+        }
+    }
+}
+
+/// Writes through a dereferenced pointer are left out.
+fn is_assigned_to(cx: &LateContext<'_>, expr: &Expr<'_>) -> bool {
+    get_parent_expr(cx, expr).map_or(false, |parent| {
+        matches!(
+            parent.kind,
+            ExprKind::Assign(lhs, ..) | ExprKind::AssignOp(_, lhs, _) if lhs.hir_id == expr.hir_id
+        )
+    })
+}
@@ -1,4 +1,4 @@
-use std::{cmp, iter};
+use std::{fmt, iter};
 
 use clippy_utils::diagnostics::span_lint_and_sugg;
 use clippy_utils::source::snippet;
@@ -34,10 +34,9 @@
     /// registers.
     ///
     /// ### Known problems
-    /// This lint is target register size dependent, it is
-    /// limited to 32-bit to try and reduce portability problems between 32 and
-    /// 64-bit, but if you are compiling for 8 or 16-bit targets then the limit
-    /// will be different.
+    /// This lint is target register size dependent, by default the limit is
+    /// twice the size of a pointer on the target, so the same code can be linted
+    /// differently when compiling for targets with a different pointer width.
     ///
     /// The configuration option `trivial_copy_size_limit` can be set to override
     /// this limit for a project.
@@ -85,6 +84,11 @@
     /// shallow copy, taking up more space in the stack and requiring a call to
     /// `memcpy`, which can be expensive.
     ///
+    /// ### Known problems
+    /// By default the limit is 32 times the size of a pointer on the target, i.e.
+    /// 256 bytes on 64-bit targets. The configuration option `pass_by_value_size_limit`
+    /// can be set to override this limit for a project.
+    ///
     /// ### Example
     /// ```rust
     /// #[derive(Clone, Copy)]
@@ -107,32 +111,58 @@
 
 #[derive(Copy, Clone)]
 pub struct PassByRefOrValue {
-    ref_min_size: u64,
-    value_max_size: u64,
+    ref_min_size: SizeLimit,
+    value_max_size: SizeLimit,
     avoid_breaking_exported_api: bool,
 }
 
+/// A size limit in bytes, either set in the configuration or derived from the target.
+#[derive(Copy, Clone)]
+struct SizeLimit {
+    bytes: u64,
+    from_target: bool,
+}
+
+impl SizeLimit {
+    fn new(configured: Option<u64>, target: &Target, pointers: u64) -> Self {
+        match configured {
+            Some(bytes) => Self {
+                bytes,
+                from_target: false,
+            },
+            None => {
+                #[expect(clippy::integer_division)]
+                let pointer_bytes = u64::from(target.pointer_width) / 8;
+                Self {
+                    bytes: pointer_bytes * pointers,
+                    from_target: true,
+                }
+            },
+        }
+    }
+}
+
+impl fmt::Display for SizeLimit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "limit: {} byte", self.bytes)?;
+        if self.from_target {
+            f.write_str(", based on the target's pointer width")?;
+        }
+        Ok(())
+    }
+}
+
 impl<'tcx> PassByRefOrValue {
     pub fn new(
         ref_min_size: Option<u64>,
-        value_max_size: u64,
+        value_max_size: Option<u64>,
         avoid_breaking_exported_api: bool,
         target: &Target,
     ) -> Self {
-        let ref_min_size = ref_min_size.unwrap_or_else(|| {
-            let bit_width = u64::from(target.pointer_width);
-            // Cap the calculated bit width at 32-bits to reduce
-            // portability problems between 32 and 64-bit targets
-            let bit_width = cmp::min(bit_width, 32);
-            #[expect(clippy::integer_division)]
-            let byte_width = bit_width / 8;
-            // Use a limit of 2 times the register byte width
-            byte_width * 2
-        });
-
         Self {
-            ref_min_size,
-            value_max_size,
+            // Types fitting into two registers are passed in registers by many calling conventions.
+            ref_min_size: SizeLimit::new(ref_min_size, target, 2),
+            value_max_size: SizeLimit::new(value_max_size, target, 32),
             avoid_breaking_exported_api,
         }
     }
@@ -182,7 +212,7 @@ fn check_poly_fn(&mut self, cx: &LateContext<'tcx>, def_id: LocalDefId, decl: &F
                     let ty = cx.tcx.erase_late_bound_regions(fn_sig.rebind(ty));
                     if is_copy(cx, ty)
                         && let Some(size) = cx.layout_of(ty).ok().map(|l| l.size.bytes())
-                        && size <= self.ref_min_size
+                        && size <= self.ref_min_size.bytes
                         && let hir::TyKind::Ref(_, MutTy { ty: decl_ty, .. }) = input.kind
                     {
                         if let Some(typeck) = cx.maybe_typeck_results() {
@@ -208,7 +238,7 @@ fn check_poly_fn(&mut self, cx: &LateContext<'tcx>, def_id: LocalDefId, decl: &F
                             cx,
                             TRIVIALLY_COPY_PASS_BY_REF,
                             input.span,
-                            &format!("this argument ({size} byte) is passed by reference, but would be more efficient if passed by value (limit: {} byte)", self.ref_min_size),
+                            &format!("this argument ({size} byte) is passed by reference, but would be more efficient if passed by value ({})", self.ref_min_size),
                             "consider passing by value instead",
                             value_type,
                             Applicability::Unspecified,
@@ -230,13 +260,13 @@ fn check_poly_fn(&mut self, cx: &LateContext<'tcx>, def_id: LocalDefId, decl: &F
                         if is_copy(cx, ty);
                         if !is_self_ty(input);
                         if let Some(size) = cx.layout_of(ty).ok().map(|l| l.size.bytes());
-                        if size > self.value_max_size;
+                        if size > self.value_max_size.bytes;
                         then {
                             span_lint_and_sugg(
                                 cx,
                                 LARGE_TYPES_PASSED_BY_VALUE,
                                 input.span,
-                                &format!("this argument ({size} byte) is passed by value, but might be more efficient if passed by reference (limit: {} byte)", self.value_max_size),
+                                &format!("this argument ({size} byte) is passed by value, but might be more efficient if passed by reference ({})", self.value_max_size),
                                 "consider passing by reference instead",
                                 format!("&{}", snippet(cx, input.span, "_")),
                                 Applicability::MaybeIncorrect,
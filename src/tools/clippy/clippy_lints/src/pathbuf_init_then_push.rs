@@ -0,0 +1,206 @@
+use clippy_utils::diagnostics::span_lint_and_sugg;
+use clippy_utils::source::{snippet, snippet_with_context};
+use clippy_utils::ty::is_type_diagnostic_item;
+use clippy_utils::visitors::for_each_local_use_after_expr;
+use clippy_utils::{get_parent_expr, path_to_local_id};
+use core::ops::ControlFlow;
+use rustc_errors::Applicability;
+use rustc_hir::{
+    BindingAnnotation, Block, BorrowKind, Expr, ExprKind, HirId, Local, Mutability, PatKind, QPath, Stmt, StmtKind,
+};
+use rustc_lint::{LateContext, LateLintPass, LintContext};
+use rustc_middle::lint::in_external_macro;
+use rustc_session::{declare_tool_lint, impl_lint_pass};
+use rustc_span::{sym, Span, Symbol};
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for calls to `push` immediately after creating a new `PathBuf`.
+    ///
+    /// ### Why is this bad?
+    /// Building the path with `join` states the intent in a single expression.
+    ///
+    /// ### Known problems
+    /// Each call to `join` allocates a new `PathBuf`, so this is a matter of style rather than
+    /// performance.
+    ///
+    /// ### Example
+    /// ```rust
+    /// # use std::path::PathBuf;
+    /// let mut path = PathBuf::new();
+    /// path.push("root");
+    /// path.push("subdir");
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # use std::path::PathBuf;
+    /// let path = PathBuf::from("root").join("subdir");
+    /// ```
+    #[clippy::version = "1.73.0"]
+    pub PATHBUF_INIT_THEN_PUSH,
+    restriction,
+    "`push` immediately after `PathBuf` creation"
+}
+
+impl_lint_pass!(PathbufInitThenPush<'_> => [PATHBUF_INIT_THEN_PUSH]);
+
+#[derive(Default)]
+pub struct PathbufInitThenPush<'tcx> {
+    searcher: Option<PathbufPushSearcher<'tcx>>,
+}
+
+struct PathbufPushSearcher<'tcx> {
+    local_id: HirId,
+    name: Symbol,
+    let_ty_span: Option<Span>,
+    /// The path to `PathBuf` as written in its initialization.
+    pathbuf_span: Span,
+    /// The argument of `PathBuf::from`, if the `PathBuf` wasn't created with `PathBuf::new`.
+    init_arg: Option<&'tcx Expr<'tcx>>,
+    pushes: Vec<&'tcx Expr<'tcx>>,
+    err_span: Span,
+    last_push_expr: HirId,
+}
+
+impl<'tcx> PathbufPushSearcher<'tcx> {
+    fn display_err(&self, cx: &LateContext<'tcx>) {
+        let (base, joined) = match (self.init_arg, self.pushes.as_slice()) {
+            (Some(base), joined @ [_, ..]) => (base, joined),
+            (None, [base, joined @ ..]) => (*base, joined),
+            _ => return,
+        };
+
+        let ctxt = self.err_span.ctxt();
+        let mut app = if is_simple(base)
+            && joined.iter().all(|arg| is_simple(arg))
+            && (self.init_arg.is_some() || is_from_path_like(cx, base))
+        {
+            Applicability::MachineApplicable
+        } else {
+            Applicability::MaybeIncorrect
+        };
+        let mut s = String::from("let ");
+        if self.needs_mut(cx) {
+            s.push_str("mut ");
+        }
+        s.push_str(self.name.as_str());
+        if let Some(span) = self.let_ty_span {
+            s.push_str(": ");
+            s.push_str(&snippet(cx, span, "_"));
+        }
+        s.push_str(" = ");
+        s.push_str(&snippet_with_context(cx, self.pathbuf_span, ctxt, "PathBuf", &mut app).0);
+        s.push_str("::from(");
+        s.push_str(&snippet_with_context(cx, base.span, ctxt, "..", &mut app).0);
+        s.push(')');
+        for arg in joined {
+            s.push_str(".join(");
+            s.push_str(&snippet_with_context(cx, arg.span, ctxt, "..", &mut app).0);
+            s.push(')');
+        }
+        s.push(';');
+
+        span_lint_and_sugg(
+            cx,
+            PATHBUF_INIT_THEN_PUSH,
+            self.err_span,
+            "calls to `push` immediately after creation",
+            "consider using the `.join()` method",
+            s,
+            app,
+        );
+    }
+
+    /// Checks whether the `PathBuf` is still used mutably after the last `push`.
+    fn needs_mut(&self, cx: &LateContext<'tcx>) -> bool {
+        for_each_local_use_after_expr(cx, self.local_id, self.last_push_expr, |e| {
+            let is_mut_use = cx.typeck_results().expr_ty_adjusted(e).ref_mutability() == Some(Mutability::Mut)
+                || get_parent_expr(cx, e).map_or(false, |parent| match parent.kind {
+                    ExprKind::AddrOf(BorrowKind::Ref, Mutability::Mut, _) => true,
+                    ExprKind::Assign(lhs, ..) | ExprKind::AssignOp(_, lhs, _) => lhs.hir_id == e.hir_id,
+                    _ => false,
+                });
+            if is_mut_use {
+                ControlFlow::Break(())
+            } else {
+                ControlFlow::Continue(())
+            }
+        })
+        .is_break()
+    }
+}
+
+/// Checks for literals and plain paths, optionally borrowed, which are passed through unchanged.
+fn is_simple(arg: &Expr<'_>) -> bool {
+    match arg.kind {
+        ExprKind::Lit(_) | ExprKind::Path(QPath::Resolved(None, _)) => true,
+        ExprKind::AddrOf(BorrowKind::Ref, _, inner) => is_simple(inner),
+        _ => false,
+    }
+}
+
+/// Checks whether `PathBuf::from` accepts `arg` just like `PathBuf::push` does. This isn't the case
+/// for generic `impl AsRef<Path>` arguments.
+fn is_from_path_like(cx: &LateContext<'_>, arg: &Expr<'_>) -> bool {
+    let ty = cx.typeck_results().expr_ty(arg).peel_refs();
+    ty.is_str()
+        || [sym::String, sym::Path, sym::PathBuf, sym::OsStr, sym::OsString]
+            .into_iter()
+            .any(|item| is_type_diagnostic_item(cx, ty, item))
+}
+
+impl<'tcx> LateLintPass<'tcx> for PathbufInitThenPush<'tcx> {
+    fn check_block(&mut self, _: &LateContext<'tcx>, _: &'tcx Block<'tcx>) {
+        self.searcher = None;
+    }
+
+    fn check_local(&mut self, cx: &LateContext<'tcx>, local: &'tcx Local<'tcx>) {
+        if let Some(init_expr) = local.init
+            && let PatKind::Binding(BindingAnnotation::MUT, id, name, None) = local.pat.kind
+            && !in_external_macro(cx.sess(), local.span)
+            && let ExprKind::Call(func, args) = init_expr.kind
+            && let ExprKind::Path(QPath::TypeRelative(ty, segment)) = func.kind
+            && is_type_diagnostic_item(cx, cx.typeck_results().expr_ty(init_expr), sym::PathBuf)
+            && let Some(init_arg) = match (segment.ident.name, args) {
+                (sym::new, []) => Some(None),
+                (sym::from, [arg]) => Some(Some(arg)),
+                _ => None,
+            }
+        {
+            self.searcher = Some(PathbufPushSearcher {
+                local_id: id,
+                name: name.name,
+                let_ty_span: local.ty.map(|ty| ty.span),
+                pathbuf_span: ty.span,
+                init_arg,
+                pushes: Vec::new(),
+                err_span: local.span,
+                last_push_expr: init_expr.hir_id,
+            });
+        }
+    }
+
+    fn check_stmt(&mut self, cx: &LateContext<'tcx>, stmt: &'tcx Stmt<'tcx>) {
+        if let Some(mut searcher) = self.searcher.take() {
+            if let StmtKind::Expr(expr) | StmtKind::Semi(expr) = stmt.kind
+                && let ExprKind::MethodCall(name, self_arg, [arg], _) = expr.kind
+                && path_to_local_id(self_arg, searcher.local_id)
+                && name.ident.as_str() == "push"
+                && stmt.span.ctxt() == searcher.err_span.ctxt()
+            {
+                searcher.pushes.push(arg);
+                searcher.err_span = searcher.err_span.to(stmt.span);
+                searcher.last_push_expr = expr.hir_id;
+                self.searcher = Some(searcher);
+            } else {
+                searcher.display_err(cx);
+            }
+        }
+    }
+
+    fn check_block_post(&mut self, cx: &LateContext<'tcx>, _: &'tcx Block<'tcx>) {
+        if let Some(searcher) = self.searcher.take() {
+            searcher.display_err(cx);
+        }
+    }
+}
@@ -1,10 +1,13 @@
 //! Checks for usage of  `&Vec[_]` and `&String`.
 
-use clippy_utils::diagnostics::{span_lint, span_lint_and_sugg, span_lint_and_then, span_lint_hir_and_then};
+use clippy_utils::diagnostics::{span_lint_and_sugg, span_lint_and_then, span_lint_hir_and_then};
 use clippy_utils::source::snippet_opt;
-use clippy_utils::ty::expr_sig;
+use clippy_utils::sugg::Sugg;
+use clippy_utils::ty::{expr_sig, implements_trait};
 use clippy_utils::visitors::contains_unsafe_block;
-use clippy_utils::{get_expr_use_or_unification_node, is_lint_allowed, path_def_id, path_to_local, paths};
+use clippy_utils::{
+    get_expr_use_or_unification_node, is_integer_literal, is_lint_allowed, path_def_id, path_to_local, paths,
+};
 use hir::LifetimeName;
 use if_chain::if_chain;
 use rustc_errors::{Applicability, MultiSpan};
@@ -26,7 +29,6 @@
 use rustc_span::source_map::Span;
 use rustc_span::sym;
 use rustc_span::symbol::Symbol;
-use rustc_trait_selection::infer::InferCtxtExt as _;
 use rustc_trait_selection::traits::query::evaluate_obligation::InferCtxtExt as _;
 use std::{fmt, iter};
 
@@ -68,7 +70,8 @@
 
 declare_clippy_lint! {
     /// ### What it does
-    /// This lint checks for equality comparisons with `ptr::null`
+    /// This lint checks for equality comparisons with `ptr::null`, and of a pointer cast to
+    /// `usize` with `0`
     ///
     /// ### Why is this bad?
     /// It's easier and more readable to use the inherent
@@ -249,12 +252,20 @@ fn check_body(&mut self, cx: &LateContext<'tcx>, body: &'tcx Body<'_>) {
 
     fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
         if let ExprKind::Binary(ref op, l, r) = expr.kind {
-            if (op.node == BinOpKind::Eq || op.node == BinOpKind::Ne) && (is_null_path(cx, l) || is_null_path(cx, r)) {
-                span_lint(
+            if (op.node == BinOpKind::Eq || op.node == BinOpKind::Ne)
+                && let Some(ptr) = null_checked_ptr(cx, l, r).or_else(|| null_checked_ptr(cx, r, l))
+            {
+                let mut app = Applicability::MachineApplicable;
+                let ptr = Sugg::hir_with_context(cx, ptr, expr.span.ctxt(), "_", &mut app).maybe_par();
+                let invert = if op.node == BinOpKind::Eq { "" } else { "!" };
+                span_lint_and_sugg(
                     cx,
                     CMP_NULL,
                     expr.span,
                     "comparing with null is better expressed by the `.is_null()` method",
+                    "try",
+                    format!("{invert}{ptr}.is_null()"),
+                    app,
                 );
             }
         } else {
@@ -728,9 +739,7 @@ fn matches_preds<'tcx>(
 ) -> bool {
     let infcx = cx.tcx.infer_ctxt().build();
     preds.iter().all(|&p| match cx.tcx.erase_late_bound_regions(p) {
-        ExistentialPredicate::Trait(p) => infcx
-            .type_implements_trait(p.def_id, [ty.into()].into_iter().chain(p.args.iter()), cx.param_env)
-            .must_apply_modulo_regions(),
+        ExistentialPredicate::Trait(p) => implements_trait(cx, ty, p.def_id, p.args),
         ExistentialPredicate::Projection(p) => infcx.predicate_must_hold_modulo_regions(&Obligation::new(
             cx.tcx,
             ObligationCause::dummy(),
@@ -740,9 +749,7 @@ fn matches_preds<'tcx>(
                     p.with_self_ty(cx.tcx, ty),
                 )))),
         )),
-        ExistentialPredicate::AutoTrait(p) => infcx
-            .type_implements_trait(p, [ty], cx.param_env)
-            .must_apply_modulo_regions(),
+        ExistentialPredicate::AutoTrait(p) => implements_trait(cx, ty, p, &[]),
     })
 }
 
@@ -754,6 +761,27 @@ fn get_ref_lm<'tcx>(ty: &'tcx hir::Ty<'tcx>) -> Option<(&'tcx Lifetime, Mutabili
     }
 }
 
+/// Matches `ptr` compared to `null`, where `null` is `ptr::null()`, `ptr::null_mut()`, or `0` if
+/// `ptr` is a pointer cast to `usize`. Returns the pointer, without a cast from another pointer.
+fn null_checked_ptr<'tcx>(cx: &LateContext<'_>, ptr: &'tcx Expr<'tcx>, null: &Expr<'_>) -> Option<&'tcx Expr<'tcx>> {
+    let ptr = if is_null_path(cx, null) {
+        ptr
+    } else if is_integer_literal(null, 0)
+        && let ExprKind::Cast(inner, _) = ptr.kind
+        && cx.typeck_results().expr_ty(ptr).is_usize()
+    {
+        inner
+    } else {
+        return None;
+    };
+    // `ptr as *const ()` checks the same address as `ptr`
+    let ptr = match ptr.kind {
+        ExprKind::Cast(inner, _) if cx.typeck_results().expr_ty(inner).is_unsafe_ptr() => inner,
+        _ => ptr,
+    };
+    cx.typeck_results().expr_ty(ptr).is_unsafe_ptr().then_some(ptr)
+}
+
 fn is_null_path(cx: &LateContext<'_>, expr: &Expr<'_>) -> bool {
     if let ExprKind::Call(pathexp, []) = expr.kind {
         path_def_id(cx, pathexp).map_or(false, |id| {
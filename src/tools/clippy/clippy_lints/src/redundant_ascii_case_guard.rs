@@ -0,0 +1,72 @@
+use clippy_utils::diagnostics::span_lint_and_sugg;
+use clippy_utils::sugg::Sugg;
+use clippy_utils::{higher, peel_blocks, SpanlessEq};
+use rustc_errors::Applicability;
+use rustc_hir::{Expr, ExprKind};
+use rustc_lint::{LateContext, LateLintPass, LintContext};
+use rustc_middle::lint::in_external_macro;
+use rustc_middle::ty::{self, UintTy};
+use rustc_session::{declare_lint_pass, declare_tool_lint};
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for an ASCII case conversion of a `char` or `u8` which is only done if the
+    /// value has the opposite case.
+    ///
+    /// ### Why is this bad?
+    /// `to_ascii_lowercase` and `to_ascii_uppercase` already return any other value
+    /// unchanged, so the check is redundant.
+    ///
+    /// ### Example
+    /// ```rust
+    /// # let c = 'A';
+    /// let lower = if c.is_ascii_uppercase() { c.to_ascii_lowercase() } else { c };
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # let c = 'A';
+    /// let lower = c.to_ascii_lowercase();
+    /// ```
+    #[clippy::version = "1.73.0"]
+    pub MANUAL_ASCII_CASE_CONVERSION,
+    complexity,
+    "converting the ASCII case of a value only if it has the opposite case"
+}
+declare_lint_pass!(RedundantAsciiCaseGuard => [MANUAL_ASCII_CASE_CONVERSION]);
+
+impl<'tcx> LateLintPass<'tcx> for RedundantAsciiCaseGuard {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
+        if let Some(higher::If {
+            cond,
+            then,
+            r#else: Some(r#else),
+        }) = higher::If::hir(expr)
+            && !in_external_macro(cx.sess(), expr.span)
+            && !expr.span.from_expansion()
+            && let ExprKind::MethodCall(check, recv, [], _) = cond.kind
+            && let ExprKind::MethodCall(convert, converted, [], _) = peel_blocks(then).kind
+            && let Some(expected) = match check.ident.as_str() {
+                "is_ascii_uppercase" => Some("to_ascii_lowercase"),
+                "is_ascii_lowercase" => Some("to_ascii_uppercase"),
+                _ => None,
+            }
+            && convert.ident.as_str() == expected
+            && matches!(cx.typeck_results().expr_ty(recv).kind(), ty::Char | ty::Uint(UintTy::U8))
+            // The value is evaluated once instead of twice afterwards.
+            && SpanlessEq::new(cx).deny_side_effects().eq_expr(recv, converted)
+            && SpanlessEq::new(cx).deny_side_effects().eq_expr(recv, peel_blocks(r#else))
+        {
+            let mut app = Applicability::MachineApplicable;
+            let recv = Sugg::hir_with_applicability(cx, recv, "..", &mut app).maybe_par();
+            span_lint_and_sugg(
+                cx,
+                MANUAL_ASCII_CASE_CONVERSION,
+                expr.span,
+                "redundant case check before an ASCII case conversion",
+                "convert the case unconditionally",
+                format!("{recv}.{expected}()"),
+                app,
+            );
+        }
+    }
+}
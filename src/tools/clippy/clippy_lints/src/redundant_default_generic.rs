@@ -0,0 +1,108 @@
+use clippy_utils::diagnostics::span_lint_and_sugg;
+use rustc_errors::Applicability;
+use rustc_hir::def::{DefKind, Res};
+use rustc_hir::{self as hir, GenericArg, Node, QPath, TyKind};
+use rustc_hir_analysis::hir_ty_to_ty;
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::lint::in_external_macro;
+use rustc_middle::ty::{GenericParamDef, GenericParamDefKind};
+use rustc_session::{declare_lint_pass, declare_tool_lint};
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for types which explicitly name the default of their trailing generic parameters,
+    /// like `HashMap<K, V, RandomState>`.
+    ///
+    /// ### Why is this bad?
+    /// The default is used when the argument is left out, naming it is only noise.
+    ///
+    /// ### Example
+    /// ```rust
+    /// # use std::collections::HashMap;
+    /// # use std::collections::hash_map::RandomState;
+    /// let map: HashMap<u32, u32, RandomState> = HashMap::default();
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # use std::collections::HashMap;
+    /// let map: HashMap<u32, u32> = HashMap::default();
+    /// ```
+    #[clippy::version = "1.73.0"]
+    pub REDUNDANT_EXPLICIT_DEFAULT_IN_GENERIC_BOUND,
+    pedantic,
+    "a generic argument which is the default of its parameter"
+}
+declare_lint_pass!(RedundantDefaultGeneric => [REDUNDANT_EXPLICIT_DEFAULT_IN_GENERIC_BOUND]);
+
+impl<'tcx> LateLintPass<'tcx> for RedundantDefaultGeneric {
+    fn check_ty(&mut self, cx: &LateContext<'tcx>, ty: &'tcx hir::Ty<'tcx>) {
+        if let TyKind::Path(QPath::Resolved(None, path)) = ty.kind
+            && let Res::Def(DefKind::Struct | DefKind::Enum | DefKind::Union, adt_id) = path.res
+            && !in_external_macro(cx.sess(), ty.span)
+            && let [.., segment] = path.segments
+            && let Some(args) = segment.args
+            && args.bindings.is_empty()
+            && !args.span_ext.from_expansion()
+        {
+            // Lifetimes may be elided, so type and const arguments are matched to the parameters
+            // by their position among each other
+            let params = cx
+                .tcx
+                .generics_of(adt_id)
+                .params
+                .iter()
+                .filter(|param| !matches!(param.kind, GenericParamDefKind::Lifetime));
+            let type_args = args.args.iter().filter(|arg| !matches!(arg, GenericArg::Lifetime(_)));
+            let is_default: Vec<bool> = params
+                .zip(type_args)
+                .map(|(param, arg)| is_default_arg(cx, param, arg))
+                .collect();
+            let redundant = is_default.iter().rev().take_while(|is_default| **is_default).count();
+            if redundant == 0 {
+                return;
+            }
+
+            let kept = args.args.len() - redundant;
+            let span = if kept == 0 {
+                // Without any arguments the parameters of a type in an expression or a pattern,
+                // like `Foo::<Bar>::new()`, are inferred instead of defaulted
+                if matches!(cx.tcx.hir().find_parent(ty.hir_id), Some(Node::Expr(_) | Node::Pat(_))) {
+                    return;
+                }
+                segment.ident.span.shrink_to_hi().to(args.span_ext)
+            } else {
+                args.args[kept - 1]
+                    .span()
+                    .shrink_to_hi()
+                    .to(args.args[args.args.len() - 1].span())
+            };
+            span_lint_and_sugg(
+                cx,
+                REDUNDANT_EXPLICIT_DEFAULT_IN_GENERIC_BOUND,
+                span,
+                if redundant == 1 {
+                    "this generic argument is the default of its parameter"
+                } else {
+                    "these generic arguments are the defaults of their parameters"
+                },
+                if redundant == 1 { "remove it" } else { "remove them" },
+                String::new(),
+                Applicability::MachineApplicable,
+            );
+        }
+    }
+}
+
+fn is_default_arg(cx: &LateContext<'_>, param: &GenericParamDef, arg: &GenericArg<'_>) -> bool {
+    if let GenericParamDefKind::Type { has_default: true, .. } = param.kind
+        && let GenericArg::Type(arg) = arg
+        // Without generic arguments of its own, the type can be lowered outside of typeck, e.g.
+        // there is no `_` in it
+        && let TyKind::Path(QPath::Resolved(None, arg_path)) = arg.kind
+        && arg_path.segments.iter().all(|segment| segment.args.is_none())
+    {
+        hir_ty_to_ty(cx.tcx, arg) == cx.tcx.type_of(param.def_id).instantiate_identity()
+    } else {
+        false
+    }
+}
@@ -1,9 +1,13 @@
-use clippy_utils::diagnostics::span_lint_and_help;
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::source::{indent_of, reindent_multiline, snippet_indent, snippet_with_applicability};
 use rustc_ast::ast::{Block, Expr, ExprKind, Stmt, StmtKind};
 use rustc_ast::visit::{walk_expr, Visitor};
+use rustc_errors::Applicability;
 use rustc_lint::{EarlyContext, EarlyLintPass, LintContext};
 use rustc_middle::lint::in_external_macro;
 use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_span::hygiene::{ExpnKind, MacroKind};
+use rustc_span::sym;
 
 declare_clippy_lint! {
     /// ### What it does
@@ -15,6 +19,10 @@
     /// ### Known problems
     /// Some may prefer to keep the `else` block for clarity.
     ///
+    /// The lint runs before type checking, so it only knows about `return`, `break`, `continue`
+    /// and the `panic!`, `unreachable!`, `todo!` and `unimplemented!` macros. Calls to other
+    /// functions returning `!`, like `std::process::exit`, aren't recognized.
+    ///
     /// ### Example
     /// ```rust
     /// fn my_func(count: u32) {
@@ -45,47 +53,97 @@
 declare_lint_pass!(RedundantElse => [REDUNDANT_ELSE]);
 
 impl EarlyLintPass for RedundantElse {
-    fn check_stmt(&mut self, cx: &EarlyContext<'_>, stmt: &Stmt) {
-        if in_external_macro(cx.sess(), stmt.span) {
+    fn check_block(&mut self, cx: &EarlyContext<'_>, block: &Block) {
+        for (i, stmt) in block.stmts.iter().enumerate() {
+            check_stmt(cx, stmt, i + 1 == block.stmts.len());
+        }
+    }
+}
+
+fn check_stmt(cx: &EarlyContext<'_>, stmt: &Stmt, is_tail: bool) {
+    if in_external_macro(cx.sess(), stmt.span) {
+        return;
+    }
+    // Only look at expressions that are a whole statement
+    let expr: &Expr = match &stmt.kind {
+        StmtKind::Expr(expr) | StmtKind::Semi(expr) => expr,
+        _ => return,
+    };
+    // if else
+    let (mut then, mut els): (&Block, &Expr) = match &expr.kind {
+        ExprKind::If(_, then, Some(els)) => (then, els),
+        _ => return,
+    };
+    loop {
+        if !BreakVisitor::default().check_block(then) {
+            // then block does not always break
             return;
         }
-        // Only look at expressions that are a whole statement
-        let expr: &Expr = match &stmt.kind {
-            StmtKind::Expr(expr) | StmtKind::Semi(expr) => expr,
-            _ => return,
-        };
-        // if else
-        let (mut then, mut els): (&Block, &Expr) = match &expr.kind {
-            ExprKind::If(_, then, Some(els)) => (then, els),
-            _ => return,
-        };
-        loop {
-            if !BreakVisitor::default().check_block(then) {
-                // then block does not always break
-                return;
-            }
-            match &els.kind {
-                // else if else
-                ExprKind::If(_, next_then, Some(next_els)) => {
-                    then = next_then;
-                    els = next_els;
-                    continue;
-                },
-                // else if without else
-                ExprKind::If(..) => return,
-                // done
-                _ => break,
-            }
+        match &els.kind {
+            // else if else
+            ExprKind::If(_, next_then, Some(next_els)) => {
+                then = next_then;
+                els = next_els;
+                continue;
+            },
+            // else if without else
+            ExprKind::If(..) => return,
+            // done
+            _ => break,
         }
-        span_lint_and_help(
-            cx,
-            REDUNDANT_ELSE,
-            els.span,
-            "redundant else block",
-            None,
+    }
+    let ExprKind::Block(els_block, _) = &els.kind else {
+        return;
+    };
+
+    // Bindings moved out of the `else` block could shadow ones used after the `if` and are dropped
+    // later.
+    let mut app = if els_block.stmts.iter().any(|stmt| matches!(stmt.kind, StmtKind::Local(_))) {
+        Applicability::MaybeIncorrect
+    } else {
+        Applicability::MachineApplicable
+    };
+    // A trailing expression of the `else` block needs a semicolon once it has been moved out, unless
+    // the `if` is followed by one or is the tail expression of its block.
+    let needs_semi = matches!(stmt.kind, StmtKind::Expr(_))
+        && !is_tail
+        && els_block
+            .stmts
+            .last()
+            .is_some_and(|stmt| matches!(stmt.kind, StmtKind::Expr(_)));
+    let block = snippet_with_applicability(cx, els.span, "{ .. }", &mut app);
+    let body = block
+        .strip_prefix('{')
+        .and_then(|block| block.strip_suffix('}'))
+        .unwrap_or("..")
+        .trim();
+    let sugg = if body.is_empty() {
+        String::new()
+    } else {
+        format!(
+            "\n{}{}{}",
+            snippet_indent(cx, expr.span).unwrap_or_default(),
+            reindent_multiline(body.into(), true, indent_of(cx, expr.span)),
+            if needs_semi { ";" } else { "" },
+        )
+    };
+
+    span_lint_and_then(cx, REDUNDANT_ELSE, els.span, "redundant else block", |diag| {
+        diag.span_suggestion(
+            then.span.shrink_to_hi().to(els.span),
             "remove the `else` block and move the contents out",
+            sugg,
+            app,
         );
-    }
+    });
+}
+
+/// Checks whether the expression is the expansion of one of the `std` macros which always panic.
+fn is_diverging_macro_call(expr: &Expr) -> bool {
+    expr.span.macro_backtrace().last().is_some_and(|data| {
+        matches!(data.kind, ExpnKind::Macro(MacroKind::Bang, name)
+            if [sym::panic, sym::unreachable, sym!(todo), sym!(unimplemented)].contains(&name))
+    })
 }
 
 /// Call `check` functions to check if an expression always breaks control flow
@@ -104,6 +162,7 @@ fn visit_block(&mut self, block: &'ast Block) {
 
     fn visit_expr(&mut self, expr: &'ast Expr) {
         self.is_break = match expr.kind {
+            _ if is_diverging_macro_call(expr) => true,
             ExprKind::Break(..) | ExprKind::Continue(..) | ExprKind::Ret(..) => true,
             ExprKind::Match(_, ref arms) => arms.iter().all(|arm| self.check_expr(&arm.body)),
             ExprKind::If(_, ref then, Some(ref els)) => self.check_block(then) && self.check_expr(els),
@@ -0,0 +1,100 @@
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::{is_trait_method, SpanlessEq};
+use rustc_errors::Applicability;
+use rustc_hir::def::Res;
+use rustc_hir::{self as hir, Expr, ExprKind, GenericArg, Local, QPath, TyKind};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_span::sym;
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for `let` statements with a type annotation which is initialized with a call to
+    /// `collect` or `parse` whose turbofish names the same type. For `parse`, the annotation is
+    /// the `Result` which contains that type.
+    ///
+    /// ### Why is this bad?
+    /// The type is written twice, either one of them is enough for the compiler to infer it.
+    ///
+    /// ### Example
+    /// ```rust
+    /// # let v = [1, 2, 3];
+    /// let x: Vec<u32> = v.iter().copied().collect::<Vec<u32>>();
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # let v = [1, 2, 3];
+    /// let x: Vec<u32> = v.iter().copied().collect();
+    /// ```
+    #[clippy::version = "1.73.0"]
+    pub REDUNDANT_TYPE_IN_TURBOFISH_AND_ANNOTATION,
+    complexity,
+    "a `let` with a type annotation which also names the type in a `collect` or `parse` turbofish"
+}
+declare_lint_pass!(RedundantTurbofish => [REDUNDANT_TYPE_IN_TURBOFISH_AND_ANNOTATION]);
+
+impl<'tcx> LateLintPass<'tcx> for RedundantTurbofish {
+    fn check_local(&mut self, cx: &LateContext<'tcx>, local: &'tcx Local<'tcx>) {
+        if let Some(annotation) = local.ty
+            && let Some(init) = local.init
+            && !local.span.from_expansion()
+            && init.span.ctxt() == local.span.ctxt()
+            && let ExprKind::MethodCall(path, ..) = init.kind
+            && let Some(args) = path.args
+            && let [GenericArg::Type(turbofish)] = args.args
+            && args.bindings.is_empty()
+            && let Some(annotated) = annotated_type(cx, init, annotation)
+            // Only exact matches, `collect::<Vec<_>>()` with `Vec<u32>` names the type only once
+            && SpanlessEq::new(cx).inter_expr().eq_ty(annotated, turbofish)
+        {
+            span_lint_and_then(
+                cx,
+                REDUNDANT_TYPE_IN_TURBOFISH_AND_ANNOTATION,
+                local.span,
+                "the type is named both in the annotation and in the turbofish",
+                |diag| {
+                    diag.span_suggestion(
+                        path.ident.span.shrink_to_hi().to(args.span_ext),
+                        "remove the turbofish",
+                        "",
+                        Applicability::MachineApplicable,
+                    );
+                },
+            );
+        }
+    }
+}
+
+/// Returns the part of the annotation which names the same type as the turbofish of a `collect`
+/// or `parse` call: the whole annotation for `collect`, and `T` of `Result<T, _>` for `parse`.
+fn annotated_type<'tcx>(
+    cx: &LateContext<'tcx>,
+    expr: &Expr<'_>,
+    annotation: &'tcx hir::Ty<'tcx>,
+) -> Option<&'tcx hir::Ty<'tcx>> {
+    let ExprKind::MethodCall(path, ..) = expr.kind else {
+        return None;
+    };
+    match path.ident.name.as_str() {
+        "collect" if is_trait_method(cx, expr, sym::Iterator) => Some(annotation),
+        "parse"
+            if cx
+                .typeck_results()
+                .type_dependent_def_id(expr.hir_id)
+                .and_then(|id| cx.tcx.impl_of_method(id))
+                .is_some_and(|impl_id| cx.tcx.type_of(impl_id).instantiate_identity().is_str()) =>
+        {
+            if let TyKind::Path(QPath::Resolved(None, result)) = annotation.kind
+                && let Res::Def(_, result_id) = result.res
+                && cx.tcx.is_diagnostic_item(sym::Result, result_id)
+                && let Some(args) = result.segments.last()?.args
+                && let [GenericArg::Type(ok_ty), ..] = args.args
+            {
+                Some(ok_ty)
+            } else {
+                None
+            }
+        },
+        _ => None,
+    }
+}
@@ -0,0 +1,110 @@
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::higher::VecArgs;
+use clippy_utils::macros::root_macro_call_first_node;
+use clippy_utils::source::snippet;
+use clippy_utils::ty::is_type_diagnostic_item;
+use clippy_utils::{is_path_diagnostic_item, is_trait_method, last_path_segment};
+use rustc_errors::Applicability;
+use rustc_hir::{Expr, ExprKind};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_span::{sym, Span};
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for `vec![Vec::with_capacity(n); len]` and `iter::repeat(Vec::with_capacity(n)).take(len)`,
+    /// as well as the same with `String::with_capacity`.
+    ///
+    /// ### Why is this bad?
+    /// These repeat the value by cloning it, and the clone of a `Vec` or `String` doesn't keep its
+    /// capacity. `vec![x; len]` moves `x` into the last element, so only that one has the
+    /// requested capacity, and none of the elements of `iter::repeat(x)` have it.
+    ///
+    /// ### Example
+    /// ```rust
+    /// let buffers: Vec<Vec<u8>> = vec![Vec::with_capacity(1024); 4];
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// let buffers: Vec<Vec<u8>> = (0..4).map(|_| Vec::with_capacity(1024)).collect::<Vec<_>>();
+    /// ```
+    #[clippy::version = "1.73.0"]
+    pub REPEAT_VEC_WITH_CAPACITY,
+    suspicious,
+    "repeating a `Vec` or `String` created with `with_capacity`, whose clones don't keep the capacity"
+}
+declare_lint_pass!(RepeatVecWithCapacity => [REPEAT_VEC_WITH_CAPACITY]);
+
+impl LateLintPass<'_> for RepeatVecWithCapacity {
+    fn check_expr(&mut self, cx: &LateContext<'_>, expr: &Expr<'_>) {
+        if let Some(macro_call) = root_macro_call_first_node(cx, expr)
+            && let Some(VecArgs::Repeat(elem, len)) = VecArgs::hir(cx, expr)
+            && let Some(ty_name) = with_capacity_type(cx, elem)
+        {
+            emit_lint(
+                cx,
+                macro_call.span,
+                &format!("repeating `{ty_name}::with_capacity` using `vec![x; n]`"),
+                "only the last element has the requested capacity",
+                format!(
+                    "(0..{}).map(|_| {}).collect::<Vec<_>>()",
+                    snippet(cx, len.span, ".."),
+                    snippet(cx, elem.span, ".."),
+                ),
+            );
+        } else if let ExprKind::MethodCall(take, repeat, [len], _) = expr.kind
+            && take.ident.name == sym!(take)
+            && !expr.span.from_expansion()
+            && is_trait_method(cx, expr, sym::Iterator)
+            && let ExprKind::Call(func, [elem]) = repeat.kind
+            && is_path_diagnostic_item(cx, func, sym::iter_repeat)
+            && let Some(ty_name) = with_capacity_type(cx, elem)
+        {
+            emit_lint(
+                cx,
+                expr.span,
+                &format!("repeating `{ty_name}::with_capacity` using `iter::repeat`"),
+                "none of the elements have the requested capacity",
+                format!(
+                    "std::iter::repeat_with(|| {}).take({})",
+                    snippet(cx, elem.span, ".."),
+                    snippet(cx, len.span, ".."),
+                ),
+            );
+        }
+    }
+}
+
+fn emit_lint(cx: &LateContext<'_>, span: Span, msg: &str, note: &str, sugg: String) {
+    span_lint_and_then(cx, REPEAT_VEC_WITH_CAPACITY, span, msg, |diag| {
+        diag.note(format!(
+            "the elements are cloned, which doesn't keep the capacity, so {note}"
+        ));
+        diag.span_suggestion(
+            span,
+            "if you intended to allocate the capacity for each element, create each one separately",
+            sugg,
+            Applicability::MaybeIncorrect,
+        );
+    });
+}
+
+/// Returns `"Vec"` or `"String"` if `expr` is a call to `Vec::with_capacity` or
+/// `String::with_capacity`.
+fn with_capacity_type(cx: &LateContext<'_>, expr: &Expr<'_>) -> Option<&'static str> {
+    if let ExprKind::Call(func, [_]) = expr.kind
+        && let ExprKind::Path(ref func_path) = func.kind
+        && last_path_segment(func_path).ident.name == sym!(with_capacity)
+    {
+        let ty = cx.typeck_results().expr_ty(expr);
+        if is_type_diagnostic_item(cx, ty, sym::Vec) {
+            Some("Vec")
+        } else if is_type_diagnostic_item(cx, ty, sym::String) {
+            Some("String")
+        } else {
+            None
+        }
+    } else {
+        None
+    }
+}
@@ -0,0 +1,88 @@
+use clippy_utils::diagnostics::span_lint_and_sugg;
+use clippy_utils::sugg::Sugg;
+use rustc_errors::Applicability;
+use rustc_hir::def_id::DefId;
+use rustc_hir::{Expr, ExprKind};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_session::{declare_lint_pass, declare_tool_lint};
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for integers converted to bytes and back, like `u32::from_le_bytes(x.to_be_bytes())`
+    /// or `u32::from_le_bytes(x.to_le_bytes())`.
+    ///
+    /// ### Why is this bad?
+    /// Converting to one byte order and back from the other one is `swap_bytes`, and converting
+    /// back from the same byte order gives the integer itself.
+    ///
+    /// ### Example
+    /// ```rust
+    /// # let x = 1_u32;
+    /// let swapped = u32::from_le_bytes(x.to_be_bytes());
+    /// let same = u32::from_be_bytes(x.to_be_bytes());
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # let x = 1_u32;
+    /// let swapped = x.swap_bytes();
+    /// let same = x;
+    /// ```
+    #[clippy::version = "1.73.0"]
+    pub MANUAL_BYTE_SWAP_VIA_FROM_TO_BYTES,
+    complexity,
+    "converting an integer to bytes and back instead of using `swap_bytes` or the integer itself"
+}
+declare_lint_pass!(RoundtripBytesSwap => [MANUAL_BYTE_SWAP_VIA_FROM_TO_BYTES]);
+
+impl<'tcx> LateLintPass<'tcx> for RoundtripBytesSwap {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) {
+        if let ExprKind::Call(func, [bytes]) = expr.kind
+            && !expr.span.from_expansion()
+            && let ExprKind::Path(ref qpath) = func.kind
+            && let Some(from_id) = cx.qpath_res(qpath, func.hir_id).opt_def_id()
+            && let Some(from) = byte_order(cx, from_id, "from_")
+            && let Some((value, to_id)) = to_bytes_call(cx, bytes)
+            && let Some(to) = byte_order(cx, to_id, "to_")
+            // e.g. `u32::from_le_bytes(x.to_be_bytes())` with `x: i32` also changes the type
+            && cx.typeck_results().expr_ty(expr) == cx.typeck_results().expr_ty(value)
+        {
+            let mut app = Applicability::MachineApplicable;
+            let value = Sugg::hir_with_context(cx, value, expr.span.ctxt(), "..", &mut app).maybe_par();
+            let (msg, sugg) = match (from, to) {
+                ("le", "be") | ("be", "le") => (
+                    "converting an integer to bytes and back in the opposite byte order",
+                    format!("{value}.swap_bytes()"),
+                ),
+                _ if from == to => ("converting an integer to bytes and back", value.to_string()),
+                // The native byte order depends on the target
+                _ => return,
+            };
+            span_lint_and_sugg(cx, MANUAL_BYTE_SWAP_VIA_FROM_TO_BYTES, expr.span, msg, "try", sugg, app);
+        }
+    }
+}
+
+/// Matches `x.to_*_bytes()` and `T::to_*_bytes(x)`, returning `x` and the called function.
+fn to_bytes_call<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) -> Option<(&'tcx Expr<'tcx>, DefId)> {
+    match expr.kind {
+        ExprKind::MethodCall(_, recv, [], _) => Some((recv, cx.typeck_results().type_dependent_def_id(expr.hir_id)?)),
+        ExprKind::Call(func, [arg]) if let ExprKind::Path(ref qpath) = func.kind => {
+            Some((arg, cx.qpath_res(qpath, func.hir_id).opt_def_id()?))
+        },
+        _ => None,
+    }
+}
+
+/// Returns the byte order of an inherent `{prefix}{le,be,ne}_bytes` function of an integer type.
+fn byte_order(cx: &LateContext<'_>, def_id: DefId, prefix: &str) -> Option<&'static str> {
+    let impl_id = cx.tcx.impl_of_method(def_id)?;
+    if !cx.tcx.type_of(impl_id).instantiate_identity().is_integral() || cx.tcx.impl_trait_ref(impl_id).is_some() {
+        return None;
+    }
+    match cx.tcx.item_name(def_id).as_str().strip_prefix(prefix)? {
+        "le_bytes" => Some("le"),
+        "be_bytes" => Some("be"),
+        "ne_bytes" => Some("ne"),
+        _ => None,
+    }
+}
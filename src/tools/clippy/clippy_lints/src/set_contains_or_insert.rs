@@ -0,0 +1,123 @@
+use clippy_utils::diagnostics::span_lint_and_sugg;
+use clippy_utils::source::snippet_with_context;
+use clippy_utils::ty::is_type_diagnostic_item;
+use clippy_utils::{higher, peel_hir_expr_refs, SpanlessEq};
+use rustc_errors::Applicability;
+use rustc_hir::{Expr, ExprKind, Node, Stmt, StmtKind, UnOp};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_span::sym;
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for `if !set.contains(&x) { set.insert(x); .. }` on a `HashSet` or `BTreeSet`.
+    ///
+    /// ### Why is this bad?
+    /// `insert` returns whether the value was newly inserted, so the set is searched twice where
+    /// once is enough.
+    ///
+    /// The same pattern on maps is linted by [`map_entry`](#map_entry).
+    ///
+    /// ### Example
+    /// ```rust
+    /// # use std::collections::HashSet;
+    /// # let mut set = HashSet::new();
+    /// # let value = 1;
+    /// if !set.contains(&value) {
+    ///     set.insert(value);
+    ///     println!("inserted {value}");
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # use std::collections::HashSet;
+    /// # let mut set = HashSet::new();
+    /// # let value = 1;
+    /// if set.insert(value) {
+    ///     println!("inserted {value}");
+    /// }
+    /// ```
+    #[clippy::version = "1.73.0"]
+    pub SET_CONTAINS_OR_INSERT,
+    nursery,
+    "calling `contains` on a set before inserting the same value"
+}
+declare_lint_pass!(SetContainsOrInsert => [SET_CONTAINS_OR_INSERT]);
+
+impl<'tcx> LateLintPass<'tcx> for SetContainsOrInsert {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) {
+        if let Some(higher::If {
+            cond,
+            then,
+            r#else: None,
+        }) = higher::If::hir(expr)
+            && !expr.span.from_expansion()
+            // Replacing an `else if` with a statement isn't possible.
+            && let Some(parent @ (Node::Stmt(_) | Node::Block(_))) = cx.tcx.hir().find_parent(expr.hir_id)
+            && let ExprKind::Unary(UnOp::Not, contains_call) = cond.kind
+            && let ExprKind::MethodCall(contains, set, [contained], _) = contains_call.kind
+            && contains.ident.name == sym!(contains)
+            && let set_ty = cx.typeck_results().expr_ty(set).peel_refs()
+            && (is_type_diagnostic_item(cx, set_ty, sym::HashSet) || is_type_diagnostic_item(cx, set_ty, sym::BTreeSet))
+            && let ExprKind::Block(block, None) = then.kind
+            // The `insert` has to come first, as it will be moved into the condition.
+            && let [first, ..] = block.stmts
+            && let StmtKind::Semi(insert_call) = first.kind
+            && let ExprKind::MethodCall(insert, insert_set, [inserted], _) = insert_call.kind
+            && insert.ident.name == sym!(insert)
+            && SpanlessEq::new(cx).deny_side_effects().eq_expr(set, insert_set)
+            && let Some(value_is_moved) = is_inserted_value(cx, contained, inserted)
+        {
+            // The inserted value is now evaluated even if the set already contains it, which is only
+            // known to be free for a local moved into the set.
+            let mut app = if value_is_moved {
+                Applicability::MachineApplicable
+            } else {
+                Applicability::MaybeIncorrect
+            };
+            let ctxt = expr.span.ctxt();
+            let set = snippet_with_context(cx, set.span, ctxt, "..", &mut app).0;
+            let inserted = snippet_with_context(cx, inserted.span, ctxt, "..", &mut app).0;
+            let sugg = if block.stmts.len() == 1 && block.expr.is_none() {
+                // A statement ending in a semicolon already has one after the `if`.
+                let semi = if matches!(parent, Node::Stmt(Stmt { kind: StmtKind::Semi(_), .. })) {
+                    ""
+                } else {
+                    ";"
+                };
+                format!("{set}.insert({inserted}){semi}")
+            } else {
+                let rest = snippet_with_context(cx, block.span.with_lo(first.span.hi()), ctxt, "..", &mut app).0;
+                format!("if {set}.insert({inserted}) {{{rest}")
+            };
+            span_lint_and_sugg(
+                cx,
+                SET_CONTAINS_OR_INSERT,
+                expr.span,
+                "checking whether a set contains a value before inserting it",
+                "`insert` returns whether the value was newly inserted",
+                sugg,
+                app,
+            );
+        }
+    }
+}
+
+/// Checks whether `inserted` is the value passed to `contains`, as `x` for `contains(&x)` or
+/// `contains(x)`, or as an owned copy like `x.clone()` or `x.to_string()`. Returns whether the
+/// value is a local which is moved into the set.
+fn is_inserted_value(cx: &LateContext<'_>, contained: &Expr<'_>, inserted: &Expr<'_>) -> Option<bool> {
+    let (contained, _) = peel_hir_expr_refs(contained);
+    if SpanlessEq::new(cx).deny_side_effects().eq_expr(contained, inserted) {
+        Some(matches!(inserted.kind, ExprKind::Path(_)))
+    } else if let ExprKind::MethodCall(method, recv, [], _) = inserted.kind
+        && [sym::clone, sym!(to_owned), sym::to_string].contains(&method.ident.name)
+        && SpanlessEq::new(cx)
+            .deny_side_effects()
+            .eq_expr(contained, peel_hir_expr_refs(recv).0)
+    {
+        Some(false)
+    } else {
+        None
+    }
+}
@@ -1,7 +1,7 @@
 use clippy_utils::diagnostics::span_lint_and_note;
 use clippy_utils::source::snippet;
 use clippy_utils::visitors::is_local_used;
-use rustc_data_structures::fx::FxHashMap;
+use rustc_data_structures::fx::{FxHashMap, FxHashSet};
 use rustc_hir::def::Res;
 use rustc_hir::def_id::LocalDefId;
 use rustc_hir::hir_id::ItemLocalId;
@@ -97,9 +97,19 @@
     "rebinding a name without even using the original value"
 }
 
-#[derive(Default)]
 pub(crate) struct Shadow {
     bindings: Vec<(FxHashMap<Symbol, Vec<ItemLocalId>>, LocalDefId)>,
+    allowed_names: FxHashSet<String>,
+}
+
+impl Shadow {
+    #[must_use]
+    pub fn new(allowed_names: &[String]) -> Self {
+        Self {
+            bindings: Vec::new(),
+            allowed_names: allowed_names.iter().cloned().collect(),
+        }
+    }
 }
 
 impl_lint_pass!(Shadow => [SHADOW_SAME, SHADOW_REUSE, SHADOW_UNRELATED]);
@@ -131,8 +141,10 @@ fn check_pat(&mut self, cx: &LateContext<'tcx>, pat: &'tcx Pat<'_>) {
             }
 
             if is_shadow(cx, scope_owner, prev, local_id) {
-                let prev_hir_id = HirId { owner, local_id: prev };
-                lint_shadow(cx, pat, prev_hir_id, ident.span);
+                if !self.allowed_names.contains(ident.name.as_str()) {
+                    let prev_hir_id = HirId { owner, local_id: prev };
+                    lint_shadow(cx, pat, prev_hir_id, ident.span);
+                }
                 // only lint against the "nearest" shadowed binding
                 break;
             }
@@ -1,12 +1,15 @@
+use clippy_utils::consts::{constant_full_int, FullInt};
 use clippy_utils::diagnostics::span_lint_and_sugg;
 use clippy_utils::match_def_path;
+use clippy_utils::msrvs::{self, Msrv};
 use clippy_utils::source::snippet_with_applicability;
+use clippy_utils::sugg::Sugg;
 use if_chain::if_chain;
 use rustc_errors::Applicability;
 use rustc_hir as hir;
 use rustc_lint::{LateContext, LateLintPass};
 use rustc_middle::ty;
-use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_session::{declare_tool_lint, impl_lint_pass};
 
 declare_clippy_lint! {
     /// ### What it does
@@ -14,19 +17,22 @@
     ///
     /// ### Why is this bad?
     /// This is a convoluted way of checking if a `char` is a digit. It's
-    /// more straight forward to use the dedicated `is_digit` method.
+    /// more straight forward to use the dedicated `is_digit` method, or
+    /// `is_ascii_digit` and `is_ascii_hexdigit` for a literal radix of 10 or 16.
     ///
     /// ### Example
     /// ```rust
     /// # let c = 'c';
-    /// # let radix = 10;
+    /// # let radix = 8;
     /// let is_digit = c.to_digit(radix).is_some();
+    /// let is_decimal = c.to_digit(10).is_some();
     /// ```
     /// can be written as:
     /// ```
     /// # let c = 'c';
-    /// # let radix = 10;
+    /// # let radix = 8;
     /// let is_digit = c.is_digit(radix);
+    /// let is_decimal = c.is_ascii_digit();
     /// ```
     #[clippy::version = "1.41.0"]
     pub TO_DIGIT_IS_SOME,
@@ -34,7 +40,18 @@
     "`char.is_digit()` is clearer"
 }
 
-declare_lint_pass!(ToDigitIsSome => [TO_DIGIT_IS_SOME]);
+pub struct ToDigitIsSome {
+    msrv: Msrv,
+}
+
+impl ToDigitIsSome {
+    #[must_use]
+    pub fn new(msrv: Msrv) -> Self {
+        Self { msrv }
+    }
+}
+
+impl_lint_pass!(ToDigitIsSome => [TO_DIGIT_IS_SOME]);
 
 impl<'tcx> LateLintPass<'tcx> for ToDigitIsSome {
     fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx hir::Expr<'_>) {
@@ -77,16 +94,30 @@ fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx hir::Expr<'_>) {
                     let char_arg_snip = snippet_with_applicability(cx, char_arg.span, "_", &mut applicability);
                     let radix_snip = snippet_with_applicability(cx, radix_arg.span, "_", &mut applicability);
 
+                    let ascii_method = if self.msrv.meets(msrvs::IS_ASCII_DIGIT) {
+                        match constant_full_int(cx, cx.typeck_results(), radix_arg) {
+                            Some(FullInt::S(10) | FullInt::U(10)) => Some("is_ascii_digit"),
+                            Some(FullInt::S(16) | FullInt::U(16)) => Some("is_ascii_hexdigit"),
+                            _ => None,
+                        }
+                    } else {
+                        None
+                    };
+
                     span_lint_and_sugg(
                         cx,
                         TO_DIGIT_IS_SOME,
                         expr.span,
                         "use of `.to_digit(..).is_some()`",
                         "try",
-                        if is_method_call {
-                            format!("{char_arg_snip}.is_digit({radix_snip})")
-                        } else {
-                            format!("char::is_digit({char_arg_snip}, {radix_snip})")
+                        match (is_method_call, ascii_method) {
+                            (true, Some(method)) => format!("{char_arg_snip}.{method}()"),
+                            (false, Some(method)) => {
+                                let char_ref = Sugg::hir_with_applicability(cx, char_arg, "_", &mut applicability).addr();
+                                format!("char::{method}({char_ref})")
+                            },
+                            (true, None) => format!("{char_arg_snip}.is_digit({radix_snip})"),
+                            (false, None) => format!("char::is_digit({char_arg_snip}, {radix_snip})"),
                         },
                         applicability,
                     );
@@ -94,4 +125,6 @@ fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx hir::Expr<'_>) {
             }
         }
     }
+
+    extract_msrv_attr!(LateContext);
 }
@@ -2,9 +2,10 @@
 use clippy_utils::diagnostics::span_lint_and_then;
 use clippy_utils::msrvs::{self, Msrv};
 use clippy_utils::source::snippet_with_applicability;
-use clippy_utils::sugg;
+use clippy_utils::{get_parent_node, sugg};
 use rustc_errors::Applicability;
-use rustc_hir::{self as hir, Expr, GenericArg, Mutability, Path, TyKind};
+use rustc_hir::def::DefKind;
+use rustc_hir::{self as hir, Expr, GenericArg, ItemLocalId, Local, Mutability, Node, Path, TyKind};
 use rustc_lint::LateContext;
 use rustc_middle::ty::{self, Ty, TypeVisitableExt};
 
@@ -21,6 +22,7 @@ pub(super) fn check<'tcx>(
 ) -> bool {
     match (&from_ty.kind(), &to_ty.kind()) {
         (ty::RawPtr(from_ptr_ty), ty::Ref(_, to_ref_ty, mutbl)) => {
+            let lifetime_note = lifetime_note(cx, e, path);
             span_lint_and_then(
                 cx,
                 TRANSMUTE_PTR_TO_REF,
@@ -60,6 +62,9 @@ pub(super) fn check<'tcx>(
                     };
 
                     diag.span_suggestion(e.span, "try", sugg, app);
+                    if let Some(note) = lifetime_note {
+                        diag.note(note);
+                    }
                 },
             );
             true
@@ -70,11 +75,66 @@ pub(super) fn check<'tcx>(
 
 /// Gets the type `Bar` in `…::transmute<Foo, &Bar>`.
 fn get_explicit_type<'tcx>(path: &'tcx Path<'tcx>) -> Option<&'tcx hir::Ty<'tcx>> {
-    if let GenericArg::Type(ty) = path.segments.last()?.args?.args.get(1)?
-        && let TyKind::Ref(_, ty) = &ty.kind
-    {
+    if let TyKind::Ref(_, ty) = &get_explicit_ref(path)?.kind {
         Some(ty.ty)
     } else {
         None
     }
 }
+
+/// Returns a note if the reference created by the transmute `e` gets a `'static` lifetime or one
+/// which isn't bounded by anything, since the pointee may not live that long.
+fn lifetime_note(cx: &LateContext<'_>, e: &Expr<'_>, path: &Path<'_>) -> Option<&'static str> {
+    const STATIC_NOTE: &str = "the resulting reference has a `'static` lifetime, make sure the pointee is never freed";
+    const UNBOUNDED_NOTE: &str =
+        "the lifetime of the resulting reference is not bounded by any argument, make sure the pointee outlives it";
+
+    // `transmute::<_, &'static T>(ptr)` or `let r: &'static T = transmute(ptr)`
+    let annotation = get_explicit_ref(path).or_else(|| match get_parent_node(cx.tcx, e.hir_id) {
+        Some(Node::Local(Local { ty: Some(ty), .. })) => Some(*ty),
+        _ => None,
+    });
+    if let Some(hir::Ty {
+        kind: TyKind::Ref(lifetime, _),
+        ..
+    }) = annotation
+        && lifetime.is_static()
+    {
+        return Some(STATIC_NOTE);
+    }
+
+    // The reference is returned from a function
+    let fn_id = cx.tcx.hir().get_return_block(e.hir_id)?;
+    // Only functions are owners, a closure's id would refer to the enclosing item.
+    let fn_def_id = fn_id.owner.def_id;
+    if fn_id.local_id != ItemLocalId::from_u32(0)
+        || !matches!(cx.tcx.def_kind(fn_def_id), DefKind::Fn | DefKind::AssocFn)
+    {
+        return None;
+    }
+    let sig = cx.tcx.fn_sig(fn_def_id).instantiate_identity().skip_binder();
+    if let ty::Ref(region, ..) = *sig.output().kind() {
+        if region.is_static() {
+            Some(STATIC_NOTE)
+        } else if sig
+            .inputs()
+            .iter()
+            .any(|ty| ty.walk().any(|arg| arg.as_region() == Some(region)))
+        {
+            None
+        } else {
+            Some(UNBOUNDED_NOTE)
+        }
+    } else {
+        None
+    }
+}
+
+/// Gets the type `&Bar` in `…::transmute<Foo, &Bar>`.
+fn get_explicit_ref<'tcx>(path: &'tcx Path<'tcx>) -> Option<&'tcx hir::Ty<'tcx>> {
+    if let GenericArg::Type(ty) = path.segments.last()?.args?.args.get(1)? {
+        Some(ty)
+    } else {
+        None
+    }
+}
@@ -1,12 +1,15 @@
-use clippy_utils::diagnostics::span_lint_and_then;
-use clippy_utils::is_def_id_trait_method;
+use clippy_utils::diagnostics::span_lint_hir_and_then;
+use clippy_utils::ty::is_type_lang_item;
+use clippy_utils::{fn_def_id, get_parent_expr, is_def_id_trait_method};
+use rustc_hir::def::{DefKind, Res};
 use rustc_hir::intravisit::{walk_body, walk_expr, walk_fn, FnKind, Visitor};
-use rustc_hir::{Body, Expr, ExprKind, FnDecl, YieldSource};
+use rustc_hir::{Body, Expr, ExprKind, FnDecl, LangItem, Node, YieldSource};
 use rustc_lint::{LateContext, LateLintPass};
 use rustc_middle::hir::nested_filter;
-use rustc_session::{declare_lint_pass, declare_tool_lint};
-use rustc_span::def_id::LocalDefId;
-use rustc_span::Span;
+use rustc_middle::ty::{self, ClauseKind, Ty};
+use rustc_session::{declare_tool_lint, impl_lint_pass};
+use rustc_span::def_id::{LocalDefId, LocalDefIdSet};
+use rustc_span::{sym, Span};
 
 declare_clippy_lint! {
     /// ### What it does
@@ -38,7 +41,22 @@
     "finds async functions with no await statements"
 }
 
-declare_lint_pass!(UnusedAsync => [UNUSED_ASYNC]);
+#[derive(Default)]
+pub struct UnusedAsync {
+    /// Async functions used where a future is required, e.g. passed to a function expecting a
+    /// function which returns a `Future`. These need to stay `async`.
+    async_fns_requiring_future: LocalDefIdSet,
+    /// Functions with unused `async`, linted after all uses of them in the crate have been seen.
+    unused_async_fns: Vec<UnusedAsyncFn>,
+}
+
+struct UnusedAsyncFn {
+    def_id: LocalDefId,
+    fn_span: Span,
+    await_in_async_block: Option<Span>,
+}
+
+impl_lint_pass!(UnusedAsync => [UNUSED_ASYNC]);
 
 struct AsyncFnVisitor<'a, 'tcx> {
     cx: &'a LateContext<'tcx>,
@@ -101,24 +119,149 @@ fn check_fn(
             };
             walk_fn(&mut visitor, fn_kind, fn_decl, body.id(), def_id);
             if !visitor.found_await {
-                span_lint_and_then(
-                    cx,
-                    UNUSED_ASYNC,
-                    span,
-                    "unused `async` for function with no await statements",
-                    |diag| {
-                        diag.help("consider removing the `async` from this function");
-
-                        if let Some(span) = visitor.await_in_async_block {
-                            diag.span_note(
-                                span,
-                                "`await` used in an async block, which does not require \
-                                the enclosing function to be `async`",
-                            );
+                // Don't lint just yet, the function may still be used where its future is required.
+                self.unused_async_fns.push(UnusedAsyncFn {
+                    def_id,
+                    fn_span: span,
+                    await_in_async_block: visitor.await_in_async_block,
+                });
+            }
+        }
+    }
+
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
+        // `register(handler)` where `register` requires a function returning a future
+        if let Some(def_id) = local_async_fn(cx, expr)
+            && arg_requires_future(cx, expr)
+        {
+            self.async_fns_requiring_future.insert(def_id);
+        }
+
+        // `register(|| handler())` or `Box::pin(handler()) as Pin<Box<dyn Future<Output = ()>>>`
+        if let ExprKind::Call(callee, _) = expr.kind
+            && let Some(def_id) = local_async_fn(cx, callee)
+            && (is_boxed_as_dyn_future(cx, expr)
+                || matches!(
+                    cx.tcx.hir().get_parent(expr.hir_id),
+                    Node::Expr(closure @ Expr { kind: ExprKind::Closure(_), .. }) if arg_requires_future(cx, closure)
+                ))
+        {
+            self.async_fns_requiring_future.insert(def_id);
+        }
+    }
+
+    fn check_crate_post(&mut self, cx: &LateContext<'tcx>) {
+        for fun in self
+            .unused_async_fns
+            .iter()
+            .filter(|fun| !self.async_fns_requiring_future.contains(&fun.def_id))
+        {
+            span_lint_hir_and_then(
+                cx,
+                UNUSED_ASYNC,
+                cx.tcx.hir().local_def_id_to_hir_id(fun.def_id),
+                fun.fn_span,
+                "unused `async` for function with no await statements",
+                |diag| {
+                    diag.help("consider removing the `async` from this function");
+
+                    if let Some(span) = fun.await_in_async_block {
+                        diag.span_note(
+                            span,
+                            "`await` used in an async block, which does not require \
+                            the enclosing function to be `async`",
+                        );
+                    }
+                },
+            );
+        }
+    }
+}
+
+/// Gets the local async function the path expression `expr` refers to.
+fn local_async_fn(cx: &LateContext<'_>, expr: &Expr<'_>) -> Option<LocalDefId> {
+    if let ExprKind::Path(ref qpath) = expr.kind
+        && let Res::Def(DefKind::Fn | DefKind::AssocFn, def_id) = cx.qpath_res(qpath, expr.hir_id)
+        && let Some(local_def_id) = def_id.as_local()
+        && cx.tcx.asyncness(def_id).is_async()
+    {
+        Some(local_def_id)
+    } else {
+        None
+    }
+}
+
+/// Checks whether `arg` is passed to a function as an argument of a generic type which is bound
+/// to return a future, e.g. `F: Fn() -> Fut, Fut: Future`.
+fn arg_requires_future(cx: &LateContext<'_>, arg: &Expr<'_>) -> bool {
+    let Some(parent) = get_parent_expr(cx, arg) else {
+        return false;
+    };
+    let index = match parent.kind {
+        ExprKind::Call(_, args) => args.iter().position(|a| a.hir_id == arg.hir_id),
+        ExprKind::MethodCall(_, _, args, _) => args.iter().position(|a| a.hir_id == arg.hir_id).map(|i| i + 1),
+        _ => None,
+    };
+    if let Some(index) = index
+        && let Some(callee_id) = fn_def_id(cx, parent)
+        && let ty::Param(param_ty) =
+            *cx.tcx.fn_sig(callee_id).instantiate_identity().skip_binder().inputs()[index].kind()
+    {
+        let predicates = cx.tcx.param_env(callee_id).caller_bounds();
+        // The types the argument's type is bound to return, directly or through further calls.
+        let mut bound_tys = vec![param_ty.to_ty(cx.tcx)];
+        let mut i = 0;
+        while let Some(&bound_ty) = bound_tys.get(i) {
+            i += 1;
+            for predicate in predicates.iter() {
+                match predicate.kind().skip_binder() {
+                    ClauseKind::Trait(trait_predicate) if trait_predicate.self_ty() == bound_ty => {
+                        let trait_id = trait_predicate.def_id();
+                        if cx.tcx.lang_items().future_trait() == Some(trait_id)
+                            || cx.tcx.is_diagnostic_item(sym::IntoFuture, trait_id)
+                        {
+                            return true;
                         }
                     },
-                );
+                    ClauseKind::Projection(projection_predicate)
+                        if projection_predicate.projection_ty.self_ty() == bound_ty
+                            && let Some(term_ty) = projection_predicate.term.ty()
+                            && let ty::Param(_) = term_ty.kind()
+                            && !bound_tys.contains(&term_ty) =>
+                    {
+                        bound_tys.push(term_ty);
+                    },
+                    _ => {},
+                }
             }
         }
     }
+    false
+}
+
+/// Checks whether the future returned by the call `expr` is boxed and coerced to a `dyn Future`,
+/// e.g. `Box::pin(f())` returned as a `Pin<Box<dyn Future<Output = ()>>>`.
+fn is_boxed_as_dyn_future(cx: &LateContext<'_>, expr: &Expr<'_>) -> bool {
+    fn is_dyn_future(cx: &LateContext<'_>, ty: Ty<'_>) -> bool {
+        if let ty::Dynamic(predicates, ..) = ty.kind() {
+            predicates.principal_def_id() == cx.tcx.lang_items().future_trait()
+        } else {
+            false
+        }
+    }
+
+    if let Some(parent) = get_parent_expr(cx, expr)
+        && let ExprKind::Call(_, [arg]) = parent.kind
+        && arg.hir_id == expr.hir_id
+    {
+        let mut ty = cx.typeck_results().expr_ty_adjusted(parent);
+        if is_type_lang_item(cx, ty, LangItem::Pin)
+            && let ty::Adt(_, args) = ty.kind()
+        {
+            ty = args.type_at(0);
+        }
+        ty.is_box() && is_dyn_future(cx, ty.boxed_ty())
+    } else {
+        false
+    }
 }
@@ -18,7 +18,10 @@
     ///
     /// By default, the lint only triggers on fully-capitalized names.
     /// You can use the `upper-case-acronyms-aggressive: true` config option to enable linting
-    /// on all camel case names
+    /// on all camel case names, and `max-acronym-length-allowed` to accept short acronyms like `IO`.
+    ///
+    /// Items in `extern` blocks and `#[repr(C)]` types aren't linted, as their names usually
+    /// mirror those of a C API.
     ///
     /// ### Known problems
     /// When two acronyms are contiguous, the lint can't tell where
@@ -43,48 +46,53 @@
 pub struct UpperCaseAcronyms {
     avoid_breaking_exported_api: bool,
     upper_case_acronyms_aggressive: bool,
+    max_acronym_length_allowed: usize,
 }
 
 impl UpperCaseAcronyms {
-    pub fn new(avoid_breaking_exported_api: bool, aggressive: bool) -> Self {
+    pub fn new(avoid_breaking_exported_api: bool, aggressive: bool, max_acronym_length_allowed: u64) -> Self {
         Self {
             avoid_breaking_exported_api,
             upper_case_acronyms_aggressive: aggressive,
+            max_acronym_length_allowed: usize::try_from(max_acronym_length_allowed).unwrap_or(usize::MAX),
         }
     }
 }
 
 impl_lint_pass!(UpperCaseAcronyms => [UPPER_CASE_ACRONYMS]);
 
-fn correct_ident(ident: &str) -> String {
+/// Lowercases all but the first letter of the acronyms in `ident` which are longer than
+/// `max_acronym_len`.
+fn correct_ident(ident: &str, max_acronym_len: usize) -> String {
     let ident = ident.chars().rev().collect::<String>();
+    // Words starting with an uppercase letter, the letters of acronyms and a leading lowercase word
     let fragments = ident
         .split_inclusive(|x: char| !x.is_ascii_lowercase())
         .rev()
         .map(|x| x.chars().rev().collect::<String>());
 
-    let mut ident = fragments.clone().next().unwrap();
-    for (ref prev, ref curr) in fragments.tuple_windows() {
-        if <[&String; 2]>::from((prev, curr))
-            .iter()
-            .all(|s| s.len() == 1 && s.chars().next().unwrap().is_ascii_uppercase())
-        {
-            ident.push_str(&curr.to_ascii_lowercase());
+    let is_acronym_letter = |s: &String| s.len() == 1 && s.chars().next().unwrap().is_ascii_uppercase();
+    let mut corrected = String::new();
+    for (is_acronym, group) in &fragments.group_by(is_acronym_letter) {
+        let group = group.collect::<Vec<_>>();
+        if is_acronym && group.len() > max_acronym_len.max(1) {
+            corrected.push_str(&group[0]);
+            corrected.extend(group[1..].iter().map(|s| s.to_ascii_lowercase()));
         } else {
-            ident.push_str(curr);
+            corrected.extend(group);
         }
     }
-    ident
+    corrected
 }
 
-fn check_ident(cx: &LateContext<'_>, ident: &Ident, be_aggressive: bool) {
+fn check_ident(cx: &LateContext<'_>, ident: &Ident, be_aggressive: bool, max_acronym_len: usize) {
     let span = ident.span;
     let ident = ident.as_str();
-    let corrected = correct_ident(ident);
+    let corrected = correct_ident(ident, max_acronym_len);
     // warn if we have pure-uppercase idents
     // assume that two-letter words are some kind of valid abbreviation like FP for false positive
     // (and don't warn)
-    if (ident.chars().all(|c| c.is_ascii_uppercase()) && ident.len() > 2)
+    if (ident.chars().all(|c| c.is_ascii_uppercase()) && ident.len() > max_acronym_len.max(2))
     // otherwise, warn if we have SOmeTHING lIKE THIs but only warn with the aggressive
     // upper-case-acronyms-aggressive config option enabled
     || (be_aggressive && ident != corrected)
@@ -110,17 +118,33 @@ fn check_item(&mut self, cx: &LateContext<'_>, it: &Item<'_>) {
             return;
         }
         match it.kind {
+            // names of C types are usually kept as is
+            ItemKind::Struct(..) | ItemKind::Enum(..) if cx.tcx.adt_def(it.owner_id).repr().c() => {},
             ItemKind::TyAlias(..) | ItemKind::Struct(..) | ItemKind::Trait(..) => {
-                check_ident(cx, &it.ident, self.upper_case_acronyms_aggressive);
+                check_ident(
+                    cx,
+                    &it.ident,
+                    self.upper_case_acronyms_aggressive,
+                    self.max_acronym_length_allowed,
+                );
             },
             ItemKind::Enum(ref enumdef, _) => {
-                check_ident(cx, &it.ident, self.upper_case_acronyms_aggressive);
+                check_ident(
+                    cx,
+                    &it.ident,
+                    self.upper_case_acronyms_aggressive,
+                    self.max_acronym_length_allowed,
+                );
                 // check enum variants separately because again we only want to lint on private enums and
                 // the fn check_variant does not know about the vis of the enum of its variants
-                enumdef
-                    .variants
-                    .iter()
-                    .for_each(|variant| check_ident(cx, &variant.ident, self.upper_case_acronyms_aggressive));
+                enumdef.variants.iter().for_each(|variant| {
+                    check_ident(
+                        cx,
+                        &variant.ident,
+                        self.upper_case_acronyms_aggressive,
+                        self.max_acronym_length_allowed,
+                    );
+                });
             },
             _ => {},
         }
@@ -213,6 +213,7 @@ fn check_ty(&mut self, cx: &LateContext<'_>, hir_ty: &hir::Ty<'_>) {
                 ref types_to_skip,
             }) = self.stack.last();
             if let TyKind::Path(QPath::Resolved(_, path)) = hir_ty.kind;
+            if is_written_at_call_site(path);
             if !matches!(
                 path.res,
                 Res::SelfTyParam { .. }
@@ -233,34 +234,67 @@ fn check_ty(&mut self, cx: &LateContext<'_>, hir_ty: &hir::Ty<'_>) {
     }
 
     fn check_expr(&mut self, cx: &LateContext<'_>, expr: &Expr<'_>) {
-        if_chain! {
-            if !expr.span.from_expansion();
-            if self.msrv.meets(msrvs::TYPE_ALIAS_ENUM_VARIANTS);
-            if let Some(&StackItem::Check { impl_id, .. }) = self.stack.last();
-            if cx.typeck_results().expr_ty(expr) == cx.tcx.type_of(impl_id).instantiate_identity();
-            then {} else { return; }
+        if !self.msrv.meets(msrvs::TYPE_ALIAS_ENUM_VARIANTS) {
+            return;
         }
-        match expr.kind {
-            ExprKind::Struct(QPath::Resolved(_, path), ..) => check_path(cx, path),
-            ExprKind::Call(fun, _) => {
-                if let ExprKind::Path(QPath::Resolved(_, path)) = fun.kind {
-                    check_path(cx, path);
-                }
-            },
-            ExprKind::Path(QPath::Resolved(_, path)) => check_path(cx, path),
-            _ => (),
+        let Some(&mut StackItem::Check {
+            impl_id,
+            ref mut types_to_skip,
+            ..
+        }) = self.stack.last_mut()
+        else {
+            return;
+        };
+        let impl_ty = cx.tcx.type_of(impl_id).instantiate_identity();
+
+        // `Foo::<T>::new()`: the type is written with explicit generic args, so it is only
+        // replaceable if those args are identical to the ones of the `impl` self type.
+        if let ExprKind::Path(QPath::TypeRelative(qself, _)) = expr.kind
+            && let TyKind::Path(QPath::Resolved(None, path)) = qself.kind
+            && path.segments.last().map_or(false, |seg| seg.args.is_some())
+            && !types_to_skip.contains(&qself.hir_id)
+            && (!expr.span.from_expansion() || is_written_at_call_site(path))
+            && !qself.span.from_expansion()
+            && let Some(qself_ty) = cx.typeck_results().node_type_opt(qself.hir_id)
+            && qself_ty == impl_ty
+        {
+            // `check_ty` will visit the same type next; make sure it is only linted once.
+            types_to_skip.insert(qself.hir_id);
+            span_lint(cx, qself.span);
+            return;
+        }
+
+        if cx.typeck_results().expr_ty(expr) != impl_ty {
+            return;
+        }
+        let path = match expr.kind {
+            ExprKind::Struct(QPath::Resolved(_, path), ..) => path,
+            ExprKind::Call(
+                Expr {
+                    kind: ExprKind::Path(QPath::Resolved(_, path)),
+                    ..
+                },
+                _,
+            ) => path,
+            ExprKind::Path(QPath::Resolved(_, path)) => path,
+            _ => return,
+        };
+        // Inside of macro expansions the type name may have been written on purpose by the macro
+        // author. Only lint if the whole path was passed in from the call site.
+        if !expr.span.from_expansion() || is_written_at_call_site(path) {
+            check_path(cx, path);
         }
     }
 
     fn check_pat(&mut self, cx: &LateContext<'_>, pat: &Pat<'_>) {
         if_chain! {
-            if !pat.span.from_expansion();
             if self.msrv.meets(msrvs::TYPE_ALIAS_ENUM_VARIANTS);
             if let Some(&StackItem::Check { impl_id, .. }) = self.stack.last();
             // get the path from the pattern
             if let PatKind::Path(QPath::Resolved(_, path))
                  | PatKind::TupleStruct(QPath::Resolved(_, path), _, _)
                  | PatKind::Struct(QPath::Resolved(_, path), _, _) = pat.kind;
+            if !pat.span.from_expansion() || is_written_at_call_site(path);
             if cx.typeck_results().pat_ty(pat) == cx.tcx.type_of(impl_id).instantiate_identity();
             then {
                 check_path(cx, path);
@@ -301,6 +335,20 @@ fn span_lint(cx: &LateContext<'_>, span: Span) {
     );
 }
 
+/// Checks if every part of `path` comes verbatim from the source the user wrote, as opposed to
+/// being (partially) produced by a macro expansion. Suggesting `Self` for a path that was put
+/// together inside a macro would rewrite the macro call site in nonsensical ways.
+fn is_written_at_call_site(path: &Path<'_>) -> bool {
+    !path.span.from_expansion()
+        && path.segments.iter().all(|seg| {
+            !seg.ident.span.from_expansion()
+                && path.span.contains(seg.ident.span)
+                && seg
+                    .args
+                    .map_or(true, |args| args.span_ext().map_or(true, |span| !span.from_expansion()))
+        })
+}
+
 fn check_path(cx: &LateContext<'_>, path: &Path<'_>) {
     match path.res {
         Res::Def(DefKind::Ctor(CtorOf::Variant, _) | DefKind::Variant, ..) => {
@@ -174,6 +174,16 @@ fn check_expr(&mut self, cx: &LateContext<'tcx>, e: &'tcx Expr<'_>) {
                             //  ^^^
                             let (into_iter_recv, depth) = into_iter_deep_call(cx, into_iter_recv);
 
+                            // If the receiver was auto-referenced, e.g. `path_buf.into_iter()` calls
+                            // `<&PathBuf as IntoIterator>::into_iter`, passing it directly would use a
+                            // different `IntoIterator` impl with a different item type, if any.
+                            let typeck = cx.typeck_results();
+                            if cx.tcx.erase_regions(typeck.expr_ty(into_iter_recv))
+                                != cx.tcx.erase_regions(typeck.expr_ty_adjusted(into_iter_recv))
+                            {
+                                return;
+                            }
+
                             let plural = if depth == 0 { "" } else { "s" };
                             let mut applicability = Applicability::MachineApplicable;
                             let sugg = snippet_with_applicability(cx, into_iter_recv.span.source_callsite(), "<expr>", &mut applicability).into_owned();
@@ -3,9 +3,11 @@
 #![allow(clippy::module_name_repetitions)]
 
 use rustc_session::Session;
-use rustc_span::{BytePos, Pos, SourceFile, Span, SyntaxContext};
+use rustc_span::edit_distance::find_best_match_for_name;
+use rustc_span::{BytePos, FileName, Pos, SourceFile, Span, Symbol, SyntaxContext};
 use serde::de::{Deserializer, IgnoredAny, IntoDeserializer, MapAccess, Visitor};
 use serde::Deserialize;
+use std::collections::BTreeMap;
 use std::fmt::{Debug, Display, Formatter};
 use std::ops::Range;
 use std::path::{Path, PathBuf};
@@ -68,6 +70,38 @@ pub fn reason(&self) -> Option<String> {
     }
 }
 
+/// Lint groups which can be given a level in the `[lints]` table, in addition to single lints.
+const LINT_GROUPS: &[&str] = &[
+    "all",
+    "cargo",
+    "complexity",
+    "correctness",
+    "nursery",
+    "pedantic",
+    "perf",
+    "restriction",
+    "style",
+    "suspicious",
+];
+
+/// A level given to a lint or a lint group in the `[lints]` table.
+#[derive(Clone, Copy, Debug, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum LintLevel {
+    Allow,
+    Warn,
+    Deny,
+    Forbid,
+}
+
+/// The `[lints]` table of the configuration file. The other keys are read by `ConfVisitor`, which
+/// loses the spans of nested keys.
+#[derive(Deserialize)]
+struct LintsTable {
+    #[serde(default)]
+    lints: BTreeMap<toml::Spanned<String>, LintLevel>,
+}
+
 /// Conf with parse errors
 #[derive(Default)]
 pub struct TryConf {
@@ -101,9 +135,17 @@ fn from(value: io::Error) -> Self {
 #[derive(Debug)]
 pub struct ConfError {
     pub message: String,
+    pub suggestion: Option<Suggestion>,
     pub span: Option<Span>,
 }
 
+/// A replacement for the span of a [`ConfError`].
+#[derive(Debug)]
+pub struct Suggestion {
+    pub message: &'static str,
+    pub suggestion: &'static str,
+}
+
 impl ConfError {
     fn from_toml(file: &SourceFile, error: &toml::de::Error) -> Self {
         if let Some(span) = error.span() {
@@ -111,6 +153,7 @@ fn from_toml(file: &SourceFile, error: &toml::de::Error) -> Self {
         } else {
             Self {
                 message: error.message().to_string(),
+                suggestion: None,
                 span: None,
             }
         }
@@ -119,6 +162,7 @@ fn from_toml(file: &SourceFile, error: &toml::de::Error) -> Self {
     fn spanned(file: &SourceFile, message: impl Into<String>, span: Range<usize>) -> Self {
         Self {
             message: message.into(),
+            suggestion: None,
             span: Some(Span::new(
                 file.start_pos + BytePos::from_usize(span.start),
                 file.start_pos + BytePos::from_usize(span.end),
@@ -129,10 +173,21 @@ fn spanned(file: &SourceFile, message: impl Into<String>, span: Range<usize>) ->
     }
 }
 
+impl ConfError {
+    /// Adds the path of the configuration file to errors without a span.
+    fn in_file(mut self, path: &Path) -> Self {
+        if self.span.is_none() {
+            self.message = format!("`{}`: {}", path.display(), self.message);
+        }
+        self
+    }
+}
+
 impl From<io::Error> for ConfError {
     fn from(value: io::Error) -> Self {
         Self {
             message: value.to_string(),
+            suggestion: None,
             span: None,
         }
     }
@@ -162,7 +217,7 @@ fn default() -> Self {
         #[derive(Deserialize)]
         #[serde(field_identifier, rename_all = "kebab-case")]
         #[allow(non_camel_case_types)]
-        enum Field { $($name,)* third_party, }
+        enum Field { $($name,)* lints, third_party, }
 
         struct ConfVisitor<'a>(&'a SourceFile);
 
@@ -182,7 +237,10 @@ fn visit_map<V>(self, mut map: V) -> Result<Self::Value, V::Error> where V: MapA
                     match Field::deserialize(name.get_ref().as_str().into_deserializer()) {
                         Err(e) => {
                             let e: FieldError = e;
-                            errors.push(ConfError::spanned(self.0, e.0, name.span()));
+                            errors.push(ConfError {
+                                suggestion: e.suggestion,
+                                ..ConfError::spanned(self.0, e.error, name.span())
+                            });
                         }
                         $(Ok(Field::$name) => {
                             $(warnings.push(ConfError::spanned(self.0, format!("deprecated field `{}`. {}", name.get_ref(), $dep), name.span()));)?
@@ -207,6 +265,8 @@ fn visit_map<V>(self, mut map: V) -> Result<Self::Value, V::Error> where V: MapA
                                 }
                             }
                         })*
+                        // the lints table is read by `check_lint_levels`
+                        Ok(Field::lints) => drop(map.next_value::<IgnoredAny>()),
                         // ignore contents of the third_party key
                         Ok(Field::third_party) => drop(map.next_value::<IgnoredAny>())
                     }
@@ -290,11 +350,11 @@ pub fn get_configuration_metadata() -> Vec<ClippyConfiguration> {
     /// arithmetic-side-effects-allowed-unary = ["SomeType", "AnotherType"]
     /// ```
     (arithmetic_side_effects_allowed_unary: rustc_data_structures::fx::FxHashSet<String> = <_>::default()),
-    /// Lint: ENUM_VARIANT_NAMES, LARGE_TYPES_PASSED_BY_VALUE, TRIVIALLY_COPY_PASS_BY_REF, UNNECESSARY_WRAPS, UNUSED_SELF, UPPER_CASE_ACRONYMS, WRONG_SELF_CONVENTION, BOX_COLLECTION, REDUNDANT_ALLOCATION, RC_BUFFER, VEC_BOX, OPTION_OPTION, LINKEDLIST, RC_MUTEX, UNNECESSARY_BOX_RETURNS, SINGLE_CALL_FN.
+    /// Lint: ENUM_VARIANT_NAMES, LARGE_TYPES_PASSED_BY_VALUE, TRIVIALLY_COPY_PASS_BY_REF, UNNECESSARY_WRAPS, UNUSED_SELF, UPPER_CASE_ACRONYMS, WRONG_SELF_CONVENTION, BOX_COLLECTION, REDUNDANT_ALLOCATION, RC_BUFFER, VEC_BOX, OPTION_OPTION, LINKEDLIST, RC_MUTEX, UNNECESSARY_BOX_RETURNS, SINGLE_CALL_FN, NEEDLESS_BOX_IN_RETURN.
     ///
     /// Suppress lints whenever the suggested change would cause breakage for other crates.
     (avoid_breaking_exported_api: bool = true),
-    /// Lint: MANUAL_SPLIT_ONCE, MANUAL_STR_REPEAT, CLONED_INSTEAD_OF_COPIED, REDUNDANT_FIELD_NAMES, OPTION_MAP_UNWRAP_OR, REDUNDANT_STATIC_LIFETIMES, FILTER_MAP_NEXT, CHECKED_CONVERSIONS, MANUAL_RANGE_CONTAINS, USE_SELF, MEM_REPLACE_WITH_DEFAULT, MANUAL_NON_EXHAUSTIVE, OPTION_AS_REF_DEREF, MAP_UNWRAP_OR, MATCH_LIKE_MATCHES_MACRO, MANUAL_STRIP, MISSING_CONST_FOR_FN, UNNESTED_OR_PATTERNS, FROM_OVER_INTO, PTR_AS_PTR, IF_THEN_SOME_ELSE_NONE, APPROX_CONSTANT, DEPRECATED_CFG_ATTR, INDEX_REFUTABLE_SLICE, MAP_CLONE, BORROW_AS_PTR, MANUAL_BITS, ERR_EXPECT, CAST_ABS_TO_UNSIGNED, UNINLINED_FORMAT_ARGS, MANUAL_CLAMP, MANUAL_LET_ELSE, UNCHECKED_DURATION_SUBTRACTION, COLLAPSIBLE_STR_REPLACE, SEEK_FROM_CURRENT, SEEK_REWIND, UNNECESSARY_LAZY_EVALUATIONS, TRANSMUTE_PTR_TO_REF, ALMOST_COMPLETE_RANGE, NEEDLESS_BORROW, DERIVABLE_IMPLS, MANUAL_IS_ASCII_CHECK, MANUAL_REM_EUCLID, MANUAL_RETAIN, TYPE_REPETITION_IN_BOUNDS, TUPLE_ARRAY_CONVERSIONS, MANUAL_TRY_FOLD.
+    /// Lint: MANUAL_SPLIT_ONCE, MANUAL_STR_REPEAT, CLONED_INSTEAD_OF_COPIED, REDUNDANT_FIELD_NAMES, OPTION_MAP_UNWRAP_OR, REDUNDANT_STATIC_LIFETIMES, FILTER_MAP_NEXT, CHECKED_CONVERSIONS, MANUAL_RANGE_CONTAINS, USE_SELF, MEM_REPLACE_WITH_DEFAULT, MANUAL_NON_EXHAUSTIVE, OPTION_AS_REF_DEREF, MAP_UNWRAP_OR, MATCH_LIKE_MATCHES_MACRO, MANUAL_STRIP, MISSING_CONST_FOR_FN, UNNESTED_OR_PATTERNS, FROM_OVER_INTO, PTR_AS_PTR, IF_THEN_SOME_ELSE_NONE, APPROX_CONSTANT, DEPRECATED_CFG_ATTR, INDEX_REFUTABLE_SLICE, MAP_CLONE, BORROW_AS_PTR, MANUAL_BITS, ERR_EXPECT, CAST_ABS_TO_UNSIGNED, UNINLINED_FORMAT_ARGS, MANUAL_CLAMP, MANUAL_LET_ELSE, UNCHECKED_DURATION_SUBTRACTION, COLLAPSIBLE_STR_REPLACE, SEEK_FROM_CURRENT, SEEK_REWIND, UNNECESSARY_LAZY_EVALUATIONS, TRANSMUTE_PTR_TO_REF, ALMOST_COMPLETE_RANGE, NEEDLESS_BORROW, DERIVABLE_IMPLS, MANUAL_IS_ASCII_CHECK, MANUAL_REM_EUCLID, MANUAL_RETAIN, TYPE_REPETITION_IN_BOUNDS, TUPLE_ARRAY_CONVERSIONS, MANUAL_TRY_FOLD, TO_DIGIT_IS_SOME, MANUAL_OPTION_XOR, MANUAL_OPTION_INSERT.
     ///
     /// The minimum rust version that the project supports
     (msrv: Option<String> = None),
@@ -311,6 +371,10 @@ pub fn get_configuration_metadata() -> Vec<ClippyConfiguration> {
     ///
     /// The maximum amount of nesting a block can reside in
     (excessive_nesting_threshold: u64 = 0),
+    /// Lint: EXCESSIVE_NESTING_IN_MATCH_ARM.
+    ///
+    /// The maximum depth of `match` expressions nested in each other
+    (excessive_match_nesting_threshold: u64 = 3),
     /// DEPRECATED LINT: CYCLOMATIC_COMPLEXITY.
     ///
     /// Use the Cognitive Complexity lint instead.
@@ -375,11 +439,13 @@ pub fn get_configuration_metadata() -> Vec<ClippyConfiguration> {
     /// Lint: TRIVIALLY_COPY_PASS_BY_REF.
     ///
     /// The maximum size (in bytes) to consider a `Copy` type for passing by value instead of by reference.
+    /// By default this is twice the target's pointer size.
     (trivial_copy_size_limit: Option<u64> = None),
     /// Lint: LARGE_TYPES_PASSED_BY_VALUE.
     ///
     /// The minimum size (in bytes) to consider a type for passing by reference instead of by value.
-    (pass_by_value_size_limit: u64 = 256),
+    /// By default this is 32 times the target's pointer size, i.e. 256 on 64-bit targets.
+    (pass_by_value_size_limit: Option<u64> = None),
     /// Lint: TOO_MANY_LINES.
     ///
     /// The maximum number of lines a function or method can have
@@ -520,7 +586,7 @@ pub fn get_configuration_metadata() -> Vec<ClippyConfiguration> {
     ///
     /// The maximum byte size a `Future` can have, before it triggers the `clippy::large_futures` lint
     (future_size_threshold: u64 = 16 * 1024),
-    /// Lint: UNNECESSARY_BOX_RETURNS.
+    /// Lint: UNNECESSARY_BOX_RETURNS, NEEDLESS_BOX_IN_RETURN.
     ///
     /// The byte size a `T` in `Box<T>` can have, below which it triggers the `clippy::unnecessary_box` lint
     (unnecessary_box_size: u64 = 128),
@@ -551,16 +617,46 @@ pub fn get_configuration_metadata() -> Vec<ClippyConfiguration> {
     ///
     /// Whether to allow `r#""#` when `r""` can be used
     (allow_one_hash_in_raw_strings: bool = false),
+    /// Lint: SINGLE_MATCH.
+    ///
+    /// Whether to also lint matches whose wildcard arm has a body consisting of a single expression or
+    /// statement, suggesting `if let .. else ..` instead
+    (single_match_with_else: bool = false),
+    /// Lint: OR_FUN_CALL.
+    ///
+    /// Additional functions, written as fully qualified paths, which are cheap enough to be called
+    /// eagerly as the argument of `unwrap_or` and similar methods.
+    (or_fun_call_cheap_fns: Vec<String> = Vec::new()),
+    /// Lint: SHADOW_SAME, SHADOW_REUSE, SHADOW_UNRELATED.
+    ///
+    /// Names of bindings, matched exactly, which are allowed to shadow a previous binding of the same name
+    (allowed_shadowed_names: Vec<String> = Vec::new()),
+    /// Lint: UPPER_CASE_ACRONYMS.
+    ///
+    /// Acronyms of this length or shorter are accepted, e.g. `2` accepts the `IO` in `IOError` but not
+    /// the `HTTP` in `HTTPResponse`
+    (max_acronym_length_allowed: u64 = 0),
 }
 
-/// Search for the configuration file.
+/// Possible filename to search for.
+const CONFIG_FILE_NAMES: [&str; 2] = [".clippy.toml", "clippy.toml"];
+
+/// Search for the configuration files, nearest first.
+///
+/// `CLIPPY_CONF_PATH` names the configuration file, or a directory containing it, and disables the
+/// search.
+///
+/// Inside of a workspace, every configuration file from the crate's directory up to the workspace
+/// root is returned, to be merged by [`read`]. Outside of one, or if the workspace has none, only
+/// the nearest file is used.
 ///
 /// # Errors
 ///
 /// Returns any unexpected filesystem error encountered when searching for the config file
-pub fn lookup_conf_file() -> io::Result<(Option<PathBuf>, Vec<String>)> {
-    /// Possible filename to search for.
-    const CONFIG_FILE_NAMES: [&str; 2] = [".clippy.toml", "clippy.toml"];
+pub fn lookup_conf_file() -> io::Result<(Vec<PathBuf>, Vec<String>)> {
+    if let Some(conf_path) = env::var_os("CLIPPY_CONF_PATH") {
+        return Ok((vec![explicit_conf_file(Path::new(&conf_path))?], Vec::new()));
+    }
 
     // Start looking for a config file in CLIPPY_CONF_DIR, or failing that, CARGO_MANIFEST_DIR.
     // If neither of those exist, use ".". (Update documentation if this priority changes)
@@ -569,10 +665,14 @@ pub fn lookup_conf_file() -> io::Result<(Option<PathBuf>, Vec<String>)> {
         .map_or_else(|| PathBuf::from("."), PathBuf::from)
         .canonicalize()?;
 
-    let mut found_config: Option<PathBuf> = None;
-    let mut warnings = vec![];
+    // Each configuration file along with the warnings about the directory it was found in
+    let mut found_configs: Vec<(PathBuf, Vec<String>)> = Vec::new();
+    // Set once a workspace root without configuration files was passed
+    let mut above_workspace = false;
 
     loop {
+        let mut found_config: Option<PathBuf> = None;
+        let mut warnings = vec![];
         for config_file_name in &CONFIG_FILE_NAMES {
             if let Ok(config_file) = current.join(config_file_name).canonicalize() {
                 match fs::metadata(&config_file) {
@@ -595,42 +695,226 @@ pub fn lookup_conf_file() -> io::Result<(Option<PathBuf>, Vec<String>)> {
             }
         }
 
-        if found_config.is_some() {
-            return Ok((found_config, warnings));
+        if let Some(found_config) = found_config {
+            found_configs.push((found_config, warnings));
+            if above_workspace {
+                break;
+            }
+        }
+
+        if is_workspace_root(&current) {
+            if !found_configs.is_empty() {
+                break;
+            }
+            // Nothing to merge, keep looking for the nearest file above the workspace
+            above_workspace = true;
         }
 
         // If the current directory has no parent, we're done searching.
         if !current.pop() {
-            return Ok((None, warnings));
+            found_configs.truncate(1);
+            break;
         }
     }
+
+    let (configs, warnings): (Vec<_>, Vec<_>) = found_configs.into_iter().unzip();
+    Ok((configs, warnings.concat()))
+}
+
+/// Returns the configuration file given by `CLIPPY_CONF_PATH`, which has to exist.
+fn explicit_conf_file(path: &Path) -> io::Result<PathBuf> {
+    let path = path.canonicalize().map_err(|e| {
+        io::Error::new(
+            e.kind(),
+            format!("`CLIPPY_CONF_PATH` is set to `{}`: {e}", path.display()),
+        )
+    })?;
+    if !path.is_dir() {
+        return Ok(path);
+    }
+    CONFIG_FILE_NAMES
+        .iter()
+        .map(|name| path.join(name))
+        .find(|file| file.is_file())
+        .ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::NotFound,
+                format!(
+                    "`CLIPPY_CONF_PATH` is set to the directory `{}`, which doesn't contain a `clippy.toml`",
+                    path.display()
+                ),
+            )
+        })
 }
 
-/// Read the `toml` configuration file.
+/// Whether `dir` contains the `Cargo.toml` of a workspace root.
+fn is_workspace_root(dir: &Path) -> bool {
+    fs::read_to_string(dir.join("Cargo.toml"))
+        .ok()
+        .and_then(|manifest| toml::from_str::<toml::Table>(&manifest).ok())
+        .map_or(false, |manifest| manifest.contains_key("workspace"))
+}
+
+/// Read the `toml` configuration files, nearest first.
+///
+/// Every file is checked on its own, so that the errors point into the file they come from. The
+/// values of several files are merged with [`merge_tables`].
 ///
 /// In case of error, the function tries to continue as much as possible.
-pub fn read(sess: &Session, path: &Path) -> TryConf {
-    let file = match sess.source_map().load_file(path) {
-        Err(e) => return e.into(),
-        Ok(file) => file,
+pub fn read(sess: &Session, paths: &[PathBuf]) -> TryConf {
+    let mut errors = Vec::new();
+    let mut warnings = Vec::new();
+    let mut conf = None;
+    let mut merged: Option<toml::Table> = None;
+
+    // Farther files first, so that the nearer ones override them
+    for path in paths.iter().rev() {
+        let file = match sess.source_map().load_file(path) {
+            Err(e) => {
+                errors.push(ConfError::from(e).in_file(path));
+                continue;
+            },
+            Ok(file) => file,
+        };
+        let file_conf = read_file(&file);
+        errors.extend(file_conf.errors.into_iter().map(|error| error.in_file(path)));
+        warnings.extend(file_conf.warnings.into_iter().map(|warning| warning.in_file(path)));
+        conf = Some(file_conf.conf);
+
+        if let Ok(table) = toml::from_str::<toml::Table>(file.src.as_ref().unwrap()) {
+            merged = Some(match merged {
+                Some(farther) => merge_tables(farther, table),
+                None => table,
+            });
+        }
+    }
+
+    let mut conf = if paths.len() > 1
+        && let Some(merged) = merged.and_then(|merged| toml::to_string(&merged).ok())
+    {
+        let file = sess
+            .source_map()
+            .new_source_file(FileName::Custom("merged clippy.toml".into()), merged);
+        let merged_conf = read_file(&file);
+        // The errors of the files themselves have already been reported, these come from merging
+        if errors.is_empty() {
+            errors.extend(merged_conf.errors);
+        }
+        if warnings.is_empty() {
+            warnings.extend(merged_conf.warnings);
+        }
+        merged_conf.conf
+    } else {
+        conf.unwrap_or_default()
     };
-    match toml::de::Deserializer::new(file.src.as_ref().unwrap()).deserialize_map(ConfVisitor(&file)) {
-        Ok(mut conf) => {
-            extend_vec_if_indicator_present(&mut conf.conf.doc_valid_idents, DEFAULT_DOC_VALID_IDENTS);
-            extend_vec_if_indicator_present(&mut conf.conf.disallowed_names, DEFAULT_DISALLOWED_NAMES);
-            // TODO: THIS SHOULD BE TESTED, this comment will be gone soon
-            if conf.conf.allowed_idents_below_min_chars.contains(&"..".to_owned()) {
-                conf.conf
-                    .allowed_idents_below_min_chars
-                    .extend(DEFAULT_ALLOWED_IDENTS_BELOW_MIN_CHARS.iter().map(ToString::to_string));
-            }
 
+    extend_vec_if_indicator_present(&mut conf.doc_valid_idents, DEFAULT_DOC_VALID_IDENTS);
+    extend_vec_if_indicator_present(&mut conf.disallowed_names, DEFAULT_DISALLOWED_NAMES);
+    // TODO: THIS SHOULD BE TESTED, this comment will be gone soon
+    if conf.allowed_idents_below_min_chars.contains(&"..".to_owned()) {
+        conf.allowed_idents_below_min_chars
+            .extend(DEFAULT_ALLOWED_IDENTS_BELOW_MIN_CHARS.iter().map(ToString::to_string));
+    }
+
+    TryConf { conf, errors, warnings }
+}
+
+fn read_file(file: &SourceFile) -> TryConf {
+    match toml::de::Deserializer::new(file.src.as_ref().unwrap()).deserialize_map(ConfVisitor(file)) {
+        Ok(mut conf) => {
+            check_lint_levels(file, &mut conf);
             conf
         },
-        Err(e) => TryConf::from_toml_error(&file, &e),
+        Err(e) => TryConf::from_toml_error(file, &e),
+    }
+}
+
+/// Merges the keys of a nearer configuration file into the ones of a farther file. Tables are
+/// merged key by key, and a `".."` in a list of the nearer file is replaced by the farther list.
+fn merge_tables(mut farther: toml::Table, nearer: toml::Table) -> toml::Table {
+    fn is_extend_marker(value: &toml::Value) -> bool {
+        value.as_str() == Some("..")
+    }
+
+    for (key, value) in nearer {
+        let value = match (farther.remove(&key), value) {
+            (Some(toml::Value::Table(farther)), toml::Value::Table(nearer)) => {
+                toml::Value::Table(merge_tables(farther, nearer))
+            },
+            (Some(toml::Value::Array(farther)), toml::Value::Array(nearer)) if nearer.iter().any(is_extend_marker) => {
+                toml::Value::Array(
+                    nearer
+                        .into_iter()
+                        .flat_map(|value| {
+                            if is_extend_marker(&value) {
+                                farther.clone()
+                            } else {
+                                vec![value]
+                            }
+                        })
+                        .collect(),
+                )
+            },
+            (_, value) => value,
+        };
+        farther.insert(key, value);
+    }
+    farther
+}
+
+/// Reads the `[lints]` tables of the configuration files, nearest first, ignoring unknown lint
+/// names. Lint groups come first so that the levels of single lints take precedence.
+///
+/// This doesn't need a `Session`, errors are reported by [`read`].
+pub fn read_lint_levels(paths: &[PathBuf]) -> Vec<(String, LintLevel)> {
+    let mut lints = BTreeMap::new();
+    // Farther files first, so that the nearer ones override them
+    for path in paths.iter().rev() {
+        if let Some(LintsTable { lints: file_lints }) =
+            fs::read_to_string(path).ok().and_then(|src| toml::from_str(&src).ok())
+        {
+            lints.extend(file_lints.into_iter().map(|(name, level)| (name.into_inner(), level)));
+        }
+    }
+    let mut levels: Vec<_> = lints.into_iter().filter(|(name, _)| is_known_lint(name)).collect();
+    levels.sort_by_key(|(name, _)| !LINT_GROUPS.contains(&name.as_str()));
+    levels
+}
+
+/// Warns about unknown lint names in the `[lints]` table.
+fn check_lint_levels(file: &SourceFile, conf: &mut TryConf) {
+    let lints = match toml::from_str::<LintsTable>(file.src.as_ref().unwrap()) {
+        Ok(table) => table.lints,
+        Err(e) => {
+            conf.errors.push(ConfError::from_toml(file, &e));
+            return;
+        },
+    };
+    let candidates: Vec<Symbol> = LINT_GROUPS
+        .iter()
+        .map(|group| Symbol::intern(group))
+        .chain(
+            crate::declared_lints::LINTS
+                .iter()
+                .map(|info| Symbol::intern(&info.lint.name_lower()["clippy::".len()..])),
+        )
+        .collect();
+    for name in lints.keys().filter(|name| !is_known_lint(name.get_ref())) {
+        let mut message = format!("unknown lint `{}` in the `lints` table", name.get_ref());
+        if let Some(suggestion) = find_best_match_for_name(&candidates, Symbol::intern(name.get_ref()), None) {
+            message.push_str(&format!(", did you mean `{suggestion}`?"));
+        }
+        conf.warnings.push(ConfError::spanned(file, message, name.span()));
     }
 }
 
+fn is_known_lint(name: &str) -> bool {
+    LINT_GROUPS.contains(&name)
+        || crate::declared_lints::LINTS
+            .iter()
+            .any(|info| info.lint.name_lower().strip_prefix("clippy::") == Some(name))
+}
+
 fn extend_vec_if_indicator_present(vec: &mut Vec<String>, default: &[&str]) {
     if vec.contains(&"..".to_string()) {
         vec.extend(default.iter().map(ToString::to_string));
@@ -640,19 +924,25 @@ fn extend_vec_if_indicator_present(vec: &mut Vec<String>, default: &[&str]) {
 const SEPARATOR_WIDTH: usize = 4;
 
 #[derive(Debug)]
-struct FieldError(String);
+struct FieldError {
+    error: String,
+    suggestion: Option<Suggestion>,
+}
 
 impl std::error::Error for FieldError {}
 
 impl Display for FieldError {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
-        f.pad(&self.0)
+        f.pad(&self.error)
     }
 }
 
 impl serde::de::Error for FieldError {
     fn custom<T: Display>(msg: T) -> Self {
-        Self(msg.to_string())
+        Self {
+            error: msg.to_string(),
+            suggestion: None,
+        }
     }
 
     fn unknown_field(field: &str, expected: &'static [&'static str]) -> Self {
@@ -674,7 +964,16 @@ fn unknown_field(field: &str, expected: &'static [&'static str]) -> Self {
                 write!(msg, "{:SEPARATOR_WIDTH$}{field:column_width$}", " ").unwrap();
             }
         }
-        Self(msg)
+
+        let candidates: Vec<Symbol> = expected.iter().map(|field| Symbol::intern(field)).collect();
+        let suggestion = find_best_match_for_name(&candidates, Symbol::intern(field), None)
+            .and_then(|best| expected.iter().find(|field| **field == best.as_str()))
+            .map(|&suggestion| Suggestion {
+                message: "perhaps you meant",
+                suggestion,
+            });
+
+        Self { error: msg, suggestion }
     }
 }
 
@@ -68,15 +68,20 @@
 /// This prefix is in front of the lint groups in the lint store. The prefix will be trimmed
 /// to only keep the actual lint group in the output.
 const CLIPPY_LINT_GROUP_PREFIX: &str = "clippy::";
-const LINT_EMISSION_FUNCTIONS: [&[&str]; 7] = [
+const LINT_EMISSION_FUNCTIONS: [&[&str]; 8] = [
     &["clippy_utils", "diagnostics", "span_lint"],
     &["clippy_utils", "diagnostics", "span_lint_and_help"],
     &["clippy_utils", "diagnostics", "span_lint_and_note"],
     &["clippy_utils", "diagnostics", "span_lint_hir"],
     &["clippy_utils", "diagnostics", "span_lint_and_sugg"],
+    &["clippy_utils", "diagnostics", "span_lint_and_multipart_sugg"],
     &["clippy_utils", "diagnostics", "span_lint_and_then"],
     &["clippy_utils", "diagnostics", "span_lint_hir_and_then"],
 ];
+/// Lint emission functions which always emit a multipart suggestion.
+const MULTI_PART_LINT_EMISSION_FUNCTIONS: [&[&str]; 1] = [
+    &["clippy_utils", "diagnostics", "span_lint_and_multipart_sugg"],
+];
 const SUGGESTION_DIAGNOSTIC_BUILDER_METHODS: [(&str, bool); 9] = [
     ("span_suggestion", false),
     ("span_suggestion_short", false),
@@ -292,6 +297,9 @@ struct LintMetadata {
     /// All the past names of lints which have been renamed.
     #[serde(skip_serializing_if = "BTreeSet::is_empty")]
     former_ids: BTreeSet<String>,
+    /// The `clippy.toml` keys which affect the lint.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    configuration: Vec<String>,
 }
 
 impl LintMetadata {
@@ -312,6 +320,7 @@ fn new(
             docs,
             applicability: None,
             former_ids: BTreeSet::new(),
+            configuration: Vec::new(),
         }
     }
 }
@@ -586,15 +595,23 @@ fn check_item(&mut self, cx: &LateContext<'hir>, item: &'hir Item<'_>) {
                         raw_docs.push_str(&configuration_section);
                     }
                     let version = get_lint_version(cx, item);
-
-                    self.lints.push(LintMetadata::new(
+                    let configuration = self
+                        .config
+                        .iter()
+                        .filter(|config| config.lints.iter().any(|lint| *lint == lint_name))
+                        .map(|config| config.name.clone())
+                        .collect();
+
+                    let mut lint = LintMetadata::new(
                         lint_name,
                         SerializableSpan::from_item(cx, item),
                         group,
                         level,
                         version,
                         raw_docs,
-                    ));
+                    );
+                    lint.configuration = configuration;
+                    self.lints.push(lint);
                 }
             }
 
@@ -647,10 +664,13 @@ fn check_expr(&mut self, cx: &LateContext<'hir>, expr: &'hir hir::Expr<'_>) {
                 return;
             }
 
+            let is_multi_part_emission = MULTI_PART_LINT_EMISSION_FUNCTIONS
+                .iter()
+                .any(|emission_fn| match_function_call(cx, expr, emission_fn).is_some());
             for (lint_name, applicability, is_multi_part) in emission_info {
                 let app_info = self.applicability_info.entry(lint_name).or_default();
                 app_info.applicability = applicability;
-                app_info.is_multi_part_suggestion = is_multi_part;
+                app_info.is_multi_part_suggestion = is_multi_part || is_multi_part_emission;
             }
         }
     }
@@ -0,0 +1,136 @@
+use clippy_utils::consts::{constant, FullInt};
+use clippy_utils::diagnostics::span_lint_and_help;
+use clippy_utils::source::snippet_with_context;
+use rustc_errors::Applicability;
+use rustc_hir::{BinOpKind, Expr, ExprKind};
+use rustc_lint::{LateContext, LateLintPass, LintContext};
+use rustc_middle::lint::in_external_macro;
+use rustc_middle::ty::layout::IntegerExt;
+use rustc_middle::ty::{self, Ty};
+use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_target::abi::Integer;
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for arithmetic performed in a wider integer type whose result is clamped to the
+    /// range of the original type and cast back to it.
+    ///
+    /// ### Why is this bad?
+    /// This is a roundabout way of writing a saturating operation, which the integer types
+    /// provide directly.
+    ///
+    /// ### Example
+    /// ```rust
+    /// # let (x, y) = (1i32, 2i32);
+    /// let sum = (x as i64 + y as i64).clamp(i32::MIN as i64, i32::MAX as i64) as i32;
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # let (x, y) = (1i32, 2i32);
+    /// let sum = x.saturating_add(y);
+    /// ```
+    #[clippy::version = "1.73.0"]
+    pub MANUAL_SATURATING_ARITHMETIC_ROUNDTRIP,
+    nursery,
+    "widening integers, computing and clamping the result instead of using saturating arithmetic"
+}
+declare_lint_pass!(WidenedSaturating => [MANUAL_SATURATING_ARITHMETIC_ROUNDTRIP]);
+
+impl<'tcx> LateLintPass<'tcx> for WidenedSaturating {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
+        let typeck = cx.typeck_results();
+        if let ExprKind::Cast(clamped, _) = expr.kind
+            && !in_external_macro(cx.sess(), expr.span)
+            && let ExprKind::MethodCall(path, computed, [low, high], _) = clamped.kind
+            && path.ident.as_str() == "clamp"
+            && let ExprKind::Binary(op, lhs, rhs) = computed.kind
+            && let ExprKind::Cast(lhs, _) = lhs.kind
+            && let ExprKind::Cast(rhs, _) = rhs.kind
+            // Both operands have to start out as the type the result is narrowed to.
+            && typeck.expr_ty(lhs) == typeck.expr_ty(expr)
+            && typeck.expr_ty(rhs) == typeck.expr_ty(expr)
+            && let Some(narrow_range) = IntRange::of(cx, typeck.expr_ty(expr))
+            && let Some(wide_range) = IntRange::of(cx, typeck.expr_ty(computed))
+            && let Some(method) = saturating_method(op.node, narrow_range, wide_range)
+            // The clamp has to saturate to exactly the range of the narrow type.
+            && clamp_bound(cx, low) == Some(narrow_range.min)
+            && clamp_bound(cx, high) == Some(narrow_range.max)
+        {
+            let ctxt = expr.span.ctxt();
+            let mut app = Applicability::MachineApplicable;
+            let lhs = snippet_with_context(cx, lhs.span, ctxt, "..", &mut app).0;
+            let rhs = snippet_with_context(cx, rhs.span, ctxt, "..", &mut app).0;
+            span_lint_and_help(
+                cx,
+                MANUAL_SATURATING_ARITHMETIC_ROUNDTRIP,
+                expr.span,
+                "manual saturating arithmetic through a wider integer type",
+                None,
+                &format!("use `{lhs}.{method}({rhs})` instead"),
+            );
+        }
+    }
+}
+
+#[derive(Clone, Copy)]
+struct IntRange {
+    min: FullInt,
+    max: FullInt,
+    bits: u64,
+    signed: bool,
+}
+
+impl IntRange {
+    fn of(cx: &LateContext<'_>, ty: Ty<'_>) -> Option<Self> {
+        match *ty.kind() {
+            ty::Int(ity) => {
+                let bits = Integer::from_int_ty(&cx.tcx, ity).size().bits();
+                Some(Self {
+                    min: FullInt::S(i128::MIN >> (128 - bits)),
+                    max: FullInt::S(i128::MAX >> (128 - bits)),
+                    bits,
+                    signed: true,
+                })
+            },
+            ty::Uint(uty) => {
+                let bits = Integer::from_uint_ty(&cx.tcx, uty).size().bits();
+                Some(Self {
+                    min: FullInt::U(0),
+                    max: FullInt::U(u128::MAX >> (128 - bits)),
+                    bits,
+                    signed: false,
+                })
+            },
+            _ => None,
+        }
+    }
+}
+
+/// Returns the saturating method equivalent to computing `op` in `wide`, if `wide` is large
+/// enough for the computation to never overflow.
+fn saturating_method(op: BinOpKind, narrow: IntRange, wide: IntRange) -> Option<&'static str> {
+    // Widening a signed type into an unsigned one loses the negative values.
+    if narrow.signed && !wide.signed {
+        return None;
+    }
+    // Bits needed to hold any value of the narrow type in the wide type.
+    let value_bits = narrow.bits + u64::from(wide.signed && !narrow.signed);
+    match op {
+        BinOpKind::Add if wide.bits > value_bits => Some("saturating_add"),
+        // Subtracting unsigned values can go below zero.
+        BinOpKind::Sub if wide.bits > value_bits && (narrow.signed || wide.signed) => Some("saturating_sub"),
+        BinOpKind::Mul if wide.bits >= 2 * value_bits => Some("saturating_mul"),
+        _ => None,
+    }
+}
+
+/// Evaluates a bound of the `clamp` call, looking through a cast to the wide type.
+fn clamp_bound(cx: &LateContext<'_>, bound: &Expr<'_>) -> Option<FullInt> {
+    let bound = if let ExprKind::Cast(inner, _) = bound.kind {
+        inner
+    } else {
+        bound
+    };
+    let typeck = cx.typeck_results();
+    constant(cx, typeck, bound)?.int_value(cx, typeck.expr_ty(bound))
+}
@@ -1,22 +1,29 @@
 #![allow(clippy::float_cmp)]
 
 use crate::source::{get_source_text, walk_span_to_context};
+use crate::visitors::for_each_expr;
 use crate::{clip, is_direct_expn_of, sext, unsext};
-use if_chain::if_chain;
 use rustc_ast::ast::{self, LitFloatType, LitKind};
 use rustc_data_structures::sync::Lrc;
-use rustc_hir::def::{DefKind, Res};
-use rustc_hir::{BinOp, BinOpKind, Block, ConstBlock, Expr, ExprKind, HirId, Item, ItemKind, Node, QPath, UnOp};
+use rustc_hir::def::{CtorKind, CtorOf, DefKind, Res};
+use rustc_hir::def_id::DefId;
+use rustc_hir::{
+    BinOp, BinOpKind, Block, Body, ConstBlock, Expr, ExprKind, HirId, HirIdMap, Item, ItemKind, Node, PatKind, QPath,
+    UnOp,
+};
 use rustc_lexer::tokenize;
 use rustc_lint::LateContext;
 use rustc_middle::mir::interpret::Scalar;
+use rustc_middle::ty::layout::IntegerExt;
 use rustc_middle::ty::{self, EarlyBinder, FloatTy, GenericArgsRef, List, ScalarInt, Ty, TyCtxt};
 use rustc_middle::{bug, mir, span_bug};
 use rustc_span::symbol::{Ident, Symbol};
 use rustc_span::SyntaxContext;
+use rustc_target::abi::Integer;
 use std::cmp::Ordering::{self, Equal};
 use std::hash::{Hash, Hasher};
 use std::iter;
+use std::ops::ControlFlow;
 
 /// A `LitKind`-like enum to fold constant `Expr`s into.
 #[derive(Debug, Clone)]
@@ -320,12 +327,20 @@ fn cmp_s_u(s: i128, u: u128) -> Ordering {
     }
 }
 
+/// The maximum number of `const fn` calls evaluated for a single expression. This keeps deeply
+/// recursive or non-terminating `const fn`s from hanging the evaluator.
+const CONST_FN_CALL_BUDGET: u32 = 64;
+
 pub struct ConstEvalLateContext<'a, 'tcx> {
     lcx: &'a LateContext<'tcx>,
     typeck_results: &'a ty::TypeckResults<'tcx>,
     param_env: ty::ParamEnv<'tcx>,
     source: ConstantSource,
     args: GenericArgsRef<'tcx>,
+    /// The values of the parameters when evaluating the body of a `const fn`.
+    locals: HirIdMap<Constant<'tcx>>,
+    /// The number of `const fn` calls which may still be evaluated.
+    call_budget: u32,
 }
 
 impl<'a, 'tcx> ConstEvalLateContext<'a, 'tcx> {
@@ -336,6 +351,8 @@ fn new(lcx: &'a LateContext<'tcx>, typeck_results: &'a ty::TypeckResults<'tcx>)
             param_env: lcx.param_env,
             source: ConstantSource::Local,
             args: List::empty(),
+            locals: HirIdMap::default(),
+            call_budget: CONST_FN_CALL_BUDGET,
         }
     }
 
@@ -370,30 +387,19 @@ pub fn expr(&mut self, e: &Expr<'_>) -> Option<Constant<'tcx>> {
             ExprKind::If(cond, then, ref otherwise) => self.ifthenelse(cond, then, *otherwise),
             ExprKind::Binary(op, left, right) => self.binop(op, left, right),
             ExprKind::Call(callee, args) => {
-                // We only handle a few const functions for now.
-                if_chain! {
-                    if args.is_empty();
-                    if let ExprKind::Path(qpath) = &callee.kind;
-                    let res = self.typeck_results.qpath_res(qpath, callee.hir_id);
-                    if let Some(def_id) = res.opt_def_id();
-                    let def_path = self.lcx.get_def_path(def_id);
-                    let def_path: Vec<&str> = def_path.iter().take(4).map(Symbol::as_str).collect();
-                    if let ["core", "num", int_impl, "max_value"] = *def_path;
-                    then {
-                        let value = match int_impl {
-                            "<impl i8>" => i8::MAX as u128,
-                            "<impl i16>" => i16::MAX as u128,
-                            "<impl i32>" => i32::MAX as u128,
-                            "<impl i64>" => i64::MAX as u128,
-                            "<impl i128>" => i128::MAX as u128,
-                            _ => return None,
-                        };
-                        Some(Constant::Int(value))
-                    } else {
-                        None
-                    }
+                let ExprKind::Path(qpath) = &callee.kind else {
+                    return None;
+                };
+                let def_id = self.typeck_results.qpath_res(qpath, callee.hir_id).opt_def_id()?;
+                if args.is_empty()
+                    && let Some(value) = self.int_limit(def_id, self.typeck_results.expr_ty(e))
+                {
+                    Some(value)
+                } else {
+                    self.const_fn_call(def_id, callee.hir_id, args)
                 }
             },
+            ExprKind::Cast(operand, _) => self.discriminant_cast(operand, self.typeck_results.expr_ty(e)),
             ExprKind::Index(arr, index) => self.index(arr, index),
             ExprKind::AddrOf(_, _, inner) => self.expr(inner).map(|r| Constant::Ref(Box::new(r))),
             ExprKind::Field(local_expr, ref field) => {
@@ -453,10 +459,95 @@ fn multi(&mut self, vec: &[Expr<'_>]) -> Option<Vec<Constant<'tcx>>> {
         vec.iter().map(|elem| self.expr(elem)).collect::<Option<_>>()
     }
 
+    /// Evaluates the `max_value` and `min_value` functions of the integer types.
+    fn int_limit(&self, def_id: DefId, ty: Ty<'tcx>) -> Option<Constant<'tcx>> {
+        let def_path = self.lcx.get_def_path(def_id);
+        let def_path: Vec<&str> = def_path.iter().take(4).map(Symbol::as_str).collect();
+        let ["core", "num", _, name] = *def_path else {
+            return None;
+        };
+        let tcx = self.lcx.tcx;
+        let value = match (*ty.kind(), name) {
+            (ty::Int(ity), "max_value") => unsext(tcx, Integer::from_int_ty(&tcx, ity).size().signed_int_max(), ity),
+            (ty::Int(ity), "min_value") => unsext(tcx, Integer::from_int_ty(&tcx, ity).size().signed_int_min(), ity),
+            (ty::Uint(uty), "max_value") => Integer::from_uint_ty(&tcx, uty).size().unsigned_int_max(),
+            (ty::Uint(_), "min_value") => 0,
+            _ => return None,
+        };
+        Some(Constant::Int(value))
+    }
+
+    /// Evaluates a call to a local, non-generic `const fn` by evaluating its body with the
+    /// arguments bound to its parameters. All arguments have to be constant.
+    fn const_fn_call(&mut self, def_id: DefId, callee_id: HirId, args: &[Expr<'_>]) -> Option<Constant<'tcx>> {
+        let tcx = self.lcx.tcx;
+        if self.call_budget == 0 || !tcx.is_const_fn(def_id) || !self.typeck_results.node_args(callee_id).is_empty() {
+            return None;
+        }
+        let body_id = tcx.hir().maybe_body_owned_by(def_id.as_local()?)?;
+        let body = tcx.hir().body(body_id);
+        if is_target_dependent(body) {
+            return None;
+        }
+        let args = self.multi(args)?;
+        let mut locals = HirIdMap::default();
+        for (param, arg) in iter::zip(body.params, args) {
+            let PatKind::Binding(_, id, _, None) = param.pat.kind else {
+                return None;
+            };
+            locals.insert(id, arg);
+        }
+
+        let mut callee = ConstEvalLateContext {
+            lcx: self.lcx,
+            typeck_results: tcx.typeck_body(body_id),
+            param_env: tcx.param_env(def_id),
+            source: ConstantSource::Local,
+            args: List::empty(),
+            locals,
+            call_budget: self.call_budget - 1,
+        };
+        let result = callee.expr(body.value);
+        self.call_budget = callee.call_budget;
+        self.source = ConstantSource::Constant;
+        result
+    }
+
+    /// Evaluates the cast of a unit variant to an integer, e.g. `Enum::A as u8`.
+    #[expect(clippy::cast_possible_wrap, clippy::cast_sign_loss)]
+    fn discriminant_cast(&mut self, operand: &Expr<'_>, ty: Ty<'tcx>) -> Option<Constant<'tcx>> {
+        let ExprKind::Path(ref qpath) = operand.kind else {
+            return None;
+        };
+        let Res::Def(DefKind::Ctor(CtorOf::Variant, CtorKind::Const), ctor_id) =
+            self.typeck_results.qpath_res(qpath, operand.hir_id)
+        else {
+            return None;
+        };
+        let ty::Adt(adt_def, _) = *self.typeck_results.expr_ty(operand).kind() else {
+            return None;
+        };
+        let tcx = self.lcx.tcx;
+        let discr = adt_def.discriminant_for_variant(tcx, adt_def.variant_index_with_ctor_id(ctor_id));
+        // sign extend the discriminant so that it is truncated correctly
+        let bits = match *discr.ty.kind() {
+            ty::Int(ity) => sext(tcx, discr.val, ity) as u128,
+            _ => discr.val,
+        };
+        let value = match *ty.kind() {
+            ty::Int(ity) => unsext(tcx, bits as i128, ity),
+            ty::Uint(uty) => clip(tcx, bits, uty),
+            _ => return None,
+        };
+        self.source = ConstantSource::Constant;
+        Some(Constant::Int(value))
+    }
+
     /// Lookup a possibly constant expression from an `ExprKind::Path`.
     fn fetch_path(&mut self, qpath: &QPath<'_>, id: HirId, ty: Ty<'tcx>) -> Option<Constant<'tcx>> {
         let res = self.typeck_results.qpath_res(qpath, id);
         match res {
+            Res::Local(id) => self.locals.get(&id).cloned(),
             Res::Def(DefKind::Const | DefKind::AssocConst, def_id) => {
                 // Check if this constant is based on `cfg!(..)`,
                 // which is NOT constant for our purposes.
@@ -655,6 +746,22 @@ fn binop(&mut self, op: BinOp, left: &Expr<'_>, right: &Expr<'_>) -> Option<Cons
     }
 }
 
+/// Whether the body of a `const fn` uses a macro whose expansion depends on the target or the
+/// build environment, like `cfg!(unix)`. Its value isn't constant for our purposes.
+fn is_target_dependent(body: &Body<'_>) -> bool {
+    for_each_expr(body.value, |e| {
+        if ["cfg", "env", "option_env"]
+            .into_iter()
+            .any(|name| is_direct_expn_of(e.span, name).is_some())
+        {
+            ControlFlow::Break(())
+        } else {
+            ControlFlow::Continue(())
+        }
+    })
+    .is_some()
+}
+
 pub fn miri_to_const<'tcx>(lcx: &LateContext<'tcx>, result: mir::ConstantKind<'tcx>) -> Option<Constant<'tcx>> {
     use rustc_middle::mir::interpret::ConstValue;
     match result {
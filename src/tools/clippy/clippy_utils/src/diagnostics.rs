@@ -8,11 +8,25 @@
 //! Thank you!
 //! ~The `INTERNAL_METADATA_COLLECTOR` lint
 
+use rustc_data_structures::fx::FxHashMap;
 use rustc_errors::{Applicability, Diagnostic, MultiSpan};
 use rustc_hir::HirId;
 use rustc_lint::{LateContext, Lint, LintContext};
 use rustc_span::source_map::Span;
 use std::env;
+use std::sync::OnceLock;
+
+/// The group of every Clippy lint, keyed by the lint's name.
+static LINT_GROUPS: OnceLock<FxHashMap<&'static str, &'static str>> = OnceLock::new();
+
+/// Records the group of every Clippy lint, e.g. `"style"`, keyed by the lint's name. The group is
+/// then noted on each emitted diagnostic.
+///
+/// Used by `clippy_lints` when registering the lints.
+pub fn set_lint_groups(groups: FxHashMap<&'static str, &'static str>) {
+    // The lints are registered once per compiler session, the groups don't change in between
+    let _ = LINT_GROUPS.set(groups);
+}
 
 fn docs_link(diag: &mut Diagnostic, lint: &'static Lint) {
     if env::var("CLIPPY_DISABLE_DOCS_LINKS").is_err() {
@@ -26,6 +40,22 @@ fn docs_link(diag: &mut Diagnostic, lint: &'static Lint) {
             ));
         }
     }
+    // Lets tools consuming the JSON output tell e.g. `correctness` and `style` lints apart
+    if let Some(group) = LINT_GROUPS.get().and_then(|groups| groups.get(lint.name)) {
+        diag.note(format!("clippy::group = {group}"));
+    }
+}
+
+/// Marks suggestions which don't state their applicability as `MaybeIncorrect`, so that tools
+/// consuming the JSON output can rely on it being populated.
+fn populate_applicability(diag: &mut Diagnostic) {
+    if let Ok(suggestions) = &mut diag.suggestions {
+        for sugg in suggestions {
+            if sugg.applicability == Applicability::Unspecified {
+                sugg.applicability = Applicability::MaybeIncorrect;
+            }
+        }
+    }
 }
 
 /// Emit a basic lint message with a `msg` and a `span`.
@@ -147,6 +177,7 @@ pub fn span_lint_and_then<C, S, F>(cx: &C, lint: &'static Lint, sp: S, msg: &str
 {
     cx.struct_span_lint(lint, sp, msg.to_string(), |diag| {
         f(diag);
+        populate_applicability(diag);
         docs_link(diag, lint);
         diag
     });
@@ -169,6 +200,7 @@ pub fn span_lint_hir_and_then(
 ) {
     cx.tcx.struct_span_lint_hir(lint, hir_id, sp, msg.to_string(), |diag| {
         f(diag);
+        populate_applicability(diag);
         docs_link(diag, lint);
         diag
     });
@@ -208,6 +240,42 @@ pub fn span_lint_and_sugg<T: LintContext>(
     });
 }
 
+/// Like `span_lint_and_sugg`, but the suggestion is made of several `span → replacement` edits.
+///
+/// rustfix applies the edits of a multipart suggestion as one unit: either all of them are
+/// applied or none are, so use this when the edits only make sense together (e.g. moving an
+/// argument from one call to another). Emitting them as separate suggestions instead lets rustfix
+/// apply each one independently, which can leave the code half rewritten.
+///
+/// # Example
+///
+/// ```text
+/// error: called `map(<f>).unwrap_or(<a>)` on an `Option` value
+///   --> $DIR/map_unwrap_or.rs:17:13
+///    |
+/// LL |     let _ = opt.map(|x| x + 1).unwrap_or(0);
+///    |             ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^
+///    |
+/// help: use `map_or(<a>, <f>)` instead
+///    |
+/// LL -     let _ = opt.map(|x| x + 1).unwrap_or(0);
+/// LL +     let _ = opt.map_or(0, |x| x + 1);
+///    |
+/// ```
+pub fn span_lint_and_multipart_sugg<T: LintContext>(
+    cx: &T,
+    lint: &'static Lint,
+    sp: impl Into<MultiSpan>,
+    msg: &str,
+    help: &str,
+    sugg: Vec<(Span, String)>,
+    applicability: Applicability,
+) {
+    span_lint_and_then(cx, lint, sp, msg, |diag| {
+        diag.multipart_suggestion(help.to_string(), sugg, applicability);
+    });
+}
+
 /// Create a suggestion made from several `span → replacement`.
 ///
 /// Note: in the JSON format (used by `compiletest_rs`), the help message will
@@ -223,9 +291,8 @@ pub fn multispan_sugg<I>(diag: &mut Diagnostic, help_msg: &str, sugg: I)
 
 /// Create a suggestion made from several `span → replacement`.
 ///
-/// rustfix currently doesn't support the automatic application of suggestions with
-/// multiple spans. This is tracked in issue [rustfix#141](https://github.com/rust-lang/rustfix/issues/141).
-/// Suggestions with multiple spans will be silently ignored.
+/// rustfix applies all the replacements together. Use `span_lint_and_multipart_sugg` if this is
+/// the only suggestion of the lint.
 pub fn multispan_sugg_with_applicability<I>(
     diag: &mut Diagnostic,
     help_msg: &str,
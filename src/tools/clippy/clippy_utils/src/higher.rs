@@ -6,9 +6,9 @@
 use crate::ty::is_type_diagnostic_item;
 use crate::{is_expn_of, match_def_path, paths};
 use if_chain::if_chain;
-use rustc_ast::ast;
+use rustc_ast::{ast, LitKind};
 use rustc_hir as hir;
-use rustc_hir::{Arm, Block, Expr, ExprKind, HirId, LoopSource, MatchSource, Node, Pat, QPath};
+use rustc_hir::{Arm, ArrayLen, Block, Expr, ExprKind, HirId, LoopSource, MatchSource, Node, Pat, QPath};
 use rustc_lint::LateContext;
 use rustc_span::{sym, symbol, Span};
 
@@ -305,6 +305,29 @@ pub fn hir(cx: &LateContext<'_>, expr: &'a hir::Expr<'_>) -> Option<VecArgs<'a>>
     }
 }
 
+/// An array repeat expression with a literal length: `[value; 3]`.
+pub struct ArrayRepeat<'hir> {
+    /// The repeated value
+    pub value: &'hir Expr<'hir>,
+    /// The length of the array
+    pub len: u128,
+}
+
+impl<'hir> ArrayRepeat<'hir> {
+    /// Parses an array repeat expression whose length is an integer literal. Lengths given by
+    /// constants or generic parameters aren't resolved.
+    pub fn hir(cx: &LateContext<'_>, expr: &Expr<'hir>) -> Option<Self> {
+        if let ExprKind::Repeat(value, ArrayLen::Body(len)) = expr.kind
+            && let ExprKind::Lit(lit) = cx.tcx.hir().body(len.body).value.kind
+            && let LitKind::Int(len, _) = lit.node
+        {
+            Some(Self { value, len })
+        } else {
+            None
+        }
+    }
+}
+
 /// A desugared `while` loop
 pub struct While<'hir> {
     /// `while` loop condition
@@ -1,14 +1,14 @@
-use crate::consts::constant_simple;
+use crate::consts::{constant, constant_simple};
 use crate::macros::macro_backtrace;
 use crate::source::{get_source_text, snippet_opt, walk_span_to_context, SpanRange};
 use crate::tokenize_with_text;
-use rustc_ast::ast::InlineAsmTemplatePiece;
+use rustc_ast::ast::{InlineAsmTemplatePiece, Label};
 use rustc_data_structures::fx::FxHasher;
 use rustc_hir::def::Res;
 use rustc_hir::{
-    ArrayLen, BinOpKind, BindingAnnotation, Block, BodyId, Closure, Expr, ExprField, ExprKind, FnRetTy, GenericArg,
-    GenericArgs, Guard, HirId, HirIdMap, InlineAsmOperand, Let, Lifetime, LifetimeName, Pat, PatField, PatKind, Path,
-    PathSegment, PrimTy, QPath, Stmt, StmtKind, Ty, TyKind, TypeBinding,
+    ArrayLen, BinOpKind, BindingAnnotation, Block, BodyId, Closure, Destination, Expr, ExprField, ExprKind, FnRetTy,
+    GenericArg, GenericArgs, Guard, HirId, HirIdMap, InlineAsmOperand, Let, Lifetime, LifetimeName, Pat, PatField,
+    PatKind, Path, PathSegment, PrimTy, QPath, Stmt, StmtKind, Ty, TyKind, TypeBinding,
 };
 use rustc_lexer::{tokenize, TokenKind};
 use rustc_lint::LateContext;
@@ -31,6 +31,7 @@ pub struct SpanlessEq<'a, 'tcx> {
     cx: &'a LateContext<'tcx>,
     maybe_typeck_results: Option<(&'tcx TypeckResults<'tcx>, &'tcx TypeckResults<'tcx>)>,
     allow_side_effects: bool,
+    eval_consts: bool,
     expr_fallback: Option<Box<SpanlessEqCallback<'a>>>,
 }
 
@@ -40,6 +41,7 @@ pub fn new(cx: &'a LateContext<'tcx>) -> Self {
             cx,
             maybe_typeck_results: cx.maybe_typeck_results().map(|x| (x, x)),
             allow_side_effects: true,
+            eval_consts: false,
             expr_fallback: None,
         }
     }
@@ -53,6 +55,13 @@ pub fn deny_side_effects(self) -> Self {
         }
     }
 
+    /// Consider expressions which evaluate to the same constant as equal, even when they refer to
+    /// `const` items, e.g. `N` and `5` given `const N: u32 = 5`.
+    #[must_use]
+    pub fn eval_consts(self, eval_consts: bool) -> Self {
+        Self { eval_consts, ..self }
+    }
+
     #[must_use]
     pub fn expr_fallback(self, expr_fallback: impl FnMut(&Expr<'_>, &Expr<'_>) -> bool + 'a) -> Self {
         Self {
@@ -100,7 +109,8 @@ pub struct HirEqInterExpr<'a, 'b, 'tcx> {
 
     // When binding are declared, the binding ID in the left expression is mapped to the one on the
     // right. For example, when comparing `{ let x = 1; x + 2 }` and `{ let y = 1; y + 2 }`,
-    // these blocks are considered equal since `x` is mapped to `y`.
+    // these blocks are considered equal since `x` is mapped to `y`. Labelled loops and blocks are
+    // mapped the same way, so `'a: loop { break 'a; }` and `'b: loop { break 'b; }` are equal.
     pub locals: HirIdMap<HirId>,
 }
 
@@ -260,6 +270,15 @@ pub fn eq_expr(&mut self, left: &Expr<'_>, right: &Expr<'_>) -> bool {
                 if l == r {
                     return true;
                 }
+            } else if self.inner.eval_consts
+                && let (Some(l), Some(r)) = (
+                    constant(self.inner.cx, typeck_lhs, left),
+                    constant(self.inner.cx, typeck_rhs, right),
+                )
+                && l == r
+                && typeck_lhs.expr_ty(left) == typeck_rhs.expr_ty(right)
+            {
+                return true;
             }
         }
 
@@ -270,16 +289,16 @@ pub fn eq_expr(&mut self, left: &Expr<'_>, right: &Expr<'_>) -> bool {
             (&ExprKind::AddrOf(lb, l_mut, le), &ExprKind::AddrOf(rb, r_mut, re)) => {
                 lb == rb && l_mut == r_mut && self.eq_expr(le, re)
             },
-            (&ExprKind::Continue(li), &ExprKind::Continue(ri)) => {
-                both(&li.label, &ri.label, |l, r| l.ident.name == r.ident.name)
-            },
+            (&ExprKind::Continue(li), &ExprKind::Continue(ri)) => self.eq_destination(li, ri),
             (&ExprKind::Assign(ll, lr, _), &ExprKind::Assign(rl, rr, _)) => {
                 self.inner.allow_side_effects && self.eq_expr(ll, rl) && self.eq_expr(lr, rr)
             },
             (&ExprKind::AssignOp(ref lo, ll, lr), &ExprKind::AssignOp(ref ro, rl, rr)) => {
                 self.inner.allow_side_effects && lo.node == ro.node && self.eq_expr(ll, rl) && self.eq_expr(lr, rr)
             },
-            (&ExprKind::Block(l, _), &ExprKind::Block(r, _)) => self.eq_block(l, r),
+            (&ExprKind::Block(lb, ll), &ExprKind::Block(rb, rl)) => {
+                self.map_labels(left.hir_id, ll, right.hir_id, rl) && self.eq_block(lb, rb)
+            },
             (&ExprKind::Binary(l_op, ll, lr), &ExprKind::Binary(r_op, rl, rr)) => {
                 l_op.node == r_op.node && self.eq_expr(ll, rl) && self.eq_expr(lr, rr)
                     || swap_binop(l_op.node, ll, lr).map_or(false, |(l_op, ll, lr)| {
@@ -287,12 +306,18 @@ pub fn eq_expr(&mut self, left: &Expr<'_>, right: &Expr<'_>) -> bool {
                     })
             },
             (&ExprKind::Break(li, ref le), &ExprKind::Break(ri, ref re)) => {
-                both(&li.label, &ri.label, |l, r| l.ident.name == r.ident.name)
-                    && both(le, re, |l, r| self.eq_expr(l, r))
+                self.eq_destination(li, ri) && both(le, re, |l, r| self.eq_expr(l, r))
             },
             (&ExprKind::Call(l_fun, l_args), &ExprKind::Call(r_fun, r_args)) => {
                 self.inner.allow_side_effects && self.eq_expr(l_fun, r_fun) && self.eq_exprs(l_args, r_args)
             },
+            (&ExprKind::Closure(l), &ExprKind::Closure(r)) => {
+                l.capture_clause == r.capture_clause
+                    && l.movability == r.movability
+                    && over(l.fn_decl.inputs, r.fn_decl.inputs, |l, r| self.eq_ty(l, r))
+                    && self.eq_fn_ret_ty(&l.fn_decl.output, &r.fn_decl.output)
+                    && self.eq_closure_body(l.body, r.body)
+            },
             (&ExprKind::Cast(lx, lt), &ExprKind::Cast(rx, rt)) | (&ExprKind::Type(lx, lt), &ExprKind::Type(rx, rt)) => {
                 self.eq_expr(lx, rx) && self.eq_ty(lt, rt)
             },
@@ -307,8 +332,8 @@ pub fn eq_expr(&mut self, left: &Expr<'_>, right: &Expr<'_>) -> bool {
                 self.eq_pat(l.pat, r.pat) && both(&l.ty, &r.ty, |l, r| self.eq_ty(l, r)) && self.eq_expr(l.init, r.init)
             },
             (ExprKind::Lit(l), ExprKind::Lit(r)) => l.node == r.node,
-            (&ExprKind::Loop(lb, ref ll, ref lls, _), &ExprKind::Loop(rb, ref rl, ref rls, _)) => {
-                lls == rls && self.eq_block(lb, rb) && both(ll, rl, |l, r| l.ident.name == r.ident.name)
+            (&ExprKind::Loop(lb, ll, ref lls, _), &ExprKind::Loop(rb, rl, ref rls, _)) => {
+                lls == rls && self.map_labels(left.hir_id, ll, right.hir_id, rl) && self.eq_block(lb, rb)
             },
             (&ExprKind::Match(le, la, ref ls), &ExprKind::Match(re, ra, ref rs)) => {
                 ls == rs
@@ -351,6 +376,46 @@ pub fn eq_expr(&mut self, left: &Expr<'_>, right: &Expr<'_>) -> bool {
             || self.inner.expr_fallback.as_mut().map_or(false, |f| f(left, right))
     }
 
+    /// Checks whether two closure bodies are the same, mapping the parameters of the left closure
+    /// to those of the right one.
+    fn eq_closure_body(&mut self, left: BodyId, right: BodyId) -> bool {
+        // closures inherit TypeckResults
+        let left = self.inner.cx.tcx.hir().body(left);
+        let right = self.inner.cx.tcx.hir().body(right);
+        over(left.params, right.params, |l, r| self.eq_pat(l.pat, r.pat)) && self.eq_expr(left.value, right.value)
+    }
+
+    /// Checks whether two `break` or `continue` expressions jump to the same place. Labelled jumps
+    /// are equal if their targets are the same or were mapped to each other.
+    fn eq_destination(&mut self, left: Destination, right: Destination) -> bool {
+        match (left.label, right.label, left.target_id, right.target_id) {
+            (None, None, ..) => true,
+            (Some(_), Some(_), Ok(l), Ok(r)) => l == r || self.locals.get(&l) == Some(&r),
+            _ => false,
+        }
+    }
+
+    /// Checks that either both or neither of two loops or blocks are labelled. Labelled ones are
+    /// mapped to each other so that jumps to them can be compared.
+    fn map_labels(&mut self, left: HirId, left_label: Option<Label>, right: HirId, right_label: Option<Label>) -> bool {
+        match (left_label, right_label) {
+            (Some(_), Some(_)) => {
+                self.locals.insert(left, right);
+                true
+            },
+            (None, None) => true,
+            _ => false,
+        }
+    }
+
+    fn eq_fn_ret_ty(&mut self, left: &FnRetTy<'_>, right: &FnRetTy<'_>) -> bool {
+        match (left, right) {
+            (FnRetTy::DefaultReturn(_), FnRetTy::DefaultReturn(_)) => true,
+            (FnRetTy::Return(l), FnRetTy::Return(r)) => self.eq_ty(l, r),
+            _ => false,
+        }
+    }
+
     fn eq_exprs(&mut self, left: &[Expr<'_>], right: &[Expr<'_>]) -> bool {
         over(left, right, |l, r| self.eq_expr(l, r))
     }
@@ -626,6 +691,7 @@ pub struct SpanlessHash<'a, 'tcx> {
     /// Context used to evaluate constant expressions.
     cx: &'a LateContext<'tcx>,
     maybe_typeck_results: Option<&'tcx TypeckResults<'tcx>>,
+    eval_consts: bool,
     s: FxHasher,
 }
 
@@ -634,10 +700,18 @@ pub fn new(cx: &'a LateContext<'tcx>) -> Self {
         Self {
             cx,
             maybe_typeck_results: cx.maybe_typeck_results(),
+            eval_consts: false,
             s: FxHasher::default(),
         }
     }
 
+    /// Hash constant expressions by their value, even when they refer to `const` items. This
+    /// should be enabled when comparing the expressions with `SpanlessEq::eval_consts`.
+    #[must_use]
+    pub fn eval_consts(self, eval_consts: bool) -> Self {
+        Self { eval_consts, ..self }
+    }
+
     pub fn finish(self) -> u64 {
         self.s.finish()
     }
@@ -656,9 +730,13 @@ pub fn hash_block(&mut self, b: &Block<'_>) {
 
     #[expect(clippy::too_many_lines)]
     pub fn hash_expr(&mut self, e: &Expr<'_>) {
-        let simple_const = self
-            .maybe_typeck_results
-            .and_then(|typeck_results| constant_simple(self.cx, typeck_results, e));
+        let simple_const = self.maybe_typeck_results.and_then(|typeck_results| {
+            if self.eval_consts {
+                constant(self.cx, typeck_results, e)
+            } else {
+                constant_simple(self.cx, typeck_results, e)
+            }
+        });
 
         // const hashing may result in the same hash as some unrelated node, so add a sort of
         // discriminant depending on which path we're choosing next
@@ -676,9 +754,8 @@ pub fn hash_expr(&mut self, e: &Expr<'_>) {
                 self.hash_expr(e);
             },
             ExprKind::Continue(i) => {
-                if let Some(i) = i.label {
-                    self.hash_name(i.ident.name);
-                }
+                // labels are compared by their targets, not their names
+                i.label.is_some().hash(&mut self.s);
             },
             ExprKind::Assign(l, r, _) => {
                 self.hash_expr(l);
@@ -698,9 +775,7 @@ pub fn hash_expr(&mut self, e: &Expr<'_>) {
                 self.hash_expr(r);
             },
             ExprKind::Break(i, ref j) => {
-                if let Some(i) = i.label {
-                    self.hash_name(i.ident.name);
-                }
+                i.label.is_some().hash(&mut self.s);
                 if let Some(j) = *j {
                     self.hash_expr(j);
                 }
@@ -803,9 +878,7 @@ pub fn hash_expr(&mut self, e: &Expr<'_>) {
             },
             ExprKind::Loop(b, ref i, ..) => {
                 self.hash_block(b);
-                if let Some(i) = *i {
-                    self.hash_name(i.ident.name);
-                }
+                i.is_some().hash(&mut self.s);
             },
             ExprKind::If(cond, then, ref else_opt) => {
                 self.hash_expr(cond);
@@ -1138,12 +1211,8 @@ fn f(cx: &LateContext<'_>, left: Range<BytePos>, right: Range<BytePos>, pred: im
             let pred = |t: &(_, _)| pred(t.0);
             let map = |(_, x)| x;
 
-            let ltok = tokenize_with_text(lsrc)
-                .filter(pred)
-                .map(map);
-            let rtok = tokenize_with_text(rsrc)
-                .filter(pred)
-                .map(map);
+            let ltok = tokenize_with_text(lsrc).filter(pred).map(map);
+            let rtok = tokenize_with_text(rsrc).filter(pred).map(map);
             ltok.eq(rtok)
         } else {
             // Unable to access the source. Conservatively assume the blocks aren't equal.
@@ -88,10 +88,10 @@
 use rustc_hir::intravisit::{walk_expr, FnKind, Visitor};
 use rustc_hir::LangItem::{OptionNone, OptionSome, ResultErr, ResultOk};
 use rustc_hir::{
-    self as hir, def, Arm, ArrayLen, BindingAnnotation, Block, BlockCheckMode, Body, Closure, Destination, Expr,
+    self as hir, def, Arm, BindingAnnotation, Block, BlockCheckMode, Body, Closure, Destination, Expr, ExprField,
     ExprKind, FnDecl, HirId, Impl, ImplItem, ImplItemKind, ImplItemRef, IsAsync, Item, ItemKind, LangItem, Local,
     MatchSource, Mutability, Node, OwnerId, Param, Pat, PatKind, Path, PathSegment, PrimTy, QPath, Stmt, StmtKind,
-    TraitItem, TraitItemRef, TraitRef, TyKind, UnOp,
+    TraitItem, TraitItemKind, TraitItemRef, TraitRef, TyKind, UnOp,
 };
 use rustc_lexer::{tokenize, TokenKind};
 use rustc_lint::{LateContext, Level, Lint, LintContext};
@@ -113,7 +113,7 @@
 
 use crate::consts::{constant, miri_to_const, Constant};
 use crate::higher::Range;
-use crate::ty::{can_partially_move_ty, expr_sig, is_copy, is_recursively_primitive_type, ty_is_fn_once_param};
+use crate::ty::{can_partially_move_ty, expr_sig, is_copy, is_recursively_primitive_type, ty_is_fn_once_param, ty_sig};
 use crate::visitors::for_each_expr;
 
 use rustc_middle::hir::nested_filter;
@@ -840,16 +840,10 @@ pub fn is_default_equivalent(cx: &LateContext<'_>, e: &Expr<'_>) -> bool {
             _ => false,
         },
         ExprKind::Tup(items) | ExprKind::Array(items) => items.iter().all(|x| is_default_equivalent(cx, x)),
-        ExprKind::Repeat(x, ArrayLen::Body(len)) => if_chain! {
-            if let ExprKind::Lit(const_lit) = cx.tcx.hir().body(len.body).value.kind;
-            if let LitKind::Int(v, _) = const_lit.node;
-            if v <= 32 && is_default_equivalent(cx, x);
-            then {
-                true
-            }
-            else {
-                false
-            }
+        ExprKind::Repeat(..) => {
+            higher::ArrayRepeat::hir(cx, e).map_or(false, |repeat| {
+                repeat.len <= 32 && is_default_equivalent(cx, repeat.value)
+            })
         },
         ExprKind::Call(repl_func, []) => is_default_equivalent_call(cx, repl_func),
         ExprKind::Call(from_func, [ref arg]) => is_default_equivalent_from(cx, from_func, arg),
@@ -869,13 +863,11 @@ fn is_default_equivalent_from(cx: &LateContext<'_>, from_func: &Expr<'_>, arg: &
                 ..
             }) => return sym.is_empty() && is_path_lang_item(cx, ty, LangItem::String),
             ExprKind::Array([]) => return is_path_diagnostic_item(cx, ty, sym::Vec),
-            ExprKind::Repeat(_, ArrayLen::Body(len)) => {
-                if let ExprKind::Lit(const_lit) = cx.tcx.hir().body(len.body).value.kind &&
-                    let LitKind::Int(v, _) = const_lit.node
-                {
-                        return v == 0 && is_path_diagnostic_item(cx, ty, sym::Vec);
+            ExprKind::Repeat(..) => {
+                if let Some(repeat) = higher::ArrayRepeat::hir(cx, arg) {
+                    return repeat.len == 0 && is_path_diagnostic_item(cx, ty, sym::Vec);
                 }
-            }
+            },
             _ => (),
         }
     }
@@ -1087,6 +1079,9 @@ fn pat_capture_kind(cx: &LateContext<'_>, pat: &Pat<'_>) -> CaptureKind {
 
 /// Checks if the expression can be moved into a closure as is. This will return a list of captures
 /// if so, otherwise, `None`.
+///
+/// Any `return`, `?`, or `break`/`continue` to a loop or labeled block outside of the expression
+/// prevents it from being moved.
 pub fn can_move_expr_to_closure<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) -> Option<HirIdMap<CaptureKind>> {
     struct V<'cx, 'tcx> {
         cx: &'cx LateContext<'tcx>,
@@ -1137,7 +1132,8 @@ fn visit_expr(&mut self, e: &'tcx Expr<'_>) {
                         }
                     }
                 },
-                ExprKind::Loop(b, ..) => {
+                // `break` may also target a labeled block.
+                ExprKind::Loop(b, ..) | ExprKind::Block(b, Some(_)) => {
                     self.loops.push(e.hir_id);
                     self.visit_block(b);
                     self.loops.pop();
@@ -1408,7 +1404,7 @@ pub fn get_parent_as_impl(tcx: TyCtxt<'_>, id: HirId) -> Option<&Impl<'_>> {
 }
 
 /// Removes blocks around an expression, only if the block contains just one expression
-/// and no statements. Unsafe blocks are not removed.
+/// and no statements. Unsafe and labeled blocks are not removed.
 ///
 /// Examples:
 ///  * `{}`               -> `{}`
@@ -1417,6 +1413,7 @@ pub fn get_parent_as_impl(tcx: TyCtxt<'_>, id: HirId) -> Option<&Impl<'_>> {
 ///  * `{ x; }`           -> `{ x; }`
 ///  * `{ x; y }`         -> `{ x; y }`
 ///  * `{ unsafe { x } }` -> `unsafe { x }`
+///  * `{ 'a: { x } }`    -> `'a: { x }`
 pub fn peel_blocks<'a>(mut expr: &'a Expr<'a>) -> &'a Expr<'a> {
     while let ExprKind::Block(
         Block {
@@ -1425,7 +1422,7 @@ pub fn peel_blocks<'a>(mut expr: &'a Expr<'a>) -> &'a Expr<'a> {
             rules: BlockCheckMode::DefaultBlock,
             ..
         },
-        _,
+        None,
     ) = expr.kind
     {
         expr = inner;
@@ -1434,7 +1431,7 @@ pub fn peel_blocks<'a>(mut expr: &'a Expr<'a>) -> &'a Expr<'a> {
 }
 
 /// Removes blocks around an expression, only if the block contains just one expression
-/// or just one expression statement with a semicolon. Unsafe blocks are not removed.
+/// or just one expression statement with a semicolon. Unsafe and labeled blocks are not removed.
 ///
 /// Examples:
 ///  * `{}`               -> `{}`
@@ -1443,6 +1440,7 @@ pub fn peel_blocks<'a>(mut expr: &'a Expr<'a>) -> &'a Expr<'a> {
 ///  * `{{ x; }}`         -> `x`
 ///  * `{ x; y }`         -> `{ x; y }`
 ///  * `{ unsafe { x } }` -> `unsafe { x }`
+///  * `{ 'a: { x; } }`   -> `'a: { x; }`
 pub fn peel_blocks_with_stmt<'a>(mut expr: &'a Expr<'a>) -> &'a Expr<'a> {
     while let ExprKind::Block(
         Block {
@@ -1463,7 +1461,7 @@ pub fn peel_blocks_with_stmt<'a>(mut expr: &'a Expr<'a>) -> &'a Expr<'a> {
             rules: BlockCheckMode::DefaultBlock,
             ..
         },
-        _,
+        None,
     ) = expr.kind
     {
         expr = inner;
@@ -2069,6 +2067,93 @@ pub fn get_expr_use_or_unification_node<'tcx>(tcx: TyCtxt<'tcx>, expr: &Expr<'_>
     }
 }
 
+/// The node which consumes the value of an expression, as returned by [`expr_use_ctxt`].
+pub enum ExprUseNode<'tcx> {
+    /// Discarded by an expression statement.
+    Discarded,
+    /// Assigned to the pattern of a `let` statement or a `let` expression.
+    Local(&'tcx Pat<'tcx>),
+    /// Passed as an argument to a function call. Contains the call, the index of the argument,
+    /// and the type of the parameter if the callee's signature is known.
+    FnArg(&'tcx Expr<'tcx>, usize, Option<Ty<'tcx>>),
+    /// Used as the receiver of a method call.
+    MethodReceiver(&'tcx Expr<'tcx>),
+    /// Passed as an argument to a method call. Contains the call, the index of the argument not
+    /// counting the receiver, and the type of the parameter.
+    MethodArg(&'tcx Expr<'tcx>, usize, Option<Ty<'tcx>>),
+    /// Used as the value of a field in a struct literal.
+    Field(&'tcx ExprField<'tcx>),
+    /// Returned from a function or a closure, either with `return` or as the body's final
+    /// expression.
+    Return,
+    /// Used as the value of a `break` expression.
+    Break(Destination),
+    /// Used as the condition of an `if` expression or a `while` loop.
+    Condition,
+    /// Used as the scrutinee of a `match` expression.
+    Scrutinee,
+    /// Borrowed with the given mutability.
+    AddrOf(Mutability),
+    /// Any other use, including the branches of an `if` or `match` expression which unify the
+    /// type of the expression with other branches.
+    Other(Node<'tcx>),
+}
+
+/// Finds the node which consumes the value of the expression, skipping blocks and single arm
+/// `match` expressions on the way. Returns `None` if the value isn't consumed, e.g. for the body of
+/// an `if` without `else`.
+pub fn expr_use_ctxt<'tcx>(cx: &LateContext<'tcx>, e: &Expr<'_>) -> Option<ExprUseNode<'tcx>> {
+    let (node, child_id) = get_expr_use_or_unification_node(cx.tcx, e)?;
+    Some(match node {
+        Node::Stmt(Stmt {
+            kind: StmtKind::Expr(_) | StmtKind::Semi(_),
+            ..
+        }) => ExprUseNode::Discarded,
+        Node::Local(local) if local.init.is_some_and(|init| init.hir_id == child_id) => ExprUseNode::Local(local.pat),
+        Node::ExprField(field) => ExprUseNode::Field(field),
+        Node::Item(Item {
+            kind: ItemKind::Fn(..), ..
+        })
+        | Node::ImplItem(ImplItem {
+            kind: ImplItemKind::Fn(..),
+            ..
+        })
+        | Node::TraitItem(TraitItem {
+            kind: TraitItemKind::Fn(..),
+            ..
+        }) => ExprUseNode::Return,
+        Node::Expr(parent) => match parent.kind {
+            ExprKind::Let(local) if local.init.hir_id == child_id => ExprUseNode::Local(local.pat),
+            ExprKind::Call(func, args) if func.hir_id != child_id => {
+                let i = args.iter().position(|arg| arg.hir_id == child_id)?;
+                let ty = ty_sig(cx, cx.typeck_results().expr_ty_adjusted(func))
+                    .and_then(|sig| sig.input(i))
+                    .map(|ty| cx.tcx.erase_late_bound_regions(ty));
+                ExprUseNode::FnArg(parent, i, ty)
+            },
+            ExprKind::MethodCall(_, recv, _, _) if recv.hir_id == child_id => ExprUseNode::MethodReceiver(parent),
+            ExprKind::MethodCall(_, _, args, _) => {
+                let i = args.iter().position(|arg| arg.hir_id == child_id)?;
+                let ty = cx.typeck_results().type_dependent_def_id(parent.hir_id).map(|id| {
+                    let sig = cx
+                        .tcx
+                        .fn_sig(id)
+                        .instantiate(cx.tcx, cx.typeck_results().node_args(parent.hir_id));
+                    cx.tcx.erase_late_bound_regions(sig.input(i + 1))
+                });
+                ExprUseNode::MethodArg(parent, i, ty)
+            },
+            ExprKind::Closure(_) | ExprKind::Ret(_) => ExprUseNode::Return,
+            ExprKind::Break(dest, _) => ExprUseNode::Break(dest),
+            ExprKind::If(cond, ..) if cond.hir_id == child_id => ExprUseNode::Condition,
+            ExprKind::Match(scrutinee, ..) if scrutinee.hir_id == child_id => ExprUseNode::Scrutinee,
+            ExprKind::AddrOf(_, mutbl, _) => ExprUseNode::AddrOf(mutbl),
+            _ => ExprUseNode::Other(node),
+        },
+        _ => ExprUseNode::Other(node),
+    })
+}
+
 /// Checks if the result of an expression is used, or it's type is unified with another branch.
 pub fn is_expr_used_or_unified(tcx: TyCtxt<'_>, expr: &Expr<'_>) -> bool {
     !matches!(
@@ -2445,6 +2530,14 @@ fn is_cfg_test(attr: &Attribute) -> bool {
         .any(is_cfg_test)
 }
 
+/// Checks if the given `HirId` is in test code, either a `#[test]` function or an item with the
+/// `#[cfg(test)]` attribute applied.
+///
+/// Note: Add `//@compile-flags: --test` to UI tests with a `#[test]` or `#[cfg(test)]` function
+pub fn is_in_test(tcx: TyCtxt<'_>, id: hir::HirId) -> bool {
+    is_in_test_function(tcx, id) || is_in_cfg_test(tcx, id)
+}
+
 /// Checks whether item either has `test` attribute applied, or
 /// is a module with `test` in its name.
 ///
@@ -2614,3 +2707,112 @@ pub fn binop_traits(kind: hir::BinOpKind) -> Option<(LangItem, LangItem)> {
     Shl    ShlAssign
     Shr    ShrAssign
 }
+
+#[cfg(test)]
+mod test {
+    use super::{peel_blocks, peel_blocks_with_stmt};
+    use rustc_ast::{Label, LitKind};
+    use rustc_hir::{Block, BlockCheckMode, Expr, ExprKind, Stmt, StmtKind, UnsafeSource, CRATE_HIR_ID};
+    use rustc_span::source_map::Spanned;
+    use rustc_span::symbol::{kw, Ident};
+    use rustc_span::DUMMY_SP;
+    use std::ptr;
+
+    const DEFAULT: BlockCheckMode = BlockCheckMode::DefaultBlock;
+    const UNSAFE: BlockCheckMode = BlockCheckMode::UnsafeBlock(UnsafeSource::UserProvided);
+
+    fn expr(kind: ExprKind<'static>) -> &'static Expr<'static> {
+        Box::leak(Box::new(Expr {
+            hir_id: CRATE_HIR_ID,
+            kind,
+            span: DUMMY_SP,
+        }))
+    }
+
+    /// `true`
+    fn lit() -> &'static Expr<'static> {
+        expr(ExprKind::Lit(Box::leak(Box::new(Spanned {
+            node: LitKind::Bool(true),
+            span: DUMMY_SP,
+        }))))
+    }
+
+    /// `x;`
+    fn semi(x: &'static Expr<'static>) -> Stmt<'static> {
+        Stmt {
+            hir_id: CRATE_HIR_ID,
+            kind: StmtKind::Semi(x),
+            span: DUMMY_SP,
+        }
+    }
+
+    fn block(
+        stmts: Vec<Stmt<'static>>,
+        tail: Option<&'static Expr<'static>>,
+        rules: BlockCheckMode,
+        labeled: bool,
+    ) -> &'static Expr<'static> {
+        let block = Box::leak(Box::new(Block {
+            stmts: stmts.leak(),
+            expr: tail,
+            hir_id: CRATE_HIR_ID,
+            rules,
+            span: DUMMY_SP,
+            targeted_by_break: labeled,
+        }));
+        let label = labeled.then(|| Label {
+            ident: Ident::with_dummy_span(kw::UnderscoreLifetime),
+        });
+        expr(ExprKind::Block(block, label))
+    }
+
+    #[test]
+    fn peel_blocks_removes_blocks_with_only_an_expression() {
+        let x = lit();
+        assert!(ptr::eq(peel_blocks(x), x));
+        assert!(ptr::eq(peel_blocks(block(vec![], Some(x), DEFAULT, false)), x));
+        let nested = block(vec![], Some(block(vec![], Some(x), DEFAULT, false)), DEFAULT, false);
+        assert!(ptr::eq(peel_blocks(nested), x));
+    }
+
+    #[test]
+    fn peel_blocks_keeps_blocks_with_statements() {
+        let empty = block(vec![], None, DEFAULT, false);
+        assert!(ptr::eq(peel_blocks(empty), empty));
+        let stmt = block(vec![semi(lit())], None, DEFAULT, false);
+        assert!(ptr::eq(peel_blocks(stmt), stmt));
+        let stmt_and_expr = block(vec![semi(lit())], Some(lit()), DEFAULT, false);
+        assert!(ptr::eq(peel_blocks(stmt_and_expr), stmt_and_expr));
+    }
+
+    #[test]
+    fn peel_blocks_with_stmt_removes_blocks_with_one_statement() {
+        let x = lit();
+        assert!(ptr::eq(peel_blocks_with_stmt(block(vec![], Some(x), DEFAULT, false)), x));
+        assert!(ptr::eq(peel_blocks_with_stmt(block(vec![semi(x)], None, DEFAULT, false)), x));
+        let nested = block(vec![], Some(block(vec![semi(x)], None, DEFAULT, false)), DEFAULT, false);
+        assert!(ptr::eq(peel_blocks_with_stmt(nested), x));
+    }
+
+    #[test]
+    fn peel_blocks_with_stmt_keeps_blocks_with_several_statements() {
+        let empty = block(vec![], None, DEFAULT, false);
+        assert!(ptr::eq(peel_blocks_with_stmt(empty), empty));
+        let stmts = block(vec![semi(lit()), semi(lit())], None, DEFAULT, false);
+        assert!(ptr::eq(peel_blocks_with_stmt(stmts), stmts));
+        let stmt_and_expr = block(vec![semi(lit())], Some(lit()), DEFAULT, false);
+        assert!(ptr::eq(peel_blocks_with_stmt(stmt_and_expr), stmt_and_expr));
+    }
+
+    #[test]
+    fn unsafe_and_labeled_blocks_are_kept() {
+        let unsafe_block = block(vec![], Some(lit()), UNSAFE, false);
+        let labeled = block(vec![], Some(lit()), DEFAULT, true);
+        let labeled_stmt = block(vec![semi(lit())], None, DEFAULT, true);
+        for inner in [unsafe_block, labeled, labeled_stmt] {
+            let outer = block(vec![], Some(inner), DEFAULT, false);
+            assert!(ptr::eq(peel_blocks(outer), inner));
+            assert!(ptr::eq(peel_blocks_with_stmt(outer), inner));
+        }
+    }
+}
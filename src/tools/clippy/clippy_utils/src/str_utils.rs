@@ -322,4 +322,13 @@ fn camel_case_split_full() {
             vec!["\u{f6}\u{f6}", "Aab", "A", "Bcd"]
         );
     }
+
+    #[test]
+    fn camel_case_split_word_boundaries() {
+        assert_eq!(camel_case_split("WithOut"), vec!["With", "Out"]);
+        assert_eq!(camel_case_split("Without"), vec!["Without"]);
+        assert_eq!(camel_case_split("ErrorKind"), vec!["Error", "Kind"]);
+        assert_eq!(camel_case_split("IoError"), vec!["Io", "Error"]);
+        assert_eq!(camel_case_split("_TypeCreate"), vec!["_", "Type", "Create"]);
+    }
 }
@@ -2,7 +2,7 @@
 #![deny(clippy::missing_docs_in_private_items)]
 
 use crate::source::{snippet, snippet_opt, snippet_with_applicability, snippet_with_context};
-use crate::ty::expr_sig;
+use crate::ty::{expr_sig, implements_trait};
 use crate::{get_parent_expr_for_hir, higher};
 use rustc_ast::util::parser::AssocOp;
 use rustc_ast::{ast, token};
@@ -17,6 +17,7 @@
 use rustc_middle::mir::{FakeReadCause, Mutability};
 use rustc_middle::ty;
 use rustc_span::source_map::{BytePos, CharPos, Pos, Span, SyntaxContext};
+use rustc_span::sym;
 use std::borrow::Cow;
 use std::fmt::{self, Display, Write as _};
 use std::ops::{Add, Neg, Not, Sub};
@@ -357,6 +358,61 @@ pub fn maybe_par(self) -> Self {
             },
         }
     }
+
+    /// Negates the expression, inverting comparison operators instead of adding a `!` where
+    /// this keeps the meaning, and removing the `!` of `!x`.
+    ///
+    /// `==` and `!=` are always inverted. `<`, `<=`, `>` and `>=` are only inverted if
+    /// `total_ord` is set, as e.g. `!(a < b)` is not `a >= b` for floats. See
+    /// [`has_total_ord_operands`].
+    pub fn negate(self, total_ord: bool) -> Sugg<'a> {
+        use AssocOp::{Equal, Greater, GreaterEqual, Less, LessEqual, NotEqual};
+
+        match self {
+            Sugg::BinOp(op, lhs, rhs) => {
+                let to_op = match op {
+                    Equal => NotEqual,
+                    NotEqual => Equal,
+                    Less if total_ord => GreaterEqual,
+                    GreaterEqual if total_ord => Less,
+                    Greater if total_ord => LessEqual,
+                    LessEqual if total_ord => Greater,
+                    _ => return make_unop("!", Sugg::BinOp(op, lhs, rhs)),
+                };
+                Sugg::BinOp(to_op, lhs, rhs)
+            },
+            Sugg::MaybeParen(ref sugg) if let Some(inner) = sugg.strip_prefix('!') => {
+                Sugg::MaybeParen(inner.trim_start().to_string().into())
+            },
+            _ => make_unop("!", self),
+        }
+    }
+}
+
+/// Returns `true` if `expr` is a comparison of values with a total order, i.e. which implement
+/// `Ord`, so that it can be negated by inverting the operator. This is meant to be passed as the
+/// `total_ord` argument of [`Sugg::negate`].
+pub fn has_total_ord_operands(cx: &LateContext<'_>, expr: &hir::Expr<'_>) -> bool {
+    let mut expr = expr;
+    while let ExprKind::DropTemps(inner) = expr.kind {
+        expr = inner;
+    }
+    if let ExprKind::Binary(op, lhs, _) = expr.kind
+        && matches!(
+            op.node,
+            hir::BinOpKind::Eq
+                | hir::BinOpKind::Ne
+                | hir::BinOpKind::Lt
+                | hir::BinOpKind::Le
+                | hir::BinOpKind::Gt
+                | hir::BinOpKind::Ge
+        )
+        && let Some(ord_id) = cx.tcx.get_diagnostic_item(sym::Ord)
+    {
+        implements_trait(cx, cx.typeck_results().expr_ty(lhs), ord_id, &[])
+    } else {
+        false
+    }
 }
 
 /// Generates a string from the operator and both sides.
@@ -472,22 +528,7 @@ fn neg(self) -> Sugg<'static> {
 impl<'a> Not for Sugg<'a> {
     type Output = Sugg<'a>;
     fn not(self) -> Sugg<'a> {
-        use AssocOp::{Equal, Greater, GreaterEqual, Less, LessEqual, NotEqual};
-
-        if let Sugg::BinOp(op, lhs, rhs) = self {
-            let to_op = match op {
-                Equal => NotEqual,
-                NotEqual => Equal,
-                Less => GreaterEqual,
-                GreaterEqual => Less,
-                Greater => LessEqual,
-                LessEqual => Greater,
-                _ => return make_unop("!", Sugg::BinOp(op, lhs, rhs)),
-            };
-            Sugg::BinOp(to_op, lhs, rhs)
-        } else {
-            make_unop("!", self)
-        }
+        self.negate(false)
     }
 }
 
@@ -1093,13 +1134,26 @@ fn test_not(op: AssocOp, correct: &str) {
             assert_eq!((!sugg).to_string(), correct);
         }
 
+        fn test_negate_total_ord(op: AssocOp, correct: &str) {
+            let sugg = Sugg::BinOp(op, "x".into(), "y".into());
+            assert_eq!(sugg.negate(true).to_string(), correct);
+        }
+
         // Invert the comparison operator.
         test_not(Equal, "x != y");
         test_not(NotEqual, "x == y");
-        test_not(Less, "x >= y");
-        test_not(LessEqual, "x > y");
-        test_not(Greater, "x <= y");
-        test_not(GreaterEqual, "x < y");
+        test_negate_total_ord(Less, "x >= y");
+        test_negate_total_ord(LessEqual, "x > y");
+        test_negate_total_ord(Greater, "x <= y");
+        test_negate_total_ord(GreaterEqual, "x < y");
+
+        // Without a total order, only equality comparisons are inverted.
+        test_not(Less, "!(x < y)");
+        test_not(GreaterEqual, "!(x >= y)");
+
+        // Double negation is removed.
+        assert_eq!((!Sugg::MaybeParen("!x".into())).to_string(), "x");
+        assert_eq!((!Sugg::MaybeParen("!(x + y)".into())).to_string(), "(x + y)");
 
         // Other operators are inverted like !(..).
         test_not(Add, "!(x + y)");
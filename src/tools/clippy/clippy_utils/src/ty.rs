@@ -11,18 +11,19 @@
 use rustc_hir::{Expr, FnDecl, LangItem, TyKind, Unsafety};
 use rustc_infer::infer::type_variable::{TypeVariableOrigin, TypeVariableOriginKind};
 use rustc_infer::infer::TyCtxtInferExt;
+use rustc_infer::traits::{Obligation, ObligationCause};
 use rustc_lint::LateContext;
 use rustc_middle::mir::interpret::{ConstValue, Scalar};
 use rustc_middle::ty::layout::ValidityRequirement;
 use rustc_middle::ty::{
     self, AdtDef, AliasTy, AssocKind, Binder, BoundRegion, FnSig, GenericArg, GenericArgKind, GenericArgsRef, IntTy,
-    List, ParamEnv, Region, RegionKind, Ty, TyCtxt, TypeSuperVisitable, TypeVisitable, TypeVisitableExt, TypeVisitor,
-    UintTy, VariantDef, VariantDiscr,
+    List, ParamEnv, Region, RegionKind, TraitRef, Ty, TyCtxt, TypeSuperVisitable, TypeVisitable, TypeVisitableExt,
+    TypeVisitor, UintTy, VariantDef, VariantDiscr,
 };
 use rustc_span::symbol::Ident;
 use rustc_span::{sym, Span, Symbol, DUMMY_SP};
 use rustc_target::abi::{Size, VariantIdx};
-use rustc_trait_selection::infer::InferCtxtExt;
+use rustc_trait_selection::traits::query::evaluate_obligation::InferCtxtExt as _;
 use rustc_trait_selection::traits::query::normalize::QueryNormalizeExt;
 use std::iter;
 
@@ -216,6 +217,9 @@ pub fn implements_trait<'tcx>(
 }
 
 /// Same as `implements_trait` but allows using a `ParamEnv` different from the lint context.
+///
+/// `ty_params` are the trait's generic arguments after `Self`. `None` is replaced by a fresh
+/// inference variable. Returns `false` if the arguments don't fit the trait's generics.
 pub fn implements_trait_with_env<'tcx>(
     tcx: TyCtxt<'tcx>,
     param_env: ParamEnv<'tcx>,
@@ -226,23 +230,44 @@ pub fn implements_trait_with_env<'tcx>(
     // Clippy shouldn't have infer types
     assert!(!ty.has_infer());
 
-    let ty = tcx.erase_regions(ty);
-    if ty.has_escaping_bound_vars() {
-        return false;
-    }
     let infcx = tcx.infer_ctxt().build();
     let orig = TypeVariableOrigin {
         kind: TypeVariableOriginKind::MiscVariable,
         span: DUMMY_SP,
     };
-    let ty_params = tcx.mk_args_from_iter(
-        ty_params
-            .into_iter()
-            .map(|arg| arg.unwrap_or_else(|| infcx.next_ty_var(orig).into())),
+    let args = tcx.mk_args_from_iter(
+        iter::once(ty.into())
+            .chain(
+                ty_params
+                    .into_iter()
+                    .map(|arg| arg.unwrap_or_else(|| infcx.next_ty_var(orig).into())),
+            )
+            .map(|arg| tcx.erase_regions(arg)),
     );
-    infcx
-        .type_implements_trait(trait_id, [ty.into()].into_iter().chain(ty_params), param_env)
-        .must_apply_modulo_regions()
+    if args.has_escaping_bound_vars() || !args_match_generics(tcx, trait_id, args) {
+        return false;
+    }
+    let trait_ref = TraitRef::new(tcx, trait_id, args);
+    // `evaluate_obligation` canonicalizes the goal, the probe makes sure no inference
+    // constraints leak out of it.
+    infcx.probe(|_| {
+        infcx.predicate_must_hold_modulo_regions(&Obligation::new(tcx, ObligationCause::dummy(), param_env, trait_ref))
+    })
+}
+
+/// Checks that `args` has exactly one argument of the right kind for each of the item's generic
+/// parameters.
+fn args_match_generics<'tcx>(tcx: TyCtxt<'tcx>, def_id: DefId, args: GenericArgsRef<'tcx>) -> bool {
+    let generics = tcx.generics_of(def_id);
+    generics.count() == args.len()
+        && args.iter().enumerate().all(|(i, arg)| {
+            matches!(
+                (&generics.param_at(i, tcx).kind, arg.unpack()),
+                (ty::GenericParamDefKind::Lifetime, GenericArgKind::Lifetime(_))
+                    | (ty::GenericParamDefKind::Type { .. }, GenericArgKind::Type(_))
+                    | (ty::GenericParamDefKind::Const { .. }, GenericArgKind::Const(_))
+            )
+        })
 }
 
 /// Checks whether this type implements `Drop`.
@@ -29,8 +29,8 @@ pub(crate) struct LintcheckConfig {
     /// Only process a single crate on the list
     #[clap(long, value_name = "CRATE")]
     pub only: Option<String>,
-    /// Runs cargo clippy --fix and checks if all suggestions apply
-    #[clap(long, conflicts_with("max_jobs"))]
+    /// Runs cargo clippy --fix on a copy of each crate and checks if it still compiles afterwards
+    #[clap(long, conflicts_with_all(["max_jobs", "recursive"]))]
     pub fix: bool,
     /// Apply a filter to only collect specified lints, this also overrides `allow` attributes
     #[clap(long = "filter", value_name = "clippy_lint_name", use_value_delimiter = true)]
@@ -51,8 +51,9 @@ pub fn new() -> Self {
         // wasd.toml, use "wasd"...)
         let filename: PathBuf = config.sources_toml_path.file_stem().unwrap().into();
         config.lintcheck_results_path = PathBuf::from(format!(
-            "lintcheck-logs/{}_logs.{}",
+            "lintcheck-logs/{}_{}logs.{}",
             filename.display(),
+            if config.fix { "fix_" } else { "" },
             if config.markdown { "md" } else { "txt" }
         ));
 
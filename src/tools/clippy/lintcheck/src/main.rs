@@ -24,7 +24,7 @@
 use std::time::Duration;
 use std::{env, fs, thread};
 
-use cargo_metadata::diagnostic::{Diagnostic, DiagnosticLevel};
+use cargo_metadata::diagnostic::{Applicability, Diagnostic, DiagnosticLevel};
 use cargo_metadata::Message;
 use rayon::prelude::*;
 use serde::{Deserialize, Serialize};
@@ -32,6 +32,7 @@
 
 const LINTCHECK_DOWNLOADS: &str = "target/lintcheck/downloads";
 const LINTCHECK_SOURCES: &str = "target/lintcheck/sources";
+const LINTCHECK_FIX_SOURCES: &str = "target/lintcheck/fix";
 
 /// List of sources to check, loaded from a .toml file
 #[derive(Debug, Serialize, Deserialize)]
@@ -153,6 +154,61 @@ fn to_output(&self, markdown: bool) -> String {
     }
 }
 
+/// A crate which doesn't compile anymore after applying clippy's suggestions
+#[derive(Debug)]
+struct FixFailure {
+    crate_name: String,
+    version: String,
+    /// The lints with machine applicable suggestions for the crate
+    lints: Vec<String>,
+    errors: String,
+}
+
+impl FixFailure {
+    fn to_output(&self) -> String {
+        format!(
+            "#### {} {}\n\nApplied suggestions of: {}\n\n```\n{}\n```\n\n",
+            self.crate_name,
+            self.version,
+            self.lints.join(", "),
+            self.errors.trim_end()
+        )
+    }
+}
+
+/// Returns `true` if the diagnostic carries a suggestion that `cargo fix` applies.
+fn is_machine_applicable(diag: &Diagnostic) -> bool {
+    diag.spans
+        .iter()
+        .chain(diag.children.iter().flat_map(|child| &child.spans))
+        .any(|span| span.suggestion_applicability == Some(Applicability::MachineApplicable))
+}
+
+fn is_cache_dir(entry: &DirEntry) -> bool {
+    std::fs::read(entry.path().join("CACHEDIR.TAG"))
+        .map(|x| x.starts_with(b"Signature: 8a477f597d28d172789f06886806bc55"))
+        .unwrap_or(false)
+}
+
+/// Copies `path` into `dest_crate_root` but skips directories that contain a CACHEDIR.TAG file.
+/// The target/ directory contains a CACHEDIR.TAG file so it is the most commonly skipped directory
+/// as a result of this filter.
+fn copy_crate_sources(path: &Path, dest_crate_root: &Path) {
+    for entry in WalkDir::new(path).into_iter().filter_entry(|e| !is_cache_dir(e)) {
+        let entry = entry.unwrap();
+        let entry_path = entry.path();
+        let relative_entry_path = entry_path.strip_prefix(path).unwrap();
+        let dest_path = dest_crate_root.join(relative_entry_path);
+        let metadata = entry_path.symlink_metadata().unwrap();
+
+        if metadata.is_dir() {
+            std::fs::create_dir(dest_path).unwrap();
+        } else if metadata.is_file() {
+            std::fs::copy(entry_path, dest_path).unwrap();
+        }
+    }
+}
+
 #[allow(clippy::result_large_err)]
 fn get(path: &str) -> Result<ureq::Response, ureq::Error> {
     const MAX_RETRIES: u8 = 4;
@@ -256,15 +312,6 @@ fn download_and_extract(&self) -> Crate {
                 }
             },
             CrateSource::Path { name, path, options } => {
-                fn is_cache_dir(entry: &DirEntry) -> bool {
-                    std::fs::read(entry.path().join("CACHEDIR.TAG"))
-                        .map(|x| x.starts_with(b"Signature: 8a477f597d28d172789f06886806bc55"))
-                        .unwrap_or(false)
-                }
-
-                // copy path into the dest_crate_root but skip directories that contain a CACHEDIR.TAG file.
-                // The target/ directory contains a CACHEDIR.TAG file so it is the most commonly skipped directory
-                // as a result of this filter.
                 let dest_crate_root = PathBuf::from(LINTCHECK_SOURCES).join(name);
                 if dest_crate_root.exists() {
                     println!("Deleting existing directory at {dest_crate_root:?}");
@@ -272,20 +319,7 @@ fn is_cache_dir(entry: &DirEntry) -> bool {
                 }
 
                 println!("Copying {path:?} to {dest_crate_root:?}");
-
-                for entry in WalkDir::new(path).into_iter().filter_entry(|e| !is_cache_dir(e)) {
-                    let entry = entry.unwrap();
-                    let entry_path = entry.path();
-                    let relative_entry_path = entry_path.strip_prefix(path).unwrap();
-                    let dest_path = dest_crate_root.join(relative_entry_path);
-                    let metadata = entry_path.symlink_metadata().unwrap();
-
-                    if metadata.is_dir() {
-                        std::fs::create_dir(dest_path).unwrap();
-                    } else if metadata.is_file() {
-                        std::fs::copy(entry_path, dest_path).unwrap();
-                    }
-                }
+                copy_crate_sources(path, &dest_crate_root);
 
                 Crate {
                     version: String::from("local"),
@@ -334,27 +368,8 @@ fn run_clippy_lints(
 
         let shared_target_dir = clippy_project_root().join("target/lintcheck/shared_target_dir");
 
-        let mut cargo_clippy_args = if config.fix {
-            vec!["--fix", "--"]
-        } else {
-            vec!["--", "--message-format=json", "--"]
-        };
-
-        let mut clippy_args = Vec::<&str>::new();
-        if let Some(options) = &self.options {
-            for opt in options {
-                clippy_args.push(opt);
-            }
-        } else {
-            clippy_args.extend(["-Wclippy::pedantic", "-Wclippy::cargo"]);
-        }
-
-        if lint_filter.is_empty() {
-            clippy_args.push("--cap-lints=warn");
-        } else {
-            clippy_args.push("--cap-lints=allow");
-            clippy_args.extend(lint_filter.iter().map(std::string::String::as_str));
-        }
+        let mut cargo_clippy_args = vec!["--", "--message-format=json", "--"];
+        let clippy_args = self.clippy_args(lint_filter);
 
         if let Some(server) = server {
             let target = shared_target_dir.join("recursive");
@@ -402,7 +417,6 @@ fn run_clippy_lints(
                 );
             });
         let stdout = String::from_utf8_lossy(&all_output.stdout);
-        let stderr = String::from_utf8_lossy(&all_output.stderr);
         let status = &all_output.status;
 
         if !status.success() {
@@ -412,21 +426,6 @@ fn run_clippy_lints(
             );
         }
 
-        if config.fix {
-            if let Some(stderr) = stderr
-                .lines()
-                .find(|line| line.contains("failed to automatically apply fixes suggested by rustc to crate"))
-            {
-                let subcrate = &stderr[63..];
-                println!(
-                    "ERROR: failed to apply some suggestion to {} / to (sub)crate {subcrate}",
-                    self.name
-                );
-            }
-            // fast path, we don't need the warnings anyway
-            return Vec::new();
-        }
-
         // get all clippy warnings and ICEs
         let warnings: Vec<ClippyWarning> = Message::parse_stream(stdout.as_bytes())
             .filter_map(|msg| match msg {
@@ -437,6 +436,124 @@ fn run_clippy_lints(
 
         warnings
     }
+
+    /// Run `cargo clippy --fix` on a copy of the `Crate` and `cargo check` the result, returning
+    /// the errors if the applied suggestions broke the build
+    fn run_clippy_fix(
+        &self,
+        cargo_clippy_path: &Path,
+        target_dir_index: &AtomicUsize,
+        total_crates_to_lint: usize,
+        lint_filter: &[String],
+    ) -> Option<FixFailure> {
+        let index = target_dir_index.fetch_add(1, Ordering::SeqCst);
+        let perc = (index * 100) / total_crates_to_lint;
+        println!(
+            "{index}/{total_crates_to_lint} {perc}% Fixing {} {}",
+            &self.name, &self.version
+        );
+
+        let cargo_clippy_path = std::fs::canonicalize(cargo_clippy_path).unwrap();
+        let target_dir = clippy_project_root().join("target/lintcheck/shared_target_dir/fix");
+
+        // work on a copy so the applied suggestions don't end up in the cached sources
+        let fix_dir = clippy_project_root()
+            .join(LINTCHECK_FIX_SOURCES)
+            .join(format!("{}-{}", self.name, self.version));
+        if fix_dir.exists() {
+            std::fs::remove_dir_all(&fix_dir).unwrap();
+        }
+        std::fs::create_dir_all(fix_dir.parent().unwrap()).unwrap();
+        copy_crate_sources(&self.path, &fix_dir);
+
+        let run = |command: &mut Command| {
+            command
+                .env("CARGO_TARGET_DIR", &target_dir)
+                .current_dir(&fix_dir)
+                .output()
+                .unwrap_or_else(|error| panic!("Encountered error:\n{error:?}\ncrate path:{}\n", fix_dir.display()))
+        };
+
+        // errors of crates which don't build with all their targets to begin with aren't caused by
+        // the suggestions
+        let check_all_targets = || run(Command::new("cargo").args(["check", "--all-targets", "--message-format=short"]));
+        if !check_all_targets().status.success() {
+            println!(
+                "Skipping {} {}, it doesn't build with `--all-targets`",
+                &self.name, &self.version
+            );
+            return None;
+        }
+
+        let clippy_args = self.clippy_args(lint_filter);
+
+        // collect the lints whose suggestions are going to be applied, `cargo fix` checks all targets
+        // as well
+        let output = run(Command::new(&cargo_clippy_path)
+            .args(["--", "--all-targets", "--message-format=json", "--"])
+            .args(&clippy_args));
+        let mut lints: Vec<String> = Message::parse_stream(&*output.stdout)
+            .filter_map(|msg| match msg {
+                Ok(Message::CompilerMessage(message)) if is_machine_applicable(&message.message) => {
+                    ClippyWarning::new(message.message, &self.name, &self.version).map(|warning| warning.lint_type)
+                },
+                _ => None,
+            })
+            .collect();
+        if lints.is_empty() {
+            return None;
+        }
+        lints.sort_unstable();
+        lints.dedup();
+
+        let output = run(Command::new(&cargo_clippy_path)
+            .args(["--", "--fix", "--allow-no-vcs", "--all-targets", "--"])
+            .args(&clippy_args));
+        let mut errors: String = String::from_utf8_lossy(&output.stderr)
+            .lines()
+            .filter(|line| line.contains("failed to automatically apply fixes suggested by rustc"))
+            .map(|line| format!("{line}\n"))
+            .collect();
+
+        let output = check_all_targets();
+        if !output.status.success() {
+            // one line per error with paths relative to the crate, so the report is stable between runs
+            errors.extend(
+                String::from_utf8_lossy(&output.stderr)
+                    .lines()
+                    .filter(|line| line.starts_with("error") || line.contains(": error"))
+                    .map(|line| format!("{line}\n")),
+            );
+        }
+
+        (!errors.is_empty()).then(|| FixFailure {
+            crate_name: self.name.clone(),
+            version: self.version.clone(),
+            lints,
+            errors,
+        })
+    }
+
+    /// The lint levels passed to clippy, either the crate's options from the sources toml or the
+    /// default lint groups
+    fn clippy_args<'a>(&'a self, lint_filter: &'a [String]) -> Vec<&'a str> {
+        let mut clippy_args = Vec::<&str>::new();
+        if let Some(options) = &self.options {
+            for opt in options {
+                clippy_args.push(opt);
+            }
+        } else {
+            clippy_args.extend(["-Wclippy::pedantic", "-Wclippy::cargo"]);
+        }
+
+        if lint_filter.is_empty() {
+            clippy_args.push("--cap-lints=warn");
+        } else {
+            clippy_args.push("--cap-lints=allow");
+            clippy_args.extend(lint_filter.iter().map(std::string::String::as_str));
+        }
+        clippy_args
+    }
 }
 
 /// Builds clippy inside the repo to make sure we have a clippy executable we can use.
@@ -629,6 +746,40 @@ fn main() {
         .build_global()
         .unwrap();
 
+    if config.fix {
+        let mut fix_failures: Vec<FixFailure> = crates
+            .par_iter()
+            .filter_map(|krate| krate.run_clippy_fix(&cargo_clippy_path, &counter, crates.len(), &lint_filter))
+            .collect();
+        fix_failures.sort_by(|a, b| (&a.crate_name, &a.version).cmp(&(&b.crate_name, &b.version)));
+
+        let mut text = clippy_ver;
+        text.push_str("\n### Fix failures\n\n");
+        for failure in &fix_failures {
+            text.push_str(&failure.to_output());
+        }
+        let _: fmt::Result = writeln!(
+            text,
+            "### Stats:\n\n{} of {} crates failed to compile after applying suggestions",
+            fix_failures.len(),
+            crates.len()
+        );
+
+        println!("Writing logs to {}", config.lintcheck_results_path.display());
+        fs::create_dir_all(config.lintcheck_results_path.parent().unwrap()).unwrap();
+        fs::write(&config.lintcheck_results_path, text).unwrap();
+
+        for failure in &fix_failures {
+            println!(
+                "ERROR: applying suggestions of {} broke {} {}",
+                failure.lints.join(", "),
+                failure.crate_name,
+                failure.version
+            );
+        }
+        return;
+    }
+
     let server = config.recursive.then(|| {
         let _: io::Result<()> = fs::remove_dir_all("target/lintcheck/shared_target_dir/recursive");
 
@@ -654,11 +805,6 @@ fn main() {
         clippy_warnings.extend(server.warnings());
     }
 
-    // if we are in --fix mode, don't change the log files, terminate here
-    if config.fix {
-        return;
-    }
-
     // generate some stats
     let (stats_formatted, new_stats) = gather_stats(&clippy_warnings);
 
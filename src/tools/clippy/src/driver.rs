@@ -125,18 +125,25 @@ impl rustc_driver::Callbacks for ClippyCallbacks {
     #[allow(rustc::bad_opt_access)]
     fn config(&mut self, config: &mut interface::Config) {
         let conf_path = clippy_lints::lookup_conf_file();
+        // Lint levels from `clippy.toml` go first, so the ones given on the command line override them
+        let mut lint_opts = clippy_lints::conf_lint_opts(&conf_path);
+        lint_opts.append(&mut config.opts.lint_opts);
+        config.opts.lint_opts = lint_opts;
+
         let previous = config.register_lints.take();
         let clippy_args_var = self.clippy_args_var.take();
         config.parse_sess_created = Some(Box::new(move |parse_sess| {
             track_clippy_args(parse_sess, &clippy_args_var);
             track_files(parse_sess);
 
-            // Trigger a rebuild if CLIPPY_CONF_DIR changes. The value must be a valid string so
-            // changes between dirs that are invalid UTF-8 will not trigger rebuilds
-            parse_sess.env_depinfo.get_mut().insert((
-                Symbol::intern("CLIPPY_CONF_DIR"),
-                env::var("CLIPPY_CONF_DIR").ok().map(|dir| Symbol::intern(&dir)),
-            ));
+            // Trigger a rebuild if CLIPPY_CONF_DIR or CLIPPY_CONF_PATH change. The values must be
+            // valid strings so changes between paths that are invalid UTF-8 will not trigger rebuilds
+            for var in ["CLIPPY_CONF_DIR", "CLIPPY_CONF_PATH"] {
+                parse_sess.env_depinfo.get_mut().insert((
+                    Symbol::intern(var),
+                    env::var(var).ok().map(|path| Symbol::intern(&path)),
+                ));
+            }
         }));
         config.register_lints = Some(Box::new(move |sess, lint_store| {
             // technically we're ~guaranteed that this is none but might as well call anything that
@@ -169,13 +176,18 @@ fn display_help() {
 
 Usage:
     cargo clippy [options] [--] [<opts>...]
+    clippy-driver [<rustc opts>...] <INPUT>
 
 Common options:
     -h, --help               Print this message
         --rustc              Pass all args to rustc
     -V, --version            Print version info and exit
+        --explain LINT       Print the documentation for a given lint
 
-For the other options see `cargo check --help`.
+For the other options see `cargo check --help`. When `clippy-driver` is invoked
+directly it takes the options of `rustc`, and reads the `clippy.toml` given by
+`CLIPPY_CONF_PATH`, or else looks for one in `CLIPPY_CONF_DIR` or the current
+directory and its parents.
 
 To allow or deny a lint from the command line you can use `cargo clippy --`
 with:
@@ -240,6 +252,14 @@ pub fn main() {
             exit(0);
         }
 
+        // Error codes such as `E0308` are explained by rustc
+        if let Some(pos) = orig_args.iter().position(|arg| arg == "--explain")
+            && let Some(lint) = orig_args.get(pos + 1)
+            && !lint.trim_start_matches(['E', 'e']).bytes().all(|b| b.is_ascii_digit())
+        {
+            exit(clippy_lints::explain(lint));
+        }
+
         // Setting RUSTC_WRAPPER causes Cargo to pass 'rustc' as the first argument.
         // We're invoking the compiler programmatically, so we ignore this/
         let wrapper_mode = orig_args.get(1).map(Path::new).and_then(Path::file_stem) == Some("rustc".as_ref());
@@ -275,10 +295,12 @@ pub fn main() {
             .collect::<Vec<String>>();
 
         // We enable Clippy if one of the following conditions is met
-        // - IF Clippy is run on its test suite OR
+        // - IF Clippy is invoked directly, e.g. `clippy-driver foo.rs`, or on its test suite OR
         // - IF Clippy is run on the main crate, not on deps (`!cap_lints_allow`) THEN
         //    - IF `--no-deps` is not set (`!no_deps`) OR
         //    - IF `--no-deps` is set and Clippy is run on the specified primary package
+        // `--no-deps` and the primary package are only known when run by `cargo clippy`, which
+        // passes them through `CLIPPY_ARGS` and `CARGO_PRIMARY_PACKAGE`.
         let cap_lints_allow = arg_value(&orig_args, "--cap-lints", |val| val == "allow").is_some()
             && arg_value(&orig_args, "--force-warn", |val| val.contains("clippy::")).is_none();
         let in_primary_package = env::var("CARGO_PRIMARY_PACKAGE").is_ok();
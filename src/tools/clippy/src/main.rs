@@ -3,7 +3,7 @@
 #![warn(rust_2018_idioms, unused_lifetimes)]
 
 use std::env;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::process::{self, Command};
 
 const CARGO_CLIPPY_HELP: &str = "Checks a package to catch common mistakes and improve your Rust code.
@@ -13,6 +13,8 @@
 
 Common options:
     --no-deps                Run Clippy only on the given crate, without linting the dependencies
+    --config-path PATH       Use this `clippy.toml`, or the one in this directory, instead of looking for one.
+                             Overrides the `CLIPPY_CONF_PATH` environment variable
     --fix                    Automatically apply lint suggestions. This flag implies `--no-deps` and `--all-targets`
     -h, --help               Print this message
     -V, --version            Print version info and exit
@@ -55,11 +57,8 @@ pub fn main() {
     }
 
     if let Some(pos) = env::args().position(|a| a == "--explain") {
-        if let Some(mut lint) = env::args().nth(pos + 1) {
-            lint.make_ascii_lowercase();
-            process::exit(clippy_lints::explain(
-                &lint.strip_prefix("clippy::").unwrap_or(&lint).replace('-', "_"),
-            ));
+        if let Some(lint) = env::args().nth(pos + 1) {
+            process::exit(clippy_lints::explain(&lint));
         } else {
             show_help();
         }
@@ -75,6 +74,7 @@ struct ClippyCmd {
     cargo_subcommand: &'static str,
     args: Vec<String>,
     clippy_args: Vec<String>,
+    conf_path: Option<PathBuf>,
 }
 
 impl ClippyCmd {
@@ -85,9 +85,19 @@ fn new<I>(mut old_args: I) -> Self
         let mut cargo_subcommand = "check";
         let mut args = vec![];
         let mut clippy_args: Vec<String> = vec![];
+        let mut conf_path = None;
 
-        for arg in old_args.by_ref() {
+        while let Some(arg) = old_args.next() {
+            if let Some(path) = arg.strip_prefix("--config-path=") {
+                conf_path = Some(path.into());
+                continue;
+            }
             match arg.as_str() {
+                "--config-path" => {
+                    // A missing path is reported by the driver like a path which doesn't exist
+                    conf_path = Some(old_args.next().unwrap_or_default().into());
+                    continue;
+                },
                 "--fix" => {
                     cargo_subcommand = "fix";
                     continue;
@@ -112,6 +122,7 @@ fn new<I>(mut old_args: I) -> Self
             cargo_subcommand,
             args,
             clippy_args,
+            conf_path: conf_path.or_else(|| env::var_os("CLIPPY_CONF_PATH").map(PathBuf::from)),
         }
     }
 
@@ -144,10 +155,22 @@ fn into_std_cmd(self) -> Command {
             .arg(self.cargo_subcommand)
             .args(&self.args);
 
+        // The driver runs in the directory of the package, so relative paths are resolved here
+        if let Some(conf_path) = &self.conf_path {
+            cmd.env("CLIPPY_CONF_PATH", absolute(conf_path));
+        }
+
         cmd
     }
 }
 
+fn absolute(path: &Path) -> PathBuf {
+    if path.as_os_str().is_empty() {
+        return PathBuf::new();
+    }
+    env::current_dir().map_or_else(|_| path.to_path_buf(), |dir| dir.join(path))
+}
+
 fn process<I>(old_args: I) -> Result<(), i32>
 where
     I: Iterator<Item = String>,
@@ -197,6 +220,30 @@ fn no_deps_not_duplicated_with_fix() {
         assert_eq!(cmd.clippy_args.iter().filter(|arg| *arg == "--no-deps").count(), 1);
     }
 
+    #[test]
+    fn fix_forwards_cargo_fix_args() {
+        // `main` skips `cargo clippy` before passing the arguments on
+        let args = "cargo clippy --fix --allow-dirty --allow-staged --broken-code -- -A clippy::all"
+            .split_whitespace()
+            .skip(2)
+            .map(ToString::to_string);
+        let cmd = ClippyCmd::new(args);
+        assert_eq!(cmd.args, ["--allow-dirty", "--allow-staged", "--broken-code"]);
+        assert!(cmd.clippy_args.iter().any(|arg| arg == "clippy::all"));
+    }
+
+    #[test]
+    fn config_path_is_not_forwarded() {
+        for args in [
+            "--config-path strict.toml --all-targets",
+            "--config-path=strict.toml --all-targets",
+        ] {
+            let cmd = ClippyCmd::new(args.split_whitespace().map(ToString::to_string));
+            assert_eq!(cmd.conf_path.as_deref(), Some("strict.toml".as_ref()));
+            assert_eq!(cmd.args, ["--all-targets"]);
+        }
+    }
+
     #[test]
     fn check() {
         let args = "cargo clippy".split_whitespace().map(ToString::to_string);
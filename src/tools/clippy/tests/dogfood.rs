@@ -59,12 +59,13 @@ fn run_metadata_collection_lint() {
     run_clippy_for_package("clippy_lints", &["-A", "unfulfilled_lint_expectations"]);
 
     // Check if cargo caching got in the way
-    if let Ok(file) = File::open(metadata_output_path) {
+    if let Ok(file) = File::open(&metadata_output_path) {
         if let Ok(metadata) = file.metadata() {
             if let Ok(last_modification) = metadata.modified() {
                 if last_modification > start_time {
                     // The output file has been modified. Most likely by a hungry
-                    // metadata collection monster. So We'll return.
+                    // metadata collection monster. So We'll only check it.
+                    check_lint_metadata(&metadata_output_path);
                     return;
                 }
             }
@@ -80,6 +81,91 @@ fn run_metadata_collection_lint() {
 
     // Running the collection again
     run_clippy_for_package("clippy_lints", &["-A", "unfulfilled_lint_expectations"]);
+    check_lint_metadata(&metadata_output_path);
+}
+
+/// Checks that the collected metadata matches the schema expected by the lint list and that every
+/// declared lint, apart from the internal ones, appears in it exactly once.
+#[cfg(feature = "internal")]
+fn check_lint_metadata(metadata_output_path: &std::path::Path) {
+    use serde::Deserialize;
+    use std::collections::BTreeSet;
+
+    // The fields are only read to check the schema.
+    #[allow(dead_code)]
+    #[derive(Deserialize)]
+    #[serde(deny_unknown_fields)]
+    struct LintMetadata {
+        id: String,
+        id_span: SerializableSpan,
+        group: String,
+        level: String,
+        docs: String,
+        version: String,
+        applicability: ApplicabilityInfo,
+        #[serde(default)]
+        former_ids: BTreeSet<String>,
+        #[serde(default)]
+        configuration: Vec<String>,
+    }
+
+    #[allow(dead_code)]
+    #[derive(Deserialize)]
+    #[serde(deny_unknown_fields)]
+    struct SerializableSpan {
+        path: String,
+        line: usize,
+    }
+
+    #[allow(dead_code)]
+    #[derive(Deserialize)]
+    #[serde(deny_unknown_fields)]
+    struct ApplicabilityInfo {
+        is_multi_part_suggestion: bool,
+        applicability: String,
+    }
+
+    let json = std::fs::read_to_string(metadata_output_path).unwrap();
+    let lints: Vec<LintMetadata> = serde_json::from_str(&json)
+        .unwrap_or_else(|e| panic!("`{}` doesn't match the schema: {e}", metadata_output_path.display()));
+
+    for lint in &lints {
+        let level = match lint.group.as_str() {
+            "correctness" => "deny",
+            "suspicious" | "style" | "complexity" | "perf" => "warn",
+            "restriction" | "pedantic" | "cargo" | "nursery" => "allow",
+            "deprecated" => "none",
+            group => panic!("lint `{}` has the unknown group `{group}`", lint.id),
+        };
+        assert_eq!(lint.level, level, "lint `{}` has the wrong level", lint.id);
+        assert!(
+            matches!(
+                lint.applicability.applicability.as_str(),
+                "Unspecified" | "HasPlaceholders" | "MaybeIncorrect" | "MachineApplicable" | "Unresolved"
+            ),
+            "lint `{}` has the unknown applicability `{}`",
+            lint.id,
+            lint.applicability.applicability,
+        );
+    }
+
+    let ids: Vec<&str> = lints.iter().map(|lint| lint.id.as_str()).collect();
+    let duplicates: Vec<_> = ids.iter().duplicates().collect();
+    assert!(duplicates.is_empty(), "lints collected more than once: {duplicates:?}");
+
+    let declared_lints_path = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("clippy_lints/src/declared_lints.rs");
+    let declared_lints = std::fs::read_to_string(declared_lints_path).unwrap();
+    let missing: Vec<String> = declared_lints
+        .lines()
+        .tuple_windows()
+        .filter(|(attr, _)| attr.trim() != r#"#[cfg(feature = "internal")]"#)
+        .filter_map(|(_, line)| {
+            let path = line.trim().strip_prefix("crate::")?.strip_suffix("_INFO,")?;
+            path.rsplit("::").next().map(str::to_lowercase)
+        })
+        .filter(|name| !ids.contains(&name.as_str()))
+        .collect();
+    assert!(missing.is_empty(), "lints missing from the metadata: {missing:?}");
 }
 
 fn run_clippy_for_package(project: &str, args: &[&str]) -> bool {
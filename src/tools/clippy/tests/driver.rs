@@ -0,0 +1,87 @@
+#![feature(lazy_cell)]
+
+use std::path::PathBuf;
+use std::process::{Command, Output};
+use test_utils::{CLIPPY_DRIVER_PATH, IS_RUSTC_TEST_SUITE};
+
+mod test_utils;
+
+/// Runs `clippy-driver` on a single file like `rustc` would be, outside of `cargo clippy`.
+fn run_driver(conf_dir: Option<&str>, conf_path: Option<&str>) -> Output {
+    let root = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+    let out_dir = root.join("target").join("driver_test");
+    let mut cmd = Command::new(&*CLIPPY_DRIVER_PATH);
+    cmd.current_dir(&root)
+        .env_remove("CLIPPY_ARGS")
+        .env_remove("CLIPPY_CONF_DIR")
+        .env_remove("CLIPPY_CONF_PATH")
+        .arg("tests/driver_test/single_file.rs")
+        .args(["--edition", "2021"])
+        .arg("--emit=metadata")
+        .arg("--out-dir")
+        .arg(&out_dir);
+    if let Some(conf_dir) = conf_dir {
+        cmd.env("CLIPPY_CONF_DIR", root.join(conf_dir));
+    }
+    if let Some(conf_path) = conf_path {
+        cmd.env("CLIPPY_CONF_PATH", root.join(conf_path));
+    }
+    let output = cmd.output().unwrap();
+    println!("status: {}", output.status);
+    println!("stdout: {}", String::from_utf8_lossy(&output.stdout));
+    println!("stderr: {}", String::from_utf8_lossy(&output.stderr));
+    output
+}
+
+#[test]
+fn standalone_driver_lints() {
+    if IS_RUSTC_TEST_SUITE {
+        return;
+    }
+    let output = run_driver(None, None);
+    assert!(output.status.success());
+
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert!(stderr.contains("warning: unneeded `return` statement"));
+    assert!(!stderr.contains("quux_custom`"));
+}
+
+#[test]
+fn standalone_driver_reads_clippy_conf_dir() {
+    if IS_RUSTC_TEST_SUITE {
+        return;
+    }
+    let output = run_driver(Some("tests/driver_test"), None);
+    assert!(output.status.success());
+
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert!(stderr.contains("use of a disallowed/placeholder name `quux_custom`"));
+}
+
+#[test]
+fn standalone_driver_reads_clippy_conf_path() {
+    if IS_RUSTC_TEST_SUITE {
+        return;
+    }
+    // `CLIPPY_CONF_PATH` takes precedence over `CLIPPY_CONF_DIR`
+    let output = run_driver(Some("tests"), Some("tests/driver_test/clippy.toml"));
+    assert!(output.status.success());
+
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert!(stderr.contains("use of a disallowed/placeholder name `quux_custom`"));
+}
+
+#[test]
+fn rustc_pass_through() {
+    if IS_RUSTC_TEST_SUITE {
+        return;
+    }
+    let output = Command::new(&*CLIPPY_DRIVER_PATH)
+        .args(["--rustc", "--version"])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(stdout.starts_with("rustc "), "{stdout}");
+}
@@ -0,0 +1,8 @@
+fn one() -> u32 {
+    return 1;
+}
+
+fn main() {
+    let quux_custom = one();
+    let _ = quux_custom;
+}
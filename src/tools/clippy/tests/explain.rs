@@ -0,0 +1,46 @@
+#![feature(lazy_cell)]
+
+use std::process::{Command, Output};
+use test_utils::{CARGO_CLIPPY_PATH, IS_RUSTC_TEST_SUITE};
+
+mod test_utils;
+
+fn explain(lint: &str) -> Output {
+    let output = Command::new(&*CARGO_CLIPPY_PATH)
+        .arg("clippy")
+        .args(["--explain", lint])
+        .output()
+        .unwrap();
+    println!("status: {}", output.status);
+    println!("stdout: {}", String::from_utf8_lossy(&output.stdout));
+    output
+}
+
+#[test]
+fn explain_known_lint() {
+    if IS_RUSTC_TEST_SUITE {
+        return;
+    }
+    for lint in ["needless_return", "clippy::needless_return", "needless-return"] {
+        let output = explain(lint);
+        assert!(output.status.success());
+
+        let stdout = String::from_utf8(output.stdout).unwrap();
+        assert!(stdout.contains("### What it does"));
+        assert!(stdout.contains("### Why is this bad?"));
+        assert!(stdout.contains("### Example"));
+    }
+}
+
+#[test]
+fn explain_unknown_lint() {
+    if IS_RUSTC_TEST_SUITE {
+        return;
+    }
+    let output = explain("needless_retrun");
+    assert!(!output.status.success());
+
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(stdout.contains("unknown lint: needless_retrun"));
+    assert!(stdout.contains("did you mean `needless_return`?"));
+}
@@ -10,4 +10,15 @@
     path
 });
 
+pub static CLIPPY_DRIVER_PATH: LazyLock<PathBuf> = LazyLock::new(|| {
+    let mut path = std::env::current_exe().unwrap();
+    assert!(path.pop()); // deps
+    path.set_file_name(if cfg!(windows) {
+        "clippy-driver.exe"
+    } else {
+        "clippy-driver"
+    });
+    path
+});
+
 pub const IS_RUSTC_TEST_SUITE: bool = option_env!("RUSTC_TEST_SUITE").is_some();
@@ -0,0 +1,15 @@
+#![warn(clippy::shadow_same, clippy::shadow_reuse, clippy::shadow_unrelated)]
+
+use std::sync::Mutex;
+
+fn main() {
+    let lock = Mutex::new(1);
+    let guard = &lock;
+    let guard = guard.lock().unwrap();
+    let _span = 1;
+    let _span = 2;
+
+    let x = *guard;
+    let x = 2;
+    let _ = x;
+}
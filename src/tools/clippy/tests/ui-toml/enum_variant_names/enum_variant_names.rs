@@ -0,0 +1,28 @@
+#![warn(clippy::enum_variant_names)]
+#![allow(dead_code)]
+
+// no error, below the configured threshold
+enum Shape {
+    KindCircle,
+    KindSquare,
+    KindTriangle,
+}
+
+enum Op {
+    CmdGet,
+    CmdSet,
+    CmdDelete,
+    CmdList,
+    CmdWatch,
+}
+
+// no error, `With` and `Without` are different words
+enum Seal {
+    WithWax,
+    WithoutWax,
+    WithRibbon,
+    WithoutRibbon,
+    WithStamp,
+}
+
+fn main() {}
@@ -0,0 +1,21 @@
+#![warn(clippy::excessive_nesting_in_match_arm)]
+#![allow(clippy::excessive_nesting)]
+
+fn at_threshold(a: Option<u8>) -> u8 {
+    match a {
+        Some(a) => a,
+        None => 0,
+    }
+}
+
+fn above_threshold(a: Option<u8>, b: Option<u8>) -> u8 {
+    match a {
+        Some(a) => match b {
+            Some(b) => a + b,
+            None => 0,
+        },
+        None => 0,
+    }
+}
+
+fn main() {}
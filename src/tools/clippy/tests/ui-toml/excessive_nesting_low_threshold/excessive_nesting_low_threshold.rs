@@ -0,0 +1,41 @@
+#![warn(clippy::excessive_nesting)]
+#![allow(unused)]
+#![allow(clippy::needless_if)]
+
+macro_rules! in_block {
+    ($e:expr) => {{ $e }};
+}
+
+struct S;
+
+impl S {
+    fn method(&self, x: bool) -> u32 {
+        if x {
+            return 1;
+        }
+        0
+    }
+}
+
+fn flat(x: bool) -> u32 {
+    if x { 1 } else { 2 }
+}
+
+fn nested(x: bool, y: bool) -> u32 {
+    if x {
+        if y {
+            return 1;
+        }
+    }
+    0
+}
+
+fn from_macro(x: bool) -> u32 {
+    if x { in_block!(1) } else { 0 }
+}
+
+fn main() {
+    fn inner() {
+        let _ = { 0 };
+    }
+}
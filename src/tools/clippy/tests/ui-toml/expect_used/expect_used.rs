@@ -45,3 +45,19 @@ fn util() {
         let _a: u8 = 5.try_into().expect("");
     }
 }
+
+#[test]
+fn test_nested_fn() {
+    // should not lint in functions nested in `#[test]` functions
+    fn nested() {
+        let _a: u8 = 6.try_into().expect("");
+    }
+    nested();
+    cfg_test_fn();
+}
+
+#[cfg(test)]
+fn cfg_test_fn() {
+    // should not lint in `#[cfg(test)]` items
+    let _a: u8 = 7.try_into().expect("");
+}
@@ -0,0 +1,13 @@
+#![warn(clippy::large_types_passed_by_value)]
+#![allow(dead_code)]
+
+#[derive(Clone, Copy)]
+struct Medium([u8; 300]);
+
+#[derive(Clone, Copy)]
+struct Large([u8; 1024]);
+
+fn medium(_x: Medium) {}
+fn large(_x: Large) {}
+
+fn main() {}
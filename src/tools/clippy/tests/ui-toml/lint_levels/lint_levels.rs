@@ -0,0 +1,20 @@
+fn main() {
+    let v = [1, 2, 3];
+    // `explicit_iter_loop` is enabled through the `pedantic` group
+    for x in v.iter() {
+        println!("{x}");
+    }
+
+    // `cast_lossless` is allowed
+    let x = 1u32;
+    let _ = x as u64;
+
+    attributes_take_precedence(&v);
+}
+
+#[allow(clippy::explicit_iter_loop)]
+fn attributes_take_precedence(v: &[u32]) {
+    for x in v.iter() {
+        println!("{x}");
+    }
+}
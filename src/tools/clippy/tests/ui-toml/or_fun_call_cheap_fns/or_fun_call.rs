@@ -0,0 +1,32 @@
+//@run-rustfix
+#![warn(clippy::or_fun_call)]
+#![allow(dead_code)]
+
+struct Config(u32);
+
+impl Config {
+    fn new(id: u32) -> Self {
+        Self(id)
+    }
+}
+
+fn make_id() -> u32 {
+    0
+}
+
+fn expensive() -> u32 {
+    0
+}
+
+fn main() {
+    let config: Option<Config> = None;
+    let _ = config.unwrap_or(Config::new(1));
+
+    // the argument of the cheap function is expensive
+    let config: Option<Config> = None;
+    let _ = config.unwrap_or(Config::new(expensive()));
+
+    let id: Option<u32> = None;
+    let _ = id.unwrap_or(make_id());
+    let _ = id.unwrap_or(expensive());
+}
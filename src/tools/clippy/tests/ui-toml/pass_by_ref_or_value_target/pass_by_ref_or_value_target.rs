@@ -0,0 +1,31 @@
+//@compile-flags: --target msp430-none-elf --crate-type=lib
+
+#![feature(no_core, lang_items)]
+#![no_core]
+#![warn(clippy::trivially_copy_pass_by_ref, clippy::large_types_passed_by_value)]
+
+// The configured limits are used as they are on every target, instead of the defaults of 4 and 64
+// bytes for 16-bit targets. `core` isn't available for the target, so the lang items needed by the
+// lints are declared here.
+
+#[lang = "sized"]
+trait Sized {}
+#[lang = "copy"]
+trait Copy {}
+#[lang = "freeze"]
+unsafe trait Freeze {}
+
+impl Copy for u8 {}
+
+struct Medium([u8; 12]);
+impl Copy for Medium {}
+
+struct Large([u8; 200]);
+impl Copy for Large {}
+
+struct Huge([u8; 600]);
+impl Copy for Huge {}
+
+fn medium_by_ref(_x: &Medium) {}
+fn large_by_value(_x: Large) {}
+fn huge_by_value(_x: Huge) {}
@@ -0,0 +1,53 @@
+//@run-rustfix
+#![warn(clippy::single_match, clippy::single_match_else)]
+#![allow(unused)]
+
+enum E {
+    A(i32),
+    B,
+    C,
+}
+
+fn foo(_: i32) {}
+fn bar() {}
+
+fn main() {
+    let x = Some(1);
+    match x {
+        Some(v) => foo(v),
+        _ => bar(),
+    }
+
+    // Used as an expression
+    let e = E::A(1);
+    let _y = match e {
+        E::A(v) => v + 1,
+        _ => 0,
+    };
+
+    // Comments in the `else` arm are kept
+    match x {
+        Some(v) => foo(v),
+        _ => {
+            // keep this comment
+            bar();
+        },
+    }
+
+    // The `if` needs to be wrapped in parentheses
+    let e = E::A(1);
+    let _z = match e {
+        E::A(v) => v,
+        _ => 0,
+    }
+    .count_ones();
+
+    // Larger `else` arms are still linted by `single_match_else`
+    match x {
+        Some(v) => foo(v),
+        _ => {
+            bar();
+            bar();
+        },
+    }
+}
@@ -41,4 +41,14 @@ enum ParseErrorPrivate<T> {
     Parse(T, String),
 }
 
+// trailing acronym
+struct ClientID;
+
+// consecutive acronyms can't be told apart either
+struct HTTPAPIClient;
+
+// C types are not linted
+#[repr(C)]
+struct TCPHeader;
+
 fn main() {}
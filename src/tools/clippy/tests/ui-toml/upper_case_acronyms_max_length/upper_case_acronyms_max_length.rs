@@ -0,0 +1,16 @@
+#![warn(clippy::upper_case_acronyms)]
+
+// two-letter acronyms are accepted
+struct IOError;
+struct ClientID;
+enum Os {
+    OS,
+    MacOSX,
+}
+
+struct HTTPSConnection;
+
+// consecutive acronyms are counted as one
+struct IOHTTPClient;
+
+fn main() {}
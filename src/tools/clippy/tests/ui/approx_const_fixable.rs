@@ -0,0 +1,13 @@
+//@run-rustfix
+#![warn(clippy::approx_constant)]
+#![allow(clippy::excessive_precision, unused)]
+
+fn main() {
+    let frac_1_sqrt_2 = 0.707106;
+    let pi = 3.14159265358979323846;
+    let pi_f32: f32 = 3.141593;
+    let tau = 6.283185307179586f64;
+
+    // Rough values written with few digits aren't replaced automatically
+    let rough_pi = 3.14;
+}
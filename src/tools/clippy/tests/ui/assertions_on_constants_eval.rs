@@ -0,0 +1,75 @@
+#![allow(dead_code, unconditional_recursion)]
+
+enum Level {
+    Low = 1,
+    Mid = 5,
+    High,
+}
+
+#[repr(i8)]
+enum Signed {
+    Negative = -1,
+}
+
+const fn double(x: u32) -> u32 {
+    x * 2
+}
+
+const fn is_small(x: u32) -> bool {
+    double(x) < 10
+}
+
+const fn factorial(n: u64) -> u64 {
+    if n == 0 {
+        1
+    } else {
+        n * factorial(n - 1)
+    }
+}
+
+const fn forever(n: u32) -> u32 {
+    forever(n + 1)
+}
+
+fn main() {
+    // Associated consts and the integer limit functions
+    assert!(i32::MAX > 0);
+    assert!(u32::max_value() == 4_294_967_295);
+    assert!(i8::min_value() == -128);
+
+    // Discriminants of unit variants
+    assert!(Level::Mid as u8 == 5);
+    assert!(Level::High as u8 == 6);
+    assert!(Signed::Negative as i32 == -1);
+    assert!(Signed::Negative as u8 == 255);
+
+    // Calls to `const fn`s with constant arguments
+    assert!(is_small(4));
+    assert!(is_small(5));
+    assert!(factorial(5) == 120);
+
+    // Don't lint, the argument isn't constant
+    let x = 4;
+    assert!(is_small(x));
+
+    // Don't lint, the evaluation runs out of its call budget
+    assert!(factorial(100) > 0);
+    assert!(forever(0) == 0);
+
+    // Don't lint, the value depends on the target
+    assert!(is_unix());
+    assert!(!is_unix_or(false));
+    assert!(has_out_dir());
+}
+
+const fn is_unix() -> bool {
+    cfg!(unix)
+}
+
+const fn is_unix_or(x: bool) -> bool {
+    x || is_unix()
+}
+
+const fn has_out_dir() -> bool {
+    option_env!("OUT_DIR").is_some()
+}
@@ -1,6 +1,6 @@
 //@run-rustfix
 #![deny(clippy::bind_instead_of_map)]
-#![allow(clippy::uninlined_format_args)]
+#![allow(clippy::redundant_closure, clippy::uninlined_format_args)]
 
 // need a main anyway, use it get rid of unused warnings too
 pub fn main() {
@@ -14,6 +14,8 @@ pub fn main() {
     // Different type
     let x: Result<u32, &str> = Ok(1);
     let _ = x.and_then(Ok);
+    let _ = x.and_then(|o| Ok(o));
+    let _ = x.or_else(|e| Err(e));
 }
 
 pub fn foo() -> Option<String> {
@@ -0,0 +1,39 @@
+//@run-rustfix
+#![warn(clippy::cast_lossless_on_ffi_boundary)]
+#![allow(clippy::cast_lossless, clippy::unnecessary_cast, unused)]
+
+extern "C" {
+    fn get_count() -> u32;
+    fn get_flag() -> u8;
+}
+
+fn calls() {
+    let count = unsafe { get_count() } as u64;
+    let flag = unsafe { get_flag() as i32 };
+}
+
+extern "C" fn narrow(x: u8) -> u16 {
+    x as u16
+}
+
+extern "C" fn early_return(x: u16, y: bool) -> u64 {
+    if y {
+        return x as u64;
+    }
+    0
+}
+
+fn no_lint(x: u8) -> u16 {
+    // Not an FFI function
+    let _ = x as u32;
+    x as u16
+}
+
+extern "C" fn no_lint_inside(x: u8) -> u16 {
+    // Not the returned value
+    let _ = x as u32;
+    let f = || x as u16;
+    f()
+}
+
+fn main() {}
@@ -1,3 +1,4 @@
+//@run-rustfix
 #![warn(clippy::cmp_null)]
 #![allow(unused_mut)]
 
@@ -14,4 +15,14 @@ fn main() {
     if m == ptr::null_mut() {
         println!("This is surprising, too!");
     }
+
+    let _ = p as usize == 0;
+    let _ = 0 != m as usize;
+    let _ = (p as *const ()) == ptr::null();
+    let _ = ptr::null_mut() != m;
+
+    // Compares two pointers which aren't null
+    let _ = p == m as *const usize;
+    let _ = p as usize == m as usize;
+    let _ = p as usize == 1;
 }
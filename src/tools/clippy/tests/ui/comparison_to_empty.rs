@@ -3,11 +3,29 @@
 #![warn(clippy::comparison_to_empty)]
 #![allow(clippy::useless_vec)]
 
+struct Wrapper;
+
+impl Wrapper {
+    fn is_empty(&self) -> bool {
+        true
+    }
+}
+
+impl PartialEq<String> for Wrapper {
+    fn eq(&self, _: &String) -> bool {
+        true
+    }
+}
+
 fn main() {
     // Disallow comparisons to empty
     let s = String::new();
     let _ = s == "";
     let _ = s != "";
+    let _ = s == String::new();
+    let _ = String::new() != s;
+    let s = "";
+    let _ = s == String::new();
 
     let v = vec![0];
     let _ = v == [];
@@ -21,4 +39,7 @@ fn main() {
     let v = vec![0];
     let _ = v == [0];
     let _ = v != [0];
+
+    // Allow comparisons of other types to `String::new()`
+    let _ = Wrapper == String::new();
 }
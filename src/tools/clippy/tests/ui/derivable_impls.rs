@@ -1,7 +1,11 @@
 //@run-rustfix
+//@aux-build:proc_macros.rs:proc-macro
 
 #![allow(dead_code)]
 
+extern crate proc_macros;
+use proc_macros::with_span;
+
 use std::collections::HashMap;
 
 struct FooDefault<'a> {
@@ -325,4 +329,19 @@ fn default() -> Self {
     }
 }
 
+// Do not lint impls generated by proc macros with call-site spans
+with_span! {
+    span
+
+    struct FromProcMacro {
+        a: bool,
+    }
+
+    impl Default for FromProcMacro {
+        fn default() -> Self {
+            Self { a: false }
+        }
+    }
+}
+
 fn main() {}
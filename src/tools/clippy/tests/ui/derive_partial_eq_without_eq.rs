@@ -101,6 +101,20 @@ pub enum EnumNotEq {
 #[derive(PartialEq, Eq)]
 pub struct GenericPhantom<T>(core::marker::PhantomData<T>);
 
+// `T: Eq` is only required by the derived `Eq` impl, not by the type itself
+#[derive(PartialEq)]
+pub struct GenericWhere<T>(T)
+where
+    T: PartialEq;
+
+#[derive(PartialEq)]
+pub struct GenericWhereField<T>
+where
+    T: PartialEq,
+{
+    foo: Vec<T>,
+}
+
 mod _hidden {
     #[derive(PartialEq)]
     pub struct Reexported;
@@ -189,4 +189,11 @@ enum Enum {
     }
 }
 
+// should not lint, the shared prefix is the name of one of the variants
+enum IoErrors {
+    IoError,
+    IoErrorKind,
+    IoErrorCode,
+}
+
 fn main() {}
@@ -0,0 +1,88 @@
+#![warn(clippy::excessive_nesting_in_match_arm)]
+#![allow(clippy::single_match, clippy::match_single_binding)]
+
+fn at_threshold(a: Option<u8>, b: Option<u8>, c: Option<u8>) -> u8 {
+    match a {
+        Some(a) => match b {
+            Some(b) => match c {
+                Some(c) => a + b + c,
+                None => 0,
+            },
+            None => 0,
+        },
+        None => 0,
+    }
+}
+
+fn above_threshold(a: Option<u8>, b: Option<u8>, c: Option<u8>, d: Option<u8>) -> u8 {
+    match a {
+        Some(a) => match b {
+            Some(b) => match c {
+                Some(c) => match d {
+                    Some(d) => a + b + c + d,
+                    None => 0,
+                },
+                None => 0,
+            },
+            None => 0,
+        },
+        None => 0,
+    }
+}
+
+fn far_above_threshold(a: Option<u8>, b: Option<u8>, c: Option<u8>, d: Option<u8>, e: Option<u8>) -> u8 {
+    // Only the outermost `match` beyond the threshold is linted
+    match a {
+        Some(a) => match b {
+            Some(b) => match c {
+                Some(c) => match d {
+                    Some(d) => match e {
+                        Some(e) => a + b + c + d + e,
+                        None => 0,
+                    },
+                    None => 0,
+                },
+                None => 0,
+            },
+            None => 0,
+        },
+        None => 0,
+    }
+}
+
+fn siblings(a: Option<u8>, b: Option<u8>) -> u8 {
+    // Matches next to each other don't add to the depth
+    let x = match a {
+        Some(a) => a,
+        None => 0,
+    };
+    let y = match b {
+        Some(b) => b,
+        None => 0,
+    };
+    let z = match (a, b) {
+        (Some(a), Some(b)) => a + b,
+        _ => 0,
+    };
+    x + y + z
+}
+
+fn desugared(a: Option<u8>, b: Option<u8>, v: &[Option<u8>]) -> Option<u8> {
+    // `for` loops, `?` and macros don't count
+    match a {
+        Some(a) => match b {
+            Some(b) => {
+                for x in v {
+                    if matches!(x, Some(1)) {
+                        return Some(x? + a + b);
+                    }
+                }
+                None
+            },
+            None => None,
+        },
+        None => None,
+    }
+}
+
+fn main() {}
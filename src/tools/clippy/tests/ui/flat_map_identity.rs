@@ -14,4 +14,7 @@ fn main() {
 
     let iterator = [[0, 1], [2, 3], [4, 5]].iter();
     let _ = iterator.flat_map(|x| return x);
+
+    let iterator = vec![Some(1), None, Some(2)].into_iter();
+    let _ = iterator.flat_map(|x| x);
 }
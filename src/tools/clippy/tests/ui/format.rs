@@ -91,4 +91,8 @@ fn main() {
     let _ = format!("{abc}");
     let xx = "xx";
     let _ = format!("{xx}");
+
+    // Positional arguments
+    let _ = format!("{0}", x);
+    let _ = format!("{0}{0}", x); // Don't lint, the argument is used twice
 }
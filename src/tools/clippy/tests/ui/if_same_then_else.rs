@@ -214,4 +214,32 @@ fn do_not_lint() {
     }
 }
 
+const ANSWER: u8 = 42;
+
+fn synonymous_blocks(x: bool, v: &[u8]) {
+    let _ = if x {
+        //~^ ERROR: this `if` has identical blocks
+        ANSWER
+    } else {
+        42
+    };
+
+    if x {
+        //~^ ERROR: this `if` has identical blocks
+        'a: loop {
+            if v.is_empty() {
+                break 'a;
+            }
+        }
+        v.iter().filter(|a| **a > 1).count();
+    } else {
+        'b: loop {
+            if v.is_empty() {
+                break 'b;
+            }
+        }
+        v.iter().filter(|b| **b > 1).count();
+    }
+}
+
 fn main() {}
@@ -125,4 +125,10 @@ fn to_owned(&self) -> Self {
     }
     let no_clone = &NoClone;
     let _ = no_clone.to_owned();
+    // an owned `Vec` reached through a field
+    struct Buffer {
+        data: Vec<u8>,
+    }
+    let buffer = Buffer { data: vec![1, 2] };
+    let _ = buffer.data.to_vec();
 }
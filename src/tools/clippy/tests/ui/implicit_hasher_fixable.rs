@@ -0,0 +1,56 @@
+//@run-rustfix
+#![deny(clippy::implicit_hasher)]
+#![allow(unused)]
+
+use std::collections::{HashMap, HashSet};
+use std::collections::HashMap as Map;
+use std::hash::Hash;
+
+pub fn with_generics<K: Hash + Eq>(map: &HashMap<K, u32>) -> usize {
+    map.len()
+}
+
+pub fn with_where_clause<T>(set: &mut HashSet<T>)
+where
+    T: Hash + Eq + Clone,
+{
+    set.clear();
+}
+
+pub fn with_constructors(map: &mut HashMap<u32, u32>) {
+    *map = HashMap::new();
+    *map = HashMap::with_capacity(10);
+}
+
+pub fn with_aliased_constructors(map: &mut HashMap<u32, u32>) {
+    *map = Map::<u32, u32>::new();
+    *map = std::collections::HashMap::with_capacity(10);
+}
+
+pub struct Cache<M>(pub M);
+
+impl Cache<HashSet<u32>> {
+    pub fn empty() -> Self {
+        Cache(HashSet::new())
+    }
+}
+
+pub trait Summary {
+    fn summary(&self) -> usize;
+}
+
+impl<K: Hash + Eq, V> Summary for HashMap<K, V> {
+    fn summary(&self) -> usize {
+        self.len()
+    }
+}
+
+fn make_map() -> HashMap<u32, u32> {
+    HashMap::new()
+}
+
+pub fn with_other_constructor(map: &mut HashMap<u32, u32>) {
+    *map = make_map();
+}
+
+fn main() {}
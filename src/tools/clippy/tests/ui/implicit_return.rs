@@ -1,7 +1,13 @@
 //@run-rustfix
 #![feature(lint_reasons)]
 #![warn(clippy::implicit_return)]
-#![allow(clippy::needless_return, clippy::needless_bool, unused, clippy::never_loop)]
+#![allow(
+    clippy::needless_return,
+    clippy::needless_bool,
+    clippy::needless_match_on_bool_returning_bool,
+    unused,
+    clippy::never_loop
+)]
 
 fn test_end_of_fn() -> bool {
     if true {
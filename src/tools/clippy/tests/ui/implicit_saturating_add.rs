@@ -152,3 +152,37 @@ fn main() {
         u_32 += 1;
     }
 }
+
+fn increments(mut x: u32, mut y: i8) {
+    const STEP: u32 = 4;
+
+    // Not applied, the guard leaves `x` in `u32::MAX - 4..u32::MAX` unchanged
+    if x <= u32::MAX - 5 {
+        x += 5;
+    }
+
+    if x <= u32::MAX - STEP {
+        x += STEP;
+    }
+
+    if x != u32::MAX {
+        x += 1_u32;
+    }
+
+    if y <= i8::MAX - 2 {
+        y += 2;
+    }
+
+    // The guard doesn't match the increment
+    if x < u32::MAX {
+        x += 2;
+    }
+
+    if x <= u32::MAX - 5 {
+        x += 4;
+    }
+
+    if y <= i8::MAX {
+        y += -1;
+    }
+}
@@ -29,4 +29,12 @@ fn main() {
 
     // Issue #6703
     let _: Vec<isize> = v.iter().copied().collect();
+
+    // `VecDeque` receivers
+    let deque: VecDeque<isize> = VecDeque::from([1, 2, 3]);
+    let _: Vec<isize> = deque.iter().cloned().collect();
+    let deque_ref = &deque;
+    let _: Vec<isize> = deque_ref.iter().copied().collect();
+    // Not collected into a `Vec`
+    let _: HashSet<isize> = deque.iter().copied().collect();
 }
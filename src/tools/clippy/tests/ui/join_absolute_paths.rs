@@ -0,0 +1,21 @@
+#![warn(clippy::join_absolute_paths)]
+
+use std::path::{Path, PathBuf};
+
+fn main() {
+    let path = Path::new("/bin");
+    let _ = path.join("/sh");
+
+    let buf = PathBuf::from("/home");
+    let _ = buf.join("//user");
+
+    // no lint: the argument is relative or not a literal
+    let _ = path.join("sh");
+    let name = "/sh";
+    let _ = path.join(name);
+    // no lint: only absolute on Windows
+    let _ = path.join("C:\\sh");
+    let _ = path.join("\\sh");
+    // no lint: not a path
+    let _ = ["a", "b"].join("/");
+}
@@ -229,7 +229,7 @@ pub fn len(&self) -> Result<usize, ()> {
         Ok(0)
     }
 
-    // Differing result types
+    // Differing result types, but `len` returning a `Result` is not checked
     pub fn is_empty(&self) -> Option<bool> {
         Some(true)
     }
@@ -420,4 +420,42 @@ pub fn is_empty(&self) -> Result<bool, u16> {
     }
 }
 
+pub struct ConstLen;
+impl ConstLen {
+    pub const fn len(&self) -> usize {
+        0
+    }
+}
+
+pub struct MutLen;
+impl MutLen {
+    pub fn len(&mut self) -> u32 {
+        0
+    }
+}
+
+// `is_empty` is provided by a trait
+pub struct TraitIsEmpty;
+impl TraitIsEmpty {
+    pub fn len(&self) -> usize {
+        0
+    }
+}
+
+pub trait IsEmpty {
+    fn is_empty(&self) -> bool {
+        true
+    }
+}
+
+impl IsEmpty for TraitIsEmpty {}
+
+// `len` doesn't return an integer or an `Option` of one
+pub struct ResultLenWithoutIsEmpty;
+impl ResultLenWithoutIsEmpty {
+    pub fn len(&self) -> Result<usize, String> {
+        Ok(0)
+    }
+}
+
 fn main() {}
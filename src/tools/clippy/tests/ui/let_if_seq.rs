@@ -4,7 +4,8 @@
     clippy::similar_names,
     clippy::disallowed_names,
     clippy::branches_sharing_code,
-    clippy::needless_late_init
+    clippy::needless_late_init,
+    clippy::manual_slice_contains
 )]
 #![warn(clippy::useless_let_if_seq)]
 
@@ -112,6 +113,17 @@ fn main() {
 
     baz = 1337;
 
+    // the local is used inside of closures
+    let mut in_cond = 0;
+    if [1, 2].iter().any(|&i| i == in_cond) {
+        in_cond = 1;
+    }
+
+    let mut in_value = 0;
+    if f() {
+        in_value = [1, 2].iter().map(|&i| i + in_value).sum();
+    }
+
     // issue 3043 - types with interior mutability should not trigger this lint
     use std::cell::Cell;
     let mut val = Cell::new(1);
@@ -0,0 +1,59 @@
+//@run-rustfix
+#![warn(clippy::manual_ascii_case_conversion)]
+#![allow(unused)]
+
+fn chars(c: char) {
+    let _ = if c.is_ascii_uppercase() {
+        c.to_ascii_lowercase()
+    } else {
+        c
+    };
+    let _ = if c.is_ascii_lowercase() {
+        c.to_ascii_uppercase()
+    } else {
+        c
+    };
+}
+
+fn bytes(b: u8, bs: &[u8]) {
+    let _ = if b.is_ascii_uppercase() {
+        b.to_ascii_lowercase()
+    } else {
+        b
+    };
+    let _ = if bs[0].is_ascii_lowercase() {
+        bs[0].to_ascii_uppercase()
+    } else {
+        bs[0]
+    };
+}
+
+fn no_lint(c: char, d: char, mut it: impl Iterator<Item = char>) {
+    // The check doesn't match the conversion
+    let _ = if c.is_ascii_lowercase() {
+        c.to_ascii_lowercase()
+    } else {
+        c
+    };
+    // Different values
+    let _ = if c.is_ascii_uppercase() {
+        d.to_ascii_lowercase()
+    } else {
+        c
+    };
+    let _ = if c.is_ascii_uppercase() {
+        c.to_ascii_lowercase()
+    } else {
+        d
+    };
+    // Side effects
+    let _ = if it.next().unwrap().is_ascii_uppercase() {
+        it.next().unwrap().to_ascii_lowercase()
+    } else {
+        it.next().unwrap()
+    };
+    // Not an ASCII check
+    let _ = if c.is_uppercase() { c.to_ascii_lowercase() } else { c };
+}
+
+fn main() {}
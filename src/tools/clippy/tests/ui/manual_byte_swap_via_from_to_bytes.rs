@@ -0,0 +1,26 @@
+//@run-rustfix
+#![warn(clippy::manual_byte_swap_via_from_to_bytes)]
+#![allow(unused)]
+
+fn lint(x: u32, y: i64) {
+    let _ = u32::from_le_bytes(x.to_be_bytes());
+    let _ = i64::from_be_bytes(y.to_le_bytes());
+    let _ = u32::from_be_bytes(u32::to_le_bytes(x + 1));
+    let _ = u32::from_le_bytes(x.to_le_bytes());
+    let _ = i64::from_ne_bytes(y.to_ne_bytes());
+    let _ = 2 * u32::from_le_bytes((x + 1).to_le_bytes());
+}
+
+fn no_lint(x: u32, y: i32, r: &u32) {
+    // The type changes
+    let _ = u32::from_le_bytes(y.to_be_bytes());
+    let _ = u64::from_le_bytes([x.to_be_bytes(), x.to_be_bytes()].concat().try_into().unwrap());
+    // The native byte order depends on the target
+    let _ = u32::from_le_bytes(x.to_ne_bytes());
+    let _ = u32::from_ne_bytes(x.to_be_bytes());
+    // The bytes don't come from an integer
+    let _ = u32::from_le_bytes([1, 2, 3, 4]);
+    let _ = u32::from_le_bytes(r.to_be_bytes());
+}
+
+fn main() {}
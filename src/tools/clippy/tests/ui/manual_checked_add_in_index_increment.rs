@@ -0,0 +1,64 @@
+#![warn(clippy::manual_checked_add_in_index_increment)]
+#![allow(unused_assignments)]
+
+struct Ring {
+    pos: usize,
+    items: Vec<u8>,
+}
+
+fn next(buf: &[u8], mut i: usize) -> usize {
+    i += 1;
+    if i >= buf.len() {
+        i = 0;
+    }
+    i
+}
+
+fn last(len: usize, mut i: usize) {
+    i += 1;
+    if len == i {
+        i = 0
+    }
+}
+
+impl Ring {
+    fn advance(&mut self) {
+        self.pos += 1;
+        if self.pos >= self.items.len() {
+            self.pos = 0;
+        }
+    }
+}
+
+fn no_lint(len: usize, mut i: usize, j: &mut usize) {
+    // resets a different variable
+    i += 1;
+    if i >= len {
+        *j = 0;
+    }
+    // steps by two
+    i += 2;
+    if i >= len {
+        i = 0;
+    }
+    // does more than resetting
+    i += 1;
+    if i >= len {
+        i = 0;
+        *j += 1;
+    }
+    // has an `else`
+    i += 1;
+    if i >= len {
+        i = 0;
+    } else {
+        *j = 1;
+    }
+    // compares the other way
+    i += 1;
+    if i < len {
+        i = 0;
+    }
+}
+
+fn main() {}
@@ -0,0 +1,39 @@
+//@run-rustfix
+#![warn(clippy::manual_checked_div)]
+
+fn unsigned(a: u32, b: u32) {
+    let _ = if b != 0 { Some(a / b) } else { None };
+    let _ = if b == 0 { None } else { Some(a % b) };
+    let _ = if 0 != b { Some((a + 1) / b) } else { None };
+}
+
+fn signed(a: i64, b: i64) {
+    let _ = if b != 0 { Some(a / b) } else { None };
+    let _ = if b != 0 { Some(a % b) } else { None };
+}
+
+fn divisor() -> u32 {
+    2
+}
+
+fn no_lint(a: u32, b: u32, c: u32, x: f64, y: f64) {
+    // Different divisor.
+    let _ = if b != 0 { Some(a / c) } else { None };
+    // Not a division.
+    let _ = if b != 0 { Some(a * b) } else { None };
+    // Not a zero check.
+    let _ = if b > 0 { Some(a / b) } else { None };
+    let _ = if b != 1 { Some(a / b) } else { None };
+    // Floats.
+    let _ = if y != 0.0 { Some(x / y) } else { None };
+    // The divisor has side effects.
+    let _ = if divisor() != 0 { Some(a / divisor()) } else { None };
+    // Something other than `None` for a zero divisor.
+    let _ = if b != 0 { Some(a / b) } else { Some(0) };
+}
+
+fn main() {
+    unsigned(4, 2);
+    signed(4, 2);
+    no_lint(4, 2, 1, 4.0, 2.0);
+}
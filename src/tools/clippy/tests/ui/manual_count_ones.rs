@@ -0,0 +1,63 @@
+#![warn(clippy::manual_count_ones)]
+#![allow(unused)]
+
+fn lint(mut x: u32, mut y: u8, mut z: u64) {
+    let mut count = 0;
+    while x != 0 {
+        count += x & 1;
+        x >>= 1;
+    }
+
+    let mut count = 0u32;
+    while y > 0 {
+        count += (1 & y) as u32;
+        y >>= 1;
+    }
+
+    let mut count = 0;
+    while z != 0 {
+        if z & 1 == 1 {
+            count += 1;
+        }
+        z >>= 1;
+    }
+}
+
+fn no_lint(mut x: u32, mut i: i32, shift: u32) {
+    // Shift by a variable amount
+    let mut count = 0;
+    while x != 0 {
+        count += x & 1;
+        x >>= shift;
+    }
+
+    // Different mask
+    let mut count = 0;
+    while x != 0 {
+        count += x & 2;
+        x >>= 1;
+    }
+
+    // Signed integers never reach zero when negative
+    let mut count = 0;
+    while i != 0 {
+        count += i & 1;
+        i >>= 1;
+    }
+
+    // Skips the lowest bit
+    let mut count = 0;
+    while x != 0 {
+        x >>= 1;
+        count += x & 1;
+    }
+
+    // Counts something else
+    let mut count = 0;
+    while x != 0 {
+        count += 1;
+        x >>= 1;
+    }
+}
+
+fn main() {}
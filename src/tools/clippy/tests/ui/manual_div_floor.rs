@@ -0,0 +1,40 @@
+#![feature(int_roundings)]
+#![warn(clippy::manual_div_floor)]
+#![allow(unused_comparisons, clippy::absurd_extreme_comparisons)]
+
+fn main() {
+    let (a, b) = (7i32, -2i32);
+    let _ = if a % b != 0 && (a < 0) != (b < 0) {
+        a / b - 1
+    } else {
+        a / b
+    };
+    let _ = if (b < 0) != (a < 0) && a % b != 0 {
+        a / b - 1
+    } else {
+        a / b
+    };
+
+    // unsigned division already floors
+    let (c, d) = (7u32, 2u32);
+    let _ = if c % d != 0 && (c < 0) != (d < 0) {
+        c / d - 1
+    } else {
+        c / d
+    };
+
+    // different operands
+    let _ = if a % b != 0 && (a < 0) != (b < 0) {
+        b / a - 1
+    } else {
+        a / b
+    };
+    // not a floor correction
+    let _ = if a % b != 0 { a / b - 1 } else { a / b };
+    let _ = if a % b != 0 && (a < 0) != (b < 0) {
+        a / b + 1
+    } else {
+        a / b
+    };
+}
+
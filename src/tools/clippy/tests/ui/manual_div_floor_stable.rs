@@ -0,0 +1,11 @@
+#![warn(clippy::manual_div_floor)]
+
+// `div_floor` is unstable
+fn main() {
+    let (a, b) = (7i32, -2i32);
+    let _ = if a % b != 0 && (a < 0) != (b < 0) {
+        a / b - 1
+    } else {
+        a / b
+    };
+}
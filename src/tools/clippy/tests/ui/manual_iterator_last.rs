@@ -0,0 +1,67 @@
+#![warn(clippy::manual_iterator_last)]
+#![allow(unused)]
+
+fn main() {
+    let xs = vec![1, 2, 3];
+
+    let mut last = None;
+    for x in &xs {
+        last = Some(x);
+    }
+
+    let mut last = None;
+    for x in xs.iter() {
+        last = Some(x)
+    }
+
+    let mut last = None;
+    for c in "abc".chars() {
+        last = Some(c);
+    }
+
+    let mut last = None;
+    for i in 0..10 {
+        last = Some(i);
+    }
+
+    let strings = vec![String::new()];
+    let mut last = None;
+    for s in strings {
+        last = Some(s);
+    }
+}
+
+fn slice(xs: &[i32]) {
+    let mut last = None;
+    for x in xs {
+        last = Some(x);
+    }
+}
+
+fn no_lint(xs: &[i32]) {
+    // Doesn't start at `None`
+    let mut last = Some(&0);
+    for x in xs {
+        last = Some(x);
+    }
+
+    // Stores something else than the element
+    let mut last = None;
+    for x in xs {
+        last = Some(x * 2);
+    }
+
+    // Additional side effects
+    let mut last = None;
+    for x in xs {
+        last = Some(x);
+        println!("{x}");
+    }
+
+    // Not initialized right before the loop
+    let mut last = None;
+    let first = xs.first();
+    for x in xs {
+        last = Some(x);
+    }
+}
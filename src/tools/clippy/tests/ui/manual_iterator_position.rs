@@ -0,0 +1,53 @@
+#![warn(clippy::manual_iterator_position)]
+
+fn main() {
+    let xs = [1_usize, 2, 3];
+
+    let mut idx = None;
+    for (i, x) in xs.iter().enumerate() {
+        if *x == 2 {
+            idx = Some(i);
+            break;
+        }
+    }
+    let _ = idx;
+
+    let mut found = None;
+    for (_, x) in xs.iter().enumerate() {
+        if *x > 1 {
+            found = Some(x);
+            break;
+        }
+    }
+    let _ = found;
+
+    // the index is part of the predicate
+    let mut idx = None;
+    for (i, x) in xs.iter().enumerate() {
+        if *x == i {
+            idx = Some(i);
+            break;
+        }
+    }
+    let _ = idx;
+
+    // the element is used beyond the predicate
+    let mut idx = None;
+    for (i, x) in xs.iter().enumerate() {
+        if *x == 2 {
+            println!("{x}");
+            idx = Some(i);
+            break;
+        }
+    }
+    let _ = idx;
+
+    // the last match is stored
+    let mut idx = None;
+    for (i, x) in xs.iter().enumerate() {
+        if *x > 1 {
+            idx = Some(i);
+        }
+    }
+    let _ = idx;
+}
@@ -0,0 +1,64 @@
+#![warn(clippy::manual_iterator_sum)]
+#![allow(unused)]
+
+fn main() {
+    let xs = vec![1, 2, 3];
+
+    let mut total = 0;
+    for x in &xs {
+        total += x;
+    }
+
+    let mut product = 1u64;
+    for x in 1..=10 {
+        product *= x;
+    }
+
+    let floats = [1.5f32, 2.5];
+    let mut sum = 0.0;
+    for f in floats {
+        sum += f
+    }
+
+    let mut total = 0;
+    for x in xs.iter() {
+        total += x;
+    }
+}
+
+fn no_lint(xs: &[i32]) {
+    // Doesn't start at zero
+    let mut total = 5;
+    for x in xs {
+        total += x;
+    }
+
+    // Adds something else than the element
+    let mut total = 0;
+    for x in xs {
+        total += x * 2;
+    }
+    let mut total = 0;
+    for x in xs {
+        total += *x;
+    }
+
+    // The accumulator is read in the loop
+    let mut total = 0;
+    for x in xs {
+        total += x;
+        println!("{total}");
+    }
+
+    // Multiplying with zero
+    let mut product = 0;
+    for x in xs {
+        product *= x;
+    }
+
+    // Not a number
+    let mut s = String::new();
+    for part in ["a", "b"] {
+        s += part;
+    }
+}
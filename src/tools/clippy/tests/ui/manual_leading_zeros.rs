@@ -0,0 +1,62 @@
+#![warn(clippy::manual_leading_zeros)]
+#![allow(unused)]
+
+fn leading(mut x: u32) -> u32 {
+    let mut count = 0;
+    while (x & 0x8000_0000) == 0 {
+        x <<= 1;
+        count += 1;
+    }
+    count
+}
+
+fn leading_u8(mut x: u8) -> usize {
+    let mut count = 0;
+    while 0 == (0x80 & x) {
+        count += 1;
+        x <<= 1;
+    }
+    count
+}
+
+fn trailing(mut x: u64) -> u32 {
+    let mut count = 0;
+    while (x & 1) == 0 {
+        x >>= 1;
+        count += 1;
+    }
+    count
+}
+
+fn no_lint(mut x: u32, mut y: i32) -> u32 {
+    let mut count = 0;
+    // Zero is handled by the loop
+    while x != 0 && (x & 0x8000_0000) == 0 {
+        x <<= 1;
+        count += 1;
+    }
+    // Not the highest bit
+    while (x & 0x4000_0000) == 0 {
+        x <<= 1;
+        count += 1;
+    }
+    // Shifting in the wrong direction
+    while (x & 1) == 0 {
+        x <<= 1;
+        count += 1;
+    }
+    // Signed integer
+    while (y & 1) == 0 {
+        y >>= 1;
+        count += 1;
+    }
+    // Something else happens in the loop
+    while (x & 1) == 0 {
+        x >>= 1;
+        count += 1;
+        println!("{count}");
+    }
+    count
+}
+
+fn main() {}
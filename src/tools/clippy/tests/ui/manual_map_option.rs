@@ -220,4 +220,10 @@ const fn f4() {
         Some(s) => Some(s),
         None => None,
     };
+
+    // Breaking out of a labeled block within the arm
+    match Some(0) {
+        Some(x) => Some('a: { break 'a x }),
+        None => None,
+    };
 }
@@ -134,4 +134,29 @@ pub fn manual_clone(src: &[String], dst: &mut [String]) {
     }
 }
 
+pub fn manual_copy_with_adapters(src: &[i32], dst: &mut [i32]) {
+    // reversed memcpy
+    for i in (0..src.len()).rev() {
+        dst[i] = src[i];
+    }
+
+    // reversed memcpy with a dst offset
+    for i in (0..src.len()).rev() {
+        dst[i + 10] = src[i];
+    }
+
+    // strided copy - lint without a suggestion
+    for i in (0..src.len()).step_by(2) {
+        dst[i] = src[i];
+    }
+
+    // overlapping source and destination - don't trigger lint
+    for i in (0..5).rev() {
+        dst[i + 1] = dst[i];
+    }
+    for i in (0..5).step_by(2) {
+        dst[i + 1] = dst[i];
+    }
+}
+
 fn main() {}
@@ -0,0 +1,40 @@
+//@run-rustfix
+#![warn(clippy::manual_min_max_chain)]
+#![allow(clippy::needless_if, unused)]
+
+fn ints(a: u32, b: u32) {
+    let _ = if a > b { a } else { b };
+    let _ = if a >= b { a } else { b };
+    let _ = if a < b { a } else { b };
+    let _ = if a <= b { a } else { b };
+    let _ = if b < a { a } else { b };
+    let _ = if a > b { b } else { a };
+    let _ = if a + 1 > b { a + 1 } else { b };
+    let _ = if a > 1 {
+        0
+    } else if a > b {
+        a
+    } else {
+        b
+    };
+}
+
+fn strings(a: String, b: String) {
+    let _ = if a > b { a } else { b };
+}
+
+fn floats(a: f64, b: f64) {
+    let _ = if a > b { a } else { b };
+}
+
+fn no_lint(a: u32, b: u32, c: u32) {
+    let _ = if a > b { a } else { c };
+    let _ = if a == b { a } else { b };
+    let _ = if a > b { a } else { b + 1 };
+}
+
+const fn in_const(a: u32, b: u32) {
+    let _ = if a > b { a } else { b };
+}
+
+fn main() {}
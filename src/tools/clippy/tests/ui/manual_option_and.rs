@@ -0,0 +1,33 @@
+#![warn(clippy::manual_option_and)]
+#![allow(clippy::unnecessary_literal_unwrap, clippy::unnecessary_unwrap, unused)]
+
+fn compute() -> Option<u32> {
+    Some(2)
+}
+
+struct S {
+    a: Option<u32>,
+}
+
+fn main() {
+    let a = Some(1u32);
+    let b = Some(2u32);
+    let _ = if a.is_some() { b } else { None };
+    let _ = if a.is_some() { compute() } else { None };
+    let _ = if a.is_some() { Some(a.unwrap() + 1) } else { None };
+    let s = S { a };
+    let _ = if s.a.is_some() { Some(s.a.unwrap() * 2) } else { None };
+
+    // The checked `Option` is used in other ways.
+    let _ = if a.is_some() { Some(a.unwrap_or_default()) } else { None };
+    // The closure argument would shadow `a`.
+    let _ = if s.a.is_some() {
+        Some(s.a.unwrap() + a.unwrap_or(0))
+    } else {
+        None
+    };
+
+    // Don't lint
+    let _ = if a.is_some() { b } else { Some(0) };
+    let _ = if a.is_none() { b } else { None };
+}
@@ -0,0 +1,65 @@
+#![warn(clippy::manual_option_get_or_insert)]
+#![allow(unused)]
+
+fn compute() -> Vec<u32> {
+    vec![1]
+}
+
+fn lazy_fn(mut opt: Option<Vec<u32>>) {
+    if opt.is_none() {
+        opt = Some(compute());
+    }
+    let value = opt.as_mut().unwrap();
+}
+
+fn lazy_closure(mut opt: Option<Vec<u32>>, n: usize) {
+    if opt.is_none() {
+        opt = Some(Vec::with_capacity(n));
+    }
+    opt.as_mut().unwrap().push(1);
+}
+
+fn eager(opt: &mut Option<u32>) -> &mut u32 {
+    if opt.is_none() {
+        *opt = Some(0);
+    }
+    opt.as_mut().unwrap()
+}
+
+struct Cache {
+    value: Option<String>,
+}
+
+impl Cache {
+    fn get(&mut self) -> &mut String {
+        if self.value.is_none() {
+            self.value = Some(String::new());
+        }
+        self.value.as_mut().unwrap()
+    }
+}
+
+fn no_lint(mut opt: Option<u32>, mut other: Option<u32>) {
+    // Different options
+    if opt.is_none() {
+        other = Some(1);
+    }
+    let value = opt.as_mut().unwrap();
+
+    // Something happens in between
+    if opt.is_none() {
+        opt = Some(1);
+    }
+    println!("{opt:?}");
+    let value = opt.as_mut().unwrap();
+
+    // Has an `else`
+    if opt.is_none() {
+        opt = Some(1);
+    } else {
+        opt = None;
+    }
+    let value = opt.as_mut().unwrap();
+}
+
+fn main() {}
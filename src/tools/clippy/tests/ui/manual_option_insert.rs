@@ -0,0 +1,51 @@
+#![warn(clippy::manual_option_insert)]
+#![allow(unused_assignments)]
+
+struct Cache {
+    entry: Option<Vec<u8>>,
+}
+
+fn local(mut opt: Option<Vec<u8>>) {
+    opt = Some(vec![1]);
+    opt.as_mut().unwrap().push(2);
+
+    opt = Some(Vec::new());
+    let v = opt.as_ref().unwrap();
+    let _ = v.len();
+}
+
+fn through_ref(opt: &mut Option<u32>) -> &mut u32 {
+    *opt = Some(1);
+    opt.as_mut().unwrap()
+}
+
+impl Cache {
+    fn fill(&mut self) -> &mut Vec<u8> {
+        self.entry = Some(vec![0; 4]);
+        self.entry.as_mut().unwrap()
+    }
+}
+
+fn no_lint(mut a: Option<u32>, mut b: Option<u32>) {
+    // a different option is unwrapped
+    a = Some(1);
+    let _ = b.as_mut().unwrap();
+    // another statement comes in between
+    a = Some(2);
+    b = None;
+    let _ = a.as_mut().unwrap();
+}
+
+#[clippy::msrv = "1.52"]
+fn msrv_1_52(mut opt: Option<u32>) {
+    opt = Some(1);
+    let _ = opt.as_mut().unwrap();
+}
+
+#[clippy::msrv = "1.53"]
+fn msrv_1_53(mut opt: Option<u32>) {
+    opt = Some(1);
+    let _ = opt.as_mut().unwrap();
+}
+
+fn main() {}
@@ -0,0 +1,97 @@
+//@run-rustfix
+#![warn(clippy::manual_option_xor)]
+#![allow(clippy::match_single_binding, unused)]
+#![feature(custom_inner_attributes)]
+
+fn lint(a: Option<u32>, b: Option<u32>) {
+    let _ = match (a, b) {
+        (Some(x), None) => Some(x),
+        (None, Some(y)) => Some(y),
+        _ => None,
+    };
+
+    let _ = match (a, b) {
+        (Some(x), None) => Some(x),
+        (None, Some(x)) => Some(x),
+        (Some(_), Some(_)) => None,
+        (None, None) => None,
+    };
+
+    let _ = match (a, b) {
+        (Some(x), None) | (None, Some(x)) => Some(x),
+        _ => None,
+    };
+
+    let _ = match (Some(1), b) {
+        (None, Some(y)) => Some(y),
+        (Some(x), None) => Some(x),
+        (_, _) => None,
+    };
+}
+
+fn no_lint(a: Option<u32>, b: Option<u32>, c: Option<u8>) {
+    // The value is modified
+    let _ = match (a, b) {
+        (Some(x), None) => Some(x + 1),
+        (None, Some(y)) => Some(y),
+        _ => None,
+    };
+
+    // The wrong value is returned
+    let _ = match (a, b) {
+        (Some(x), None) => Some(x),
+        (None, Some(_)) => Some(0),
+        _ => None,
+    };
+
+    // `(Some, Some)` isn't `None`
+    let _ = match (a, b) {
+        (Some(x), None) => Some(x),
+        (None, Some(y)) => Some(y),
+        (Some(x), Some(_)) => Some(x),
+        (None, None) => None,
+    };
+
+    // The remaining combinations don't return `None`
+    let _ = match (a, b) {
+        (Some(x), None) => Some(x),
+        (None, Some(y)) => Some(y),
+        _ => a,
+    };
+
+    // Guards
+    let _ = match (a, b) {
+        (Some(x), None) if x > 1 => Some(x),
+        (None, Some(y)) => Some(y),
+        _ => None,
+    };
+
+    // Different types
+    let _ = match (a, c) {
+        (Some(x), None) => Some(x),
+        (None, Some(y)) => Some(u32::from(y)),
+        _ => None,
+    };
+}
+
+fn msrv_1_36(a: Option<u32>, b: Option<u32>) {
+    #![clippy::msrv = "1.36"]
+
+    let _ = match (a, b) {
+        (Some(x), None) => Some(x),
+        (None, Some(y)) => Some(y),
+        _ => None,
+    };
+}
+
+fn msrv_1_37(a: Option<u32>, b: Option<u32>) {
+    #![clippy::msrv = "1.37"]
+
+    let _ = match (a, b) {
+        (Some(x), None) => Some(x),
+        (None, Some(y)) => Some(y),
+        _ => None,
+    };
+}
+
+fn main() {}
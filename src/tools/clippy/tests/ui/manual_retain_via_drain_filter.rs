@@ -0,0 +1,52 @@
+#![warn(clippy::manual_retain_via_drain_filter, clippy::swap_remove_in_forward_loop)]
+#![allow(clippy::needless_range_loop, unused)]
+
+fn is_odd(x: &u32) -> bool {
+    x % 2 == 1
+}
+
+fn main() {
+    let mut v = vec![1u32, 2, 3, 4];
+    for i in (0..v.len()).rev() {
+        if v[i] % 2 == 0 {
+            v.swap_remove(i);
+        }
+    }
+
+    for i in (0..v.len()).rev() {
+        if !is_odd(&v[i]) {
+            v.swap_remove(i);
+        }
+    }
+
+    // Iterating from the start skips the element swapped into the removed slot.
+    for i in 0..v.len() {
+        if v[i] > 2 {
+            v.swap_remove(i);
+        }
+    }
+
+    // Don't lint. `remove` keeps the order.
+    for i in (0..v.len()).rev() {
+        if v[i] % 2 == 0 {
+            v.remove(i);
+        }
+    }
+
+    // Don't lint. The loop does more than removing elements.
+    for i in (0..v.len()).rev() {
+        if v[i] % 2 == 0 {
+            v.swap_remove(i);
+        } else {
+            v[i] += 1;
+        }
+    }
+
+    // Don't lint. The elements are removed from another `Vec`.
+    let mut w = v.clone();
+    for i in (0..v.len()).rev() {
+        if v[i] % 2 == 0 {
+            w.swap_remove(i);
+        }
+    }
+}
@@ -0,0 +1,28 @@
+#![warn(clippy::manual_saturating_arithmetic_roundtrip)]
+
+fn main() {
+    let (x, y) = (1i32, 2i32);
+    let _ = (x as i64 + y as i64).clamp(i32::MIN as i64, i32::MAX as i64) as i32;
+    let _ = (x as i64 * y as i64).clamp(i32::MIN as i64, i32::MAX as i64) as i32;
+    let _ = (x as i128 - y as i128).clamp(i32::MIN as i128, i32::MAX as i128) as i32;
+
+    let (a, b) = (1u8, 2u8);
+    let _ = (a as u16 + b as u16).clamp(0, 255) as u8;
+    let _ = (a as i16 - b as i16).clamp(u8::MIN as i16, u8::MAX as i16) as u8;
+
+    // bounds don't match the range of the narrow type
+    let _ = (x as i64 + y as i64).clamp(0, i32::MAX as i64) as i32;
+    let _ = (a as u16 + b as u16).clamp(0, 100) as u8;
+
+    // operands aren't widened from the same type
+    let z = 3i16;
+    let _ = (x as i64 + z as i64).clamp(i32::MIN as i64, i32::MAX as i64) as i32;
+
+    // the wide type can overflow
+    let _ = (a as i16 * b as i16).clamp(0, 255) as u8;
+    let _ = (a as u16 - b as u16).clamp(0, 255) as u8;
+
+    // not an integer
+    let (f, g) = (1.0f32, 2.0f32);
+    let _ = (f as f64 + g as f64).clamp(f32::MIN as f64, f32::MAX as f64) as f32;
+}
@@ -0,0 +1,20 @@
+#![warn(clippy::manual_saturating_index)]
+
+fn main() {
+    let v = vec![1, 2, 3];
+    let a = [4, 5, 6];
+    let i = 5;
+
+    // clamped
+    let _ = v[i.min(v.len() - 1)];
+    let _ = v[i.min(v.len().saturating_sub(1))];
+    let _ = a[(a.len() - 1).min(i)];
+
+    // not clamped
+    let _ = v[i];
+    let _ = v[i.min(2)];
+    let _ = v[i.min(a.len() - 1)];
+    let _ = v[i.min(v.len() - 2)];
+    let _ = v[i.max(v.len() - 1)];
+    let _ = v.get(i).copied().unwrap_or(0);
+}
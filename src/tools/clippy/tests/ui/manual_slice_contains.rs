@@ -0,0 +1,31 @@
+//@run-rustfix
+#![warn(clippy::manual_slice_contains)]
+#![allow(unused)]
+
+fn lint(xs: &[u32], v: Vec<String>, arr: [char; 3], target: u32, s: &String) {
+    let _ = xs.iter().any(|x| *x == target);
+    let _ = xs.iter().any(|x| target == *x);
+    let _ = xs.iter().any(|&x| x == target);
+    let _ = xs.iter().any(|x| x == &target);
+    let _ = xs.iter().any(|x| *x == 1);
+    let _ = v.iter().any(|x| x == s);
+    let _ = arr.iter().any(|c| *c == 'a');
+    let _ = xs.iter().any(|x| *x == target + 1);
+}
+
+fn no_lint(xs: &[u32], target: u32, f: fn() -> u32, names: &[&'static str], name: &str) {
+    // Depends on the element
+    let _ = xs.iter().any(|x| *x == *x + 1);
+    // Not an equality check
+    let _ = xs.iter().any(|x| *x > target);
+    // Evaluated for every element
+    let _ = xs.iter().any(|x| *x == f());
+    // Compares something else than the element
+    let _ = xs.iter().any(|x| x.count_ones() == target);
+    // Not a slice
+    let _ = (0..10).any(|x| x == target);
+    // `contains` would require `name` to be `'static`
+    let _ = names.iter().any(|&n| n == name);
+}
+
+fn main() {}
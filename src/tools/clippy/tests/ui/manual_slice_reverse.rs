@@ -0,0 +1,58 @@
+#![warn(clippy::manual_slice_reverse)]
+#![allow(clippy::needless_range_loop, clippy::manual_swap)]
+
+struct Buf {
+    data: Vec<u8>,
+}
+
+fn swaps(v: &mut Vec<u32>, s: &mut [u32], mut arr: [u8; 4]) {
+    for i in 0..v.len() / 2 {
+        v.swap(i, v.len() - 1 - i);
+    }
+
+    let n = s.len();
+    for i in 0..n / 2 {
+        s.swap(n - i - 1, i)
+    }
+
+    for i in 0..arr.len() / 2 {
+        arr.swap(i, arr.len() - i - 1);
+    }
+}
+
+impl Buf {
+    fn reverse_in_place(&mut self) {
+        let len = self.data.len();
+        for i in 0..len / 2 {
+            let tmp = self.data[i];
+            self.data[i] = self.data[len - 1 - i];
+            self.data[len - 1 - i] = tmp;
+        }
+    }
+}
+
+fn no_lint(v: &mut Vec<u32>, w: &mut Vec<u32>) {
+    // only reverses part of the slice
+    for i in 0..v.len() / 3 {
+        v.swap(i, v.len() - 1 - i);
+    }
+    // the indices don't mirror each other
+    for i in 0..v.len() / 2 {
+        v.swap(i, v.len() - 2 - i);
+    }
+    // the bound is the length of another vector
+    for i in 0..w.len() / 2 {
+        v.swap(i, v.len() - 1 - i);
+    }
+    // skips every other element
+    for i in (0..v.len() / 2).step_by(2) {
+        v.swap(i, v.len() - 1 - i);
+    }
+    // does more than swapping
+    for i in 0..v.len() / 2 {
+        v.swap(i, v.len() - 1 - i);
+        v[i] += 1;
+    }
+}
+
+fn main() {}
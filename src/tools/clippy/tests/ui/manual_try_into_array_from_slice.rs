@@ -0,0 +1,38 @@
+#![warn(clippy::manual_try_into_array_from_slice)]
+#![allow(unused)]
+
+fn lint(bytes: &[u8], words: &[u32]) {
+    let mut header = [0u8; 4];
+    header.copy_from_slice(&bytes[0..4]);
+
+    let mut tail = [0; 2];
+    tail.copy_from_slice(&bytes[4..=5]);
+
+    let mut prefix: [u32; 3] = [0; 3];
+    prefix.copy_from_slice(&words[..3]);
+}
+
+fn no_lint(bytes: &[u8], start: usize) {
+    // The range is shorter than the array
+    let mut short = [0u8; 4];
+    short[..2].copy_from_slice(&bytes[0..2]);
+
+    // The range length is not known
+    let mut dynamic = [0u8; 4];
+    dynamic.copy_from_slice(&bytes[start..start + 4]);
+
+    // The range length doesn't match the array length
+    let mut mismatch = [0u8; 4];
+    mismatch.copy_from_slice(&bytes[1..4]);
+
+    // The array is used before being filled
+    let mut used = [0u8; 4];
+    println!("{used:?}");
+    used.copy_from_slice(&bytes[0..4]);
+
+    // The array is filled from another array
+    let mut other = [0u8; 4];
+    other.copy_from_slice(&[1, 2, 3, 4]);
+}
+
+fn main() {}
@@ -0,0 +1,55 @@
+#![allow(unused)]
+#![warn(clippy::manual_unzip)]
+
+fn main() {
+    let pairs = vec![(1, 'a'), (2, 'b')];
+
+    let mut numbers = Vec::new();
+    let mut chars = Vec::new();
+    for pair in pairs.clone() {
+        numbers.push(pair.0);
+        chars.push(pair.1);
+    }
+
+    let mut chars = Vec::new();
+    let mut numbers = Vec::with_capacity(2);
+    for (n, c) in pairs.iter().copied() {
+        numbers.push(n);
+        chars.push(c);
+    }
+
+    // Don't lint: both pushes use the same half of the pair
+    let mut a = Vec::new();
+    let mut b = Vec::new();
+    for pair in pairs.clone() {
+        a.push(pair.0);
+        b.push(pair.0);
+    }
+
+    // Don't lint: both halves are pushed into the same vector
+    let mut a = Vec::new();
+    let mut b: Vec<i32> = Vec::new();
+    for (n, m) in [(1, 2), (3, 4)] {
+        a.push(n);
+        a.push(m);
+    }
+
+    // Don't lint: the loop does more than pushing
+    let mut a = Vec::new();
+    let mut b = Vec::new();
+    for pair in pairs.clone() {
+        a.push(pair.0);
+        b.push(pair.1);
+        println!("{pair:?}");
+    }
+
+    // Don't lint: a different vector is pushed to
+    let mut other = Vec::new();
+    let mut a = Vec::new();
+    let mut b = Vec::new();
+    for pair in pairs {
+        a.push(pair.0);
+        other.push(pair.1);
+    }
+    b.push(1);
+}
@@ -0,0 +1,31 @@
+#![warn(clippy::manual_wrapping_offset)]
+#![allow(unused)]
+
+use std::mem::size_of;
+
+fn main() {
+    let v = [1_u32, 2, 3];
+    let ptr = v.as_ptr();
+    let bytes = b"abc".as_ptr();
+    let mut w = [0_u64; 4];
+    let ptr_mut = w.as_mut_ptr();
+    let n = 2;
+
+    // should lint
+    let _ = (ptr as usize + n * size_of::<u32>()) as *const u32;
+    let _ = (ptr as usize + size_of::<u32>() * n) as *const u32;
+    let _ = (ptr as usize - size_of::<u32>()) as *const u32;
+    let _ = (size_of::<u32>() + ptr as usize) as *const u32;
+    let _ = (ptr as usize).wrapping_add(n * size_of::<u32>()) as *const u32;
+    let _ = (bytes as usize + n) as *const u8;
+    let _ = (ptr as usize + 2) as *const u32;
+    let _ = (ptr_mut as usize + n * size_of::<u64>()) as *mut u64;
+    let _ = (ptr as usize + n * size_of::<u32>()) as *const i32;
+
+    // should not lint
+    let _ = (ptr as usize + n * size_of::<u32>()) as u64;
+    let _ = (n + 4) as *const u32;
+    let _ = (ptr as usize * 2) as *const u32;
+    let _ = (n - ptr as usize) as *const u32;
+    let _ = ptr.wrapping_add(n);
+}
@@ -22,6 +22,11 @@ fn option_methods() {
     // Should not lint.
     let _ = opt_map!(opt, |x| x + 1).unwrap_or_else(|| 0);
 
+    // Check for `option.map(_).unwrap_or(_)` use.
+    // The parts of the suggestion have to be applied together.
+    let _ = opt.map(|x| x + 1).unwrap_or(0);
+    let _ = opt.map(|x| x > 1).unwrap_or(false);
+
     // Issue #4144
     {
         let mut frequencies = HashMap::new();
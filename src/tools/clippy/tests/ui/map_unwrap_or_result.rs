@@ -0,0 +1,54 @@
+//@run-rustfix
+
+#![warn(clippy::map_unwrap_or)]
+#![allow(clippy::unnecessary_lazy_evaluations)]
+
+fn result_unwrap_or() {
+    let res: Result<i32, i32> = Ok(1);
+
+    let _ = res.map(|x| x + 1).unwrap_or(0);
+    let _ = res.map(|x| x > 5).unwrap_or(false);
+    let _ = res.map(|x| Some(x + 1)).unwrap_or(None);
+}
+
+#[clippy::msrv = "1.40"]
+fn msrv_1_40() {
+    let res: Result<i32, i32> = Ok(1);
+
+    let _ = res.map(|x| x + 1).unwrap_or(0);
+    let _ = res.map(|x| x + 1).unwrap_or_else(|_| 0);
+    let _ = res.map(|x| x + 1).unwrap_or_else(|_e| 0);
+    // The error is used, so there is no `Option` equivalent.
+    let _ = res.map(|x| x + 1).unwrap_or_else(|e| e);
+}
+
+#[clippy::msrv = "1.69"]
+fn msrv_1_69() {
+    let res: Result<i32, i32> = Ok(1);
+
+    let _ = res.map(|x| x > 5).unwrap_or(false);
+}
+
+#[rustfmt::skip]
+fn borrow_conflict() {
+    let mut count = 0;
+    let opt = Some(1);
+    let res: Result<i32, i32> = Ok(1);
+
+    // Both closures would be alive at the same time, `count` can't be borrowed by both.
+    let _ = opt.map(|x| { count += x; x }).unwrap_or_else(|| count);
+    let _ = opt.map(|x| x + count).unwrap_or_else(|| { count += 1; 0 });
+    let _ = res.map(|x| { count += x; x }).unwrap_or_else(|e| e + count);
+    let _ = res.map(|x| x + count).unwrap_or_else(|e| { count += e; 0 });
+
+    // Shared borrows are fine.
+    let _ = opt.map(|x| x + count).unwrap_or_else(|| count);
+    let _ = res.map(|x| x + count).unwrap_or_else(|e| e + count);
+}
+
+fn main() {
+    result_unwrap_or();
+    msrv_1_40();
+    msrv_1_69();
+    borrow_conflict();
+}
@@ -255,3 +255,15 @@ fn msrv_1_42() {
         _ => false,
     };
 }
+
+#[rustfmt::skip]
+fn nested_blocks() {
+    let x = Some(5);
+
+    let _y = match x {
+        Some(0) => {
+            { true }
+        },
+        _ => false,
+    };
+}
@@ -247,3 +247,28 @@ struct Bar {
         _ => false,
     };
 }
+
+const FIVE: u32 = 5;
+
+fn synonymous_arms(x: u32) {
+    // Lint, the const has the same value
+    let _ = match x {
+        0 => FIVE,
+        1 => 5, //~ ERROR: this match arm has an identical body to another arm
+        _ => 10,
+    };
+
+    // Lint, the closures only differ in the name of their parameter
+    let _ = match x {
+        0 => Some(x).map(|a| a + 1),
+        1 => Some(x).map(|b| b + 1), //~ ERROR: this match arm has an identical body to another arm
+        _ => None,
+    };
+
+    // Don't lint, the closures differ
+    let _ = match x {
+        0 => Some(x).map(|a| a + 1),
+        1 => Some(x).map(|b| b + 2),
+        _ => None,
+    };
+}
@@ -60,3 +60,17 @@ fn should_not_warn() {
         }
     }
 }
+
+#[clippy::msrv = "1.42.0"]
+mod outer_attr_module {
+    fn should_not_warn() {
+        let log2_10 = 3.321928094887362;
+    }
+
+    #[clippy::msrv = "1.43.0"]
+    mod raised {
+        fn should_warn() {
+            let log2_10 = 3.321928094887362;
+        }
+    }
+}
@@ -0,0 +1,54 @@
+#![warn(clippy::needless_arc_clone_in_loop)]
+
+use std::rc::Rc;
+use std::sync::Arc;
+
+fn main() {
+    let shared = Arc::new(vec![1, 2, 3]);
+    for i in 0..3 {
+        let data = Arc::clone(&shared);
+        println!("{}", data[i]);
+    }
+
+    let shared = Rc::new(String::from("hello"));
+    for _ in 0..3 {
+        let data = Rc::clone(&shared);
+        println!("{}", data.len());
+    }
+
+    // moved into a spawned thread
+    let shared = Arc::new(vec![1, 2, 3]);
+    for i in 0..3 {
+        let data = Arc::clone(&shared);
+        std::thread::spawn(move || println!("{}", data[i]));
+    }
+
+    // moved into `drop`
+    let shared = Arc::new(1);
+    let data = Arc::clone(&shared);
+    println!("{data}");
+    drop(data);
+
+    // the original is moved later on
+    let shared = Arc::new(1);
+    let data = Arc::clone(&shared);
+    println!("{data}");
+    drop(shared);
+
+    // not a local
+    let data = Arc::clone(&Arc::new(1));
+    println!("{data}");
+}
+
+fn returned(shared: &Arc<u32>) -> Arc<u32> {
+    let data = Arc::clone(shared);
+    println!("{data}");
+    data
+}
+
+fn returned_local() -> Arc<u32> {
+    let shared = Arc::new(1);
+    let data = Arc::clone(&shared);
+    println!("{data}");
+    data
+}
@@ -192,3 +192,19 @@ fn foo() -> bool {
 
     foo()
 }
+
+fn negated_conditions(x: f64, y: f64, flag: bool) {
+    let _ = if x < y { false } else { true };
+    let _ = if !flag { false } else { true };
+}
+
+macro_rules! is_positive {
+    ($e:expr) => {
+        $e > 0
+    };
+}
+
+fn conditions_from_macros(x: i32) {
+    let _ = if is_positive!(x) { true } else { false };
+    let _ = if is_positive!(x) { false } else { true };
+}
@@ -0,0 +1,65 @@
+//@run-rustfix
+#![warn(clippy::needless_borrow)]
+#![allow(unused)]
+
+struct S;
+impl S {
+    fn name(&self) -> &'static str {
+        "inherent"
+    }
+}
+
+trait Name {
+    fn name(self) -> &'static str;
+}
+impl Name for &S {
+    fn name(self) -> &'static str {
+        "trait"
+    }
+}
+
+struct Wrapper<T>(T);
+impl Wrapper<u8> {
+    fn get(&self) -> u32 {
+        0
+    }
+}
+
+trait Get {
+    fn get(&self) -> u32;
+}
+impl Get for Wrapper<u32> {
+    fn get(&self) -> u32 {
+        self.0
+    }
+}
+
+fn take(_: impl AsRef<str>) {}
+
+fn method_resolution() {
+    // The inherent method is called both with and without the borrow.
+    let x = &S;
+    let _ = (&x).name();
+
+    // The inherent method isn't defined for `Wrapper<u32>`.
+    let w = &Wrapper(1u32);
+    let _ = (&w).get();
+}
+
+fn moved_binding() {
+    // Don't lint. Removing the borrow would move `s` into the closure.
+    let s = String::new();
+    let f = || take(&s);
+    f();
+    drop(s);
+
+    // Don't lint. `s` is used after the call.
+    let s = String::new();
+    take(&s);
+    println!("{s}");
+
+    let s = String::new();
+    take(&s);
+}
+
+fn main() {}
@@ -0,0 +1,87 @@
+#![warn(clippy::needless_box_in_return)]
+
+use std::fmt::Display;
+
+struct Node {
+    value: i32,
+    next: Option<Box<Node>>,
+}
+
+struct Large([u8; 1024]);
+
+trait Make {
+    fn make() -> Box<Self>;
+}
+
+impl Make for String {
+    fn make() -> Box<Self> {
+        Box::new(String::from("made"))
+    }
+}
+
+fn name(first: &str) -> Box<String> {
+    Box::new(format!("{first} Smith"))
+}
+
+fn number(big: bool) -> Box<u64> {
+    if big {
+        return Box::new(u64::MAX);
+    }
+    match u8::from(big) {
+        0 => Box::new(0),
+        _ => Box::new(1),
+    }
+}
+
+fn node(value: i32) -> Box<Node> {
+    Box::new(Node { value, next: None })
+}
+
+// The box isn't created here.
+fn passed_through(value: Box<String>) -> Box<String> {
+    value
+}
+
+fn partly_passed_through(value: Option<Box<String>>) -> Box<String> {
+    match value {
+        Some(value) => value,
+        None => Box::new(String::from("none")),
+    }
+}
+
+// Unsized types need the box.
+fn trait_object() -> Box<dyn Display> {
+    Box::new(1)
+}
+
+fn slice() -> Box<[u8]> {
+    Box::new([1, 2, 3])
+}
+
+// Large types are likely boxed on purpose.
+fn large() -> Box<Large> {
+    Box::new(Large([0; 1024]))
+}
+
+struct S;
+
+impl S {
+    fn method(&self) -> Box<i32> {
+        Box::new(1)
+    }
+}
+
+fn main() {
+    let _ = String::make();
+    let _ = name("John");
+    let _ = number(true);
+    let n = node(1);
+    let _ = (n.value, n.next);
+    let _ = passed_through(Box::default());
+    let _ = partly_passed_through(None);
+    let _ = trait_object();
+    let _ = slice();
+    let _ = large().0;
+    let _ = S.method();
+    let _ = |x: i32| -> Box<i32> { Box::new(x) };
+}
@@ -0,0 +1,23 @@
+//@run-rustfix
+#![warn(clippy::needless_collect_into_hashset_for_contains)]
+#![allow(unused, clippy::needless_collect)]
+
+use std::collections::{BTreeSet, HashSet};
+
+fn main() {
+    let names = ["a", "b", "c"];
+    let target = "b";
+    let _ = names.iter().collect::<HashSet<_>>().contains(&&target);
+    let _ = names.into_iter().collect::<HashSet<&str>>().contains(&target);
+    let reference = &target;
+    let _ = names.into_iter().collect::<HashSet<_>>().contains(reference);
+
+    // The set is used for multiple lookups
+    let set = names.iter().collect::<HashSet<_>>();
+    let _ = set.contains(&"a") && set.contains(&"b");
+    // Not a `HashSet`
+    let _ = names.iter().collect::<BTreeSet<_>>().contains(&&target);
+    // Lookup by a borrowed form
+    let owned = vec![String::from("a")];
+    let _ = owned.into_iter().collect::<HashSet<_>>().contains("a");
+}
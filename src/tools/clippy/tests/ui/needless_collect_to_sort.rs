@@ -0,0 +1,28 @@
+#![warn(clippy::needless_collect_to_sort)]
+#![allow(unused)]
+
+fn main() {
+    let words = ["b", "a", "b"];
+
+    let mut sorted: Vec<_> = words.iter().collect();
+    sorted.sort();
+
+    let mut sorted = words.iter().copied().collect::<Vec<_>>();
+    sorted.sort_unstable();
+    sorted.dedup();
+}
+
+fn no_lint(words: &[&str]) {
+    // A comparator a sorted collection can't keep
+    let mut sorted: Vec<_> = words.iter().collect();
+    sorted.sort_by_key(|w| w.len());
+
+    // Not sorted right away
+    let mut sorted: Vec<_> = words.iter().collect();
+    sorted.push(&"c");
+    sorted.sort();
+
+    // Not a `Vec`
+    let mut sorted: std::collections::VecDeque<_> = words.iter().collect();
+    sorted.make_contiguous().sort();
+}
@@ -0,0 +1,48 @@
+#![warn(clippy::needless_explicit_drop_at_scope_end)]
+#![allow(dropping_copy_types)]
+
+use std::sync::Mutex;
+
+fn increment(mutex: &Mutex<u32>) {
+    let mut guard = mutex.lock().unwrap();
+    *guard += 1;
+    drop(guard);
+}
+
+fn nested(v: Vec<String>) {
+    for s in &v {
+        let upper = s.to_uppercase();
+        println!("{upper}");
+        std::mem::drop(upper);
+    }
+}
+
+fn no_lint(mutex: &Mutex<u32>, data: Vec<u8>) -> usize {
+    // released before the other local is dropped
+    {
+        let guard = mutex.lock().unwrap();
+        let copy = data.clone();
+        drop(guard);
+    }
+    // not the last statement
+    {
+        let guard = mutex.lock().unwrap();
+        drop(guard);
+        println!("unlocked");
+    }
+    // declared outside of the block
+    {
+        drop(data);
+    }
+    // no drop glue
+    {
+        let n = 1;
+        drop(n);
+    }
+    let v = vec![1];
+    let len = v.len();
+    drop(v);
+    len
+}
+
+fn main() {}
@@ -0,0 +1,56 @@
+//@run-rustfix
+
+#![warn(clippy::needless_lifetime_in_impl_trait_return)]
+#![allow(dead_code, clippy::extra_unused_lifetimes)]
+
+use std::fmt;
+
+struct Wrapper<'a>(&'a str);
+struct Pair<'a, 'b>(&'a str, &'b str);
+trait Named {}
+trait Tagged<'a> {}
+
+impl<'a> fmt::Display for Wrapper<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.0)
+    }
+}
+
+impl<'a> Named for &'a u32 {}
+
+impl<'a> Tagged<'a> for u32 {}
+
+impl<'a, 'b> Named for Pair<'a, 'b> {}
+
+impl<'a, T> Named for (&'a u8, T) {}
+
+// should not lint
+
+// used twice in the header
+impl<'a> From<&'a str> for Wrapper<'a> {
+    fn from(s: &'a str) -> Self {
+        Wrapper(s)
+    }
+}
+
+// used in the impl items
+impl<'a> Iterator for Wrapper<'a> {
+    type Item = &'a str;
+    fn next(&mut self) -> Option<Self::Item> {
+        None
+    }
+}
+
+// constrained by bounds
+impl<'a, T: 'a> Named for Option<&'a T> {}
+
+impl<'a, T> Named for Box<&'a T> where T: 'a {}
+
+// inherent impls
+impl<'a> Wrapper<'a> {
+    fn get(&self) -> &str {
+        self.0
+    }
+}
+
+fn main() {}
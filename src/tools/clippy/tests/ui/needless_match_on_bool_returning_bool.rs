@@ -0,0 +1,45 @@
+//@run-rustfix
+#![warn(clippy::needless_match_on_bool_returning_bool)]
+#![allow(clippy::match_like_matches_macro, clippy::needless_match, unused)]
+
+fn lint(b: bool, x: u32) {
+    let _ = match b {
+        true => true,
+        false => false,
+    };
+    let _ = match b {
+        false => true,
+        true => false,
+    };
+    let _ = match x > 1 {
+        true => { false },
+        _ => true,
+    };
+    let _ = x == 2
+        || match x > 1 && b {
+            true => true,
+            _ => false,
+        };
+}
+
+fn no_lint(b: bool, other: bool) {
+    let _ = match b {
+        true => true,
+        false => other,
+    };
+    let _ = match b {
+        true => true,
+        false => true,
+    };
+    let _ = match b {
+        true if other => true,
+        _ => false,
+    };
+    let _ = match b {
+        // a comment
+        true => true,
+        false => false,
+    };
+}
+
+fn main() {}
@@ -0,0 +1,16 @@
+#![allow(clippy::needless_match_on_bool_returning_bool)]
+#![warn(clippy::match_like_matches_macro, clippy::needless_match)]
+
+// Allowing `needless_match_on_bool_returning_bool` keeps the other lints on these matches
+fn allowed(b: bool) {
+    let _: bool = match b {
+        true => true,
+        false => false,
+    };
+    let _ = match b {
+        true => true,
+        _ => false,
+    };
+}
+
+fn main() {}
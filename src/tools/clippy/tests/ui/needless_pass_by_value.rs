@@ -1,3 +1,4 @@
+//@aux-build:proc_macros.rs:proc-macro
 #![warn(clippy::needless_pass_by_value)]
 #![allow(dead_code)]
 #![allow(
@@ -8,6 +9,9 @@
     clippy::uninlined_format_args
 )]
 
+extern crate proc_macros;
+
+use proc_macros::with_span;
 use std::borrow::Borrow;
 use std::collections::HashSet;
 use std::convert::AsRef;
@@ -154,6 +158,15 @@ fn is_sync<T>(_: T)
 {
 }
 
+// Do not lint functions generated by proc macros with call-site spans
+with_span! {
+    span
+
+    fn from_proc_macro(v: Vec<u8>) -> usize {
+        v.len()
+    }
+}
+
 fn main() {
     // This should not cause an ICE either
     // https://github.com/rust-lang/rust-clippy/issues/3144
@@ -125,3 +125,13 @@ fn not(self) -> Self::Output {
     // Should not lint
     let _: bool = !!Foo(true);
 }
+
+fn dont_drop_side_effects(a: bool) {
+    fn f() -> bool {
+        unimplemented!()
+    }
+
+    // Should not lint, `f` would no longer be called
+    let _ = a && f() || a;
+    let _ = a || a && f();
+}
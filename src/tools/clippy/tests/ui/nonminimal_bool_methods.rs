@@ -108,4 +108,21 @@ fn dont_warn_for_negated_partial_ord_comparison() {
     let _ = !(a >= b);
 }
 
+fn negated_total_order_comparisons() {
+    let (a, b) = (1i32, 2i32);
+    let _ = !(a >= b);
+    let _ = !(a < b);
+    let (c, d) = ('a', 'b');
+    let _ = !(c <= d);
+    let (e, f) = (true, false);
+    let _ = !(e > f);
+}
+
+#[derive(PartialEq, Eq, PartialOrd, Ord)]
+struct Version(u32);
+
+fn negated_user_type_comparison(a: Version, b: Version) {
+    let _ = !(a < b);
+}
+
 fn main() {}
@@ -271,3 +271,13 @@ pub fn reproduce2(initial: &mut Option<String>) {
     fn do_something(_value: &str) {}
     fn do_something2(_value: &mut str) {}
 }
+
+fn question_mark_in_arm(x: Option<u32>, y: Result<u32, ()>) -> Result<u32, ()> {
+    // `?` would return from the closure instead of the function
+    let _ = if let Some(x) = x { x + y? } else { 0 };
+    let _ = match x {
+        Some(x) => x,
+        None => y?,
+    };
+    Ok(0)
+}
@@ -257,4 +257,20 @@ fn test_map_or() {
     }
 }
 
+mod expensive_receivers {
+    fn make_vec() -> Vec<u32> {
+        Vec::new()
+    }
+
+    fn test() {
+        let opt: Option<usize> = None;
+        let vec = make_vec();
+        let _ = opt.unwrap_or(vec.len());
+        // `len` is cheap, computing its receiver isn't
+        let _ = opt.unwrap_or(make_vec().len());
+        // const-evaluable
+        let _ = opt.unwrap_or(usize::pow(2, 3));
+    }
+}
+
 fn main() {}
@@ -32,3 +32,9 @@ fn check_expect() {
     #[expect(clippy::overly_complex_bool_expr)]
     let _ = a < b && a >= b;
 }
+
+fn method_call_terminals() {
+    let x: Option<u32> = unimplemented!();
+    let _ = x.is_some() && !x.is_some();
+    let _ = x.is_none() || !x.is_none();
+}
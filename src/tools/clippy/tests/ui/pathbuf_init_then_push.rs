@@ -0,0 +1,38 @@
+//@run-rustfix
+#![warn(clippy::pathbuf_init_then_push)]
+#![allow(unused)]
+
+use std::path::PathBuf;
+
+fn main() {
+    let root = String::from("/usr");
+    let file = "file.txt";
+
+    let mut path = PathBuf::new();
+    path.push(&root);
+    path.push("share");
+    path.push(file);
+
+    let mut path = PathBuf::from("/etc");
+    path.push("hosts");
+
+    let mut path: PathBuf = std::path::PathBuf::new();
+    path.push("src");
+    path.push("lib");
+    path.set_extension("rs");
+
+    // no lint: nothing is pushed
+    let mut path = PathBuf::new();
+    path.set_extension("rs");
+
+    // no lint: the push doesn't follow the creation
+    let mut path = PathBuf::from("/tmp");
+    let name = "tmp.txt";
+    path.push(name);
+
+    // no lint: the push is conditional
+    let mut path = PathBuf::from("/tmp");
+    if root.is_empty() {
+        path.push("empty");
+    }
+}
@@ -0,0 +1,17 @@
+#![warn(clippy::pathbuf_init_then_push)]
+#![allow(unused)]
+
+use std::path::{Path, PathBuf};
+
+fn generic<P: AsRef<Path>>(root: P) {
+    let mut path = PathBuf::new();
+    path.push(root);
+    path.push("file.txt");
+}
+
+fn computed(dir: &str, id: u32) {
+    let mut path = PathBuf::from(dir);
+    path.push(format!("{id}.txt"));
+}
+
+fn main() {}
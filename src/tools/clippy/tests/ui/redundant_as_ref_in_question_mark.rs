@@ -0,0 +1,41 @@
+#![warn(clippy::redundant_as_ref_in_question_mark)]
+
+fn through_question_mark(name: Option<String>) -> Option<String> {
+    let first = name.as_ref()?.clone();
+    Some(first)
+}
+
+fn through_ok_or(name: Option<String>) -> Result<String, &'static str> {
+    let first = name.as_ref().ok_or("missing")?.clone();
+    Ok(first)
+}
+
+fn through_ok_or_else(name: Option<String>) -> Result<String, String> {
+    let first = name.as_ref().ok_or_else(|| String::from("missing"))?.clone();
+    Ok(first)
+}
+
+fn used_afterwards(name: Option<String>) -> Option<(String, Option<String>)> {
+    let first = name.as_ref()?.clone();
+    Some((first, name))
+}
+
+fn borrowed_option(name: &Option<String>) -> Option<String> {
+    let first = name.as_ref()?.clone();
+    Some(first)
+}
+
+fn in_loop(name: Option<String>) -> Option<Vec<String>> {
+    let mut names = Vec::new();
+    for _ in 0..2 {
+        names.push(name.as_ref()?.clone());
+    }
+    Some(names)
+}
+
+fn in_closure(name: Option<String>) -> Option<String> {
+    let get = || Some(name.as_ref()?.clone());
+    get()
+}
+
+fn main() {}
@@ -1,3 +1,4 @@
+//@run-rustfix
 #![warn(clippy::redundant_else)]
 #![allow(clippy::needless_return, clippy::if_same_then_else, clippy::needless_late_init)]
 
@@ -64,6 +65,18 @@ fn main() {
     } else {
         1
     };
+    // panic
+    if foo() {
+        panic!("Little strokes fell great oaks.");
+    } else {
+        println!("Lost time is never found again.");
+    }
+    // unreachable
+    if foo() {
+        unreachable!();
+    } else {
+        println!("Well done is better than well said.");
+    }
 
     //
     // non-lint cases
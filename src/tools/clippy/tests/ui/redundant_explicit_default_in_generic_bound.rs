@@ -0,0 +1,33 @@
+//@run-rustfix
+#![warn(clippy::redundant_explicit_default_in_generic_bound)]
+#![allow(unused)]
+
+use std::collections::hash_map::RandomState;
+use std::collections::HashMap;
+
+#[derive(Default)]
+struct Plain;
+#[derive(Default)]
+struct Other;
+
+#[derive(Default)]
+struct Wrapper<T, U = Plain, V = Plain>(T, U, V);
+
+#[derive(Default)]
+struct Only<T = Plain>(T);
+
+fn lint(map: HashMap<u32, u32, RandomState>) -> Wrapper<u8, Plain, Plain> {
+    let _: Only<Plain> = Only::default();
+    let _ = HashMap::<u32, u32, RandomState>::default();
+    Wrapper(1, Plain, Plain)
+}
+
+fn no_lint(map: HashMap<u32, u32>) -> Wrapper<u8, Plain, Other> {
+    // Without any argument, `Only::default()` would have to infer `T`
+    let _ = Only::<Plain>::default();
+    let _: Wrapper<Plain> = Wrapper::default();
+    let _: Wrapper<u8, Other> = Wrapper::default();
+    Wrapper(1, Plain, Other)
+}
+
+fn main() {}
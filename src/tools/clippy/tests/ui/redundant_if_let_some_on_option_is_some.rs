@@ -0,0 +1,44 @@
+#![allow(clippy::unnecessary_unwrap, clippy::unnecessary_literal_unwrap, unused)]
+#![warn(clippy::redundant_if_let_some_on_option_is_some)]
+
+fn take(_: u32) {}
+
+fn main() {
+    let opt = Some(1u32);
+    if opt.is_some() {
+        let x = opt.unwrap();
+        take(x);
+    }
+
+    if opt.is_some() {
+        take(opt.unwrap() + 1);
+    }
+
+    // Don't lint: the `Option` may be changed before the `unwrap`
+    let mut opt2 = Some(1u32);
+    if opt2.is_some() {
+        opt2 = None;
+        take(opt2.unwrap());
+    }
+    if opt2.is_some() {
+        opt2.take();
+        take(opt2.unwrap());
+    }
+
+    // Don't lint: different `Option`
+    let other = Some(2u32);
+    if opt.is_some() {
+        take(other.unwrap());
+    }
+
+    // Don't lint: no `unwrap` in the body
+    if opt.is_some() {
+        take(0);
+    }
+
+    // Don't lint: not an `Option`
+    let res: Result<u32, ()> = Ok(1);
+    if res.is_ok() {
+        take(res.unwrap());
+    }
+}
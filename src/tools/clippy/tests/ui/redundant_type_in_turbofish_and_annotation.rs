@@ -0,0 +1,27 @@
+//@run-rustfix
+#![warn(clippy::redundant_type_in_turbofish_and_annotation)]
+#![allow(unused)]
+
+use std::collections::HashSet;
+
+fn main() {
+    let v = vec![1, 2, 3];
+
+    let a: Vec<u32> = v.iter().copied().collect::<Vec<u32>>();
+    let b: HashSet<&u32> = v.iter().collect::<HashSet<&u32>>();
+    let c: Result<u8, _> = "1".parse::<u8>();
+}
+
+fn no_lint(v: &[u32]) {
+    // Partial turbofish
+    let a: Vec<u32> = v.iter().copied().collect::<Vec<_>>();
+    // Only one of them
+    let b: Vec<u32> = v.iter().copied().collect();
+    let c = v.iter().copied().collect::<Vec<u32>>();
+    // Different types
+    let d: Vec<u64> = v.iter().map(|&x| u64::from(x)).collect::<Vec<_>>();
+    // The annotation is for another expression
+    let e: u8 = "1".parse::<u8>().unwrap_or_default();
+    // Not `collect` or `parse`
+    let f: Vec<u32> = Vec::<u32>::new();
+}
@@ -0,0 +1,20 @@
+//@run-rustfix
+#![warn(clippy::redundant_vec_into_boxed_slice_then_vec)]
+
+fn make() -> Vec<u8> {
+    vec![1, 2, 3]
+}
+
+fn main() {
+    let v = vec![1, 2, 3];
+    let _ = v.into_boxed_slice().into_vec();
+    let _ = make().into_boxed_slice().into_vec();
+
+    let b: Box<[u8]> = Box::new([1, 2, 3]);
+    let _ = b.into_vec().into_boxed_slice();
+
+    // no lint: the conversion is only one way
+    let v = vec![1, 2, 3];
+    let b = v.into_boxed_slice();
+    let _ = b.into_vec();
+}
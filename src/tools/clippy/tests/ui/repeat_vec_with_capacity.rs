@@ -0,0 +1,22 @@
+//@run-rustfix
+#![warn(clippy::repeat_vec_with_capacity)]
+
+use std::iter::repeat;
+
+fn main() {
+    let n = 8;
+
+    let v: Vec<Vec<u8>> = vec![Vec::with_capacity(1024); 4];
+    let v: Vec<String> = vec![String::with_capacity(n); n + 1];
+
+    let v: Vec<Vec<u8>> = std::iter::repeat(Vec::with_capacity(n)).take(2).collect();
+    let v: Vec<String> = repeat(String::with_capacity(16)).take(3).collect();
+
+    // no lint: the capacity isn't set
+    let v: Vec<Vec<u8>> = vec![Vec::new(); 4];
+    let v: Vec<String> = repeat(String::new()).take(3).collect();
+    // no lint: each element is created separately
+    let v: Vec<Vec<u8>> = (0..4).map(|_| Vec::with_capacity(1024)).collect();
+    // no lint: the repeated value isn't a `Vec`
+    let v = vec![Some(Vec::<u8>::with_capacity(n)); 2];
+}
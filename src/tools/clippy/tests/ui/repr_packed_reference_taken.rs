@@ -0,0 +1,45 @@
+#![warn(clippy::repr_packed_reference_taken)]
+
+use std::ptr;
+
+#[repr(packed)]
+struct Header {
+    tag: u8,
+    len: u32,
+}
+
+#[repr(packed(4))]
+struct Packed4 {
+    tag: u8,
+    len: u32,
+}
+
+impl Header {
+    fn len(&self) -> u32 {
+        unsafe { ptr::addr_of!(self.len).read() }
+    }
+}
+
+fn main() {
+    let header = Header { tag: 1, len: 2 };
+    unsafe {
+        let _len = ptr::addr_of!(header.len).read();
+        let _len = ptr::read(ptr::addr_of!(header.len));
+        let _len = *ptr::addr_of!(header.len);
+        let _len = ptr::addr_of!(header.len).read_volatile();
+    }
+
+    // the field is read without assuming alignment
+    let _len = unsafe { ptr::addr_of!(header.len).read_unaligned() };
+    let _len = { header.len };
+    let _len = header.len();
+    // the field is aligned
+    let _tag = unsafe { ptr::addr_of!(header.tag).read() };
+    let p4 = Packed4 { tag: 1, len: 2 };
+    let _len = unsafe { ptr::addr_of!(p4.len).read() };
+    let _tag = p4.tag;
+
+    let mut other = Header { tag: 1, len: 2 };
+    unsafe { *ptr::addr_of_mut!(other.len) = 3 };
+    let _tag = other.tag;
+}
@@ -0,0 +1,53 @@
+//@run-rustfix
+#![warn(clippy::set_contains_or_insert)]
+
+use std::collections::{BTreeSet, HashSet};
+
+fn main() {
+    let mut set = HashSet::new();
+    let value = 1;
+    if !set.contains(&value) {
+        set.insert(value);
+    }
+
+    if !set.contains(&value) {
+        set.insert(value);
+    };
+
+    let mut tree = BTreeSet::new();
+    if !tree.contains(&value) {
+        tree.insert(value);
+        println!("inserted {value}");
+    }
+
+    let mut names = HashSet::new();
+    let mut count = 0;
+    for name in ["a", "b", "a"] {
+        if !names.contains(name) {
+            names.insert(name.to_string());
+            count += 1;
+        }
+    }
+
+    // no lint: the value isn't inserted first
+    if !set.contains(&value) {
+        println!("{}", set.len());
+        set.insert(value);
+    }
+    // no lint: another value is inserted
+    if !set.contains(&value) {
+        set.insert(value + 1);
+    }
+    // no lint: there is an `else`
+    if !set.contains(&value) {
+        set.insert(value);
+    } else {
+        println!("present");
+    }
+    // no lint: the `if` is an `else if`
+    if value == 0 {
+        println!("zero");
+    } else if !set.contains(&value) {
+        set.insert(value);
+    }
+}
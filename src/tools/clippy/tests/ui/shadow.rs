@@ -114,4 +114,11 @@ fn ice_8748() {
     }];
 }
 
+fn shadow_reuse_nested() {
+    let guard = 1;
+    let guard = Some(1).map(|g| g + guard);
+    let name = "a";
+    let name = format!("{name}!");
+}
+
 fn main() {}
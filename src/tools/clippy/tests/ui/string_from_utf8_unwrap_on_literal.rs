@@ -0,0 +1,21 @@
+//@run-rustfix
+#![warn(clippy::string_from_utf8_unwrap_on_literal)]
+
+fn main() {
+    let _ = std::str::from_utf8(b"hello").unwrap();
+    let _ = std::str::from_utf8(b"tab\there").expect("valid");
+    let _ = std::str::from_utf8(br"raw\n").unwrap();
+    let _ = String::from_utf8(b"hello".to_vec()).unwrap();
+    let _ = std::str::from_utf8(b"caf\xC3\xA9").unwrap();
+
+    // not valid UTF-8
+    let _ = std::str::from_utf8(b"\xFF\xFE").unwrap();
+    let _ = String::from_utf8(b"\xC3".to_vec()).unwrap();
+
+    // not a literal
+    let bytes = b"hello";
+    let _ = std::str::from_utf8(bytes).unwrap();
+
+    // not unwrapped
+    let _ = std::str::from_utf8(b"hello");
+}
@@ -8,4 +8,20 @@ fn main() {
 
     let _ = d.to_digit(8).is_some();
     let _ = char::to_digit(c, 8).is_some();
+
+    let _ = c.to_digit(10).is_some();
+    let _ = d.to_digit(16).is_some();
+    let _ = char::to_digit(c, 10).is_some();
+}
+
+#[clippy::msrv = "1.23"]
+fn msrv_1_23() {
+    let c = 'x';
+    let _ = c.to_digit(10).is_some();
+}
+
+#[clippy::msrv = "1.24"]
+fn msrv_1_24() {
+    let c = 'x';
+    let _ = c.to_digit(10).is_some();
 }
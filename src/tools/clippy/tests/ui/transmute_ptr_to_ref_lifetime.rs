@@ -0,0 +1,29 @@
+#![warn(clippy::transmute_ptr_to_ref)]
+#![allow(clippy::missing_safety_doc)]
+
+pub unsafe fn turbofish_static(p: *const u32) {
+    let _ = std::mem::transmute::<_, &'static u32>(p);
+}
+
+pub unsafe fn annotated_static(p: *const u32) {
+    let _: &'static u32 = std::mem::transmute(p);
+}
+
+pub unsafe fn returns_static(p: *const u32) -> &'static u32 {
+    std::mem::transmute(p)
+}
+
+pub unsafe fn returns_unbounded<'a>(p: *const u32) -> &'a u32 {
+    std::mem::transmute(p)
+}
+
+pub struct Wrapper(*const u32);
+
+impl Wrapper {
+    pub unsafe fn get(&self) -> &u32 {
+        // The lifetime is bounded by `self`
+        std::mem::transmute(self.0)
+    }
+}
+
+fn main() {}
@@ -76,6 +76,54 @@ async fn unused() -> i32 {
 }
 async_trait_impl!();
 
+mod required_future {
+    #![allow(dead_code, clippy::redundant_closure)]
+
+    use std::future::Future;
+    use std::pin::Pin;
+
+    struct Router;
+
+    impl Router {
+        fn route<F, Fut>(&mut self, _handler: F)
+        where
+            F: Fn() -> Fut,
+            Fut: Future<Output = ()>,
+        {
+        }
+    }
+
+    fn spawn<F: Future<Output = ()>>(_f: impl FnOnce() -> F) {}
+
+    // Don't lint. The handlers are registered with functions requiring a future.
+    async fn handler() {}
+
+    async fn closure_handler() {}
+
+    fn register(router: &mut Router) {
+        router.route(handler);
+        spawn(|| closure_handler());
+    }
+
+    // Don't lint. The future is boxed as a `dyn Future`.
+    async fn boxed() {}
+
+    fn boxed_future() -> Pin<Box<dyn Future<Output = ()>>> {
+        Box::pin(boxed())
+    }
+
+    // Lint. The function pointer doesn't need to return a future.
+    async fn as_value() {}
+
+    fn call<F: Fn() -> T, T>(f: F) -> T {
+        f()
+    }
+
+    fn use_as_value() -> impl Future<Output = ()> {
+        call(as_value)
+    }
+}
+
 fn main() {
     foo();
     bar();
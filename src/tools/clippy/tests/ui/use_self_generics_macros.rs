@@ -0,0 +1,101 @@
+//@run-rustfix
+#![warn(clippy::use_self)]
+#![allow(dead_code, clippy::new_without_default)]
+
+fn main() {}
+
+mod turbofish {
+    pub struct Foo<T>(T);
+
+    impl<T: Default> Foo<T> {
+        pub fn new() -> Self {
+            Self(T::default())
+        }
+
+        fn lint() -> Self {
+            Foo::<T>::new()
+        }
+
+        // Different generic args, `Self` is not applicable
+        fn no_lint() -> Foo<u8> {
+            Foo::<u8>::new()
+        }
+    }
+}
+
+mod nested_generic_impls {
+    pub struct Outer<T>(T);
+    pub struct Inner<U>(U);
+
+    impl<T: Default> Outer<T> {
+        pub fn new() -> Self {
+            Self(T::default())
+        }
+
+        fn lint() -> Self {
+            impl<U: Default> Inner<U> {
+                pub fn new() -> Self {
+                    Self(U::default())
+                }
+
+                fn lint() -> Self {
+                    Inner::<U>::new()
+                }
+
+                // `Outer` is not the self type of this `impl`
+                fn no_lint() -> Outer<U> {
+                    Outer::<U>::new()
+                }
+            }
+
+            Outer::<T>::new()
+        }
+    }
+}
+
+mod macros {
+    pub struct Foo;
+    pub struct Bar<T>(T);
+
+    macro_rules! make {
+        () => {
+            Foo::new()
+        };
+        ($ty:ident) => {
+            $ty::new()
+        };
+    }
+
+    macro_rules! make_generic {
+        ($ty:ident) => {
+            $ty::<T>::new()
+        };
+    }
+
+    impl Foo {
+        fn new() -> Self {
+            Self
+        }
+
+        // The macro author wrote `Foo` on purpose, don't lint
+        fn from_macro() -> Self {
+            make!()
+        }
+
+        // The whole path comes from the call site, this is fine to lint
+        fn from_call_site() -> Self {
+            make!(Foo)
+        }
+    }
+
+    impl<T: Default> Bar<T> {
+        fn new() -> Self {
+            Self(T::default())
+        }
+
+        // Don't lint: the generic args are added by the macro
+        fn partially_from_macro() -> Self {
+            make_generic!(Bar)
+        }
+    }
+}
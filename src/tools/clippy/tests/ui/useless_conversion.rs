@@ -182,6 +182,16 @@ macro_rules! macro_generated {
         };
     }
     b(macro_generated!());
+
+    let mut v = vec![1];
+    let other = vec![2, 3];
+    v.extend(other.clone().into_iter());
+    let _ = v.iter().copied().chain(other.into_iter());
+
+    // `PathBuf` doesn't implement `IntoIterator`, only `&PathBuf` does
+    let path = std::path::PathBuf::from("a/b");
+    let mut components = Vec::new();
+    components.extend(path.into_iter());
 }
 
 #[derive(Copy, Clone)]
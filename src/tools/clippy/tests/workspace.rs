@@ -1,5 +1,6 @@
 #![feature(lazy_cell)]
 
+use std::fs;
 use std::path::PathBuf;
 use std::process::Command;
 use test_utils::{CARGO_CLIPPY_PATH, IS_RUSTC_TEST_SUITE};
@@ -145,3 +146,201 @@ fn test_no_deps_ignores_path_deps_in_workspaces() {
     // Make sure Cargo is aware of the new `--cfg` flag.
     lint_path_dep();
 }
+
+#[test]
+fn test_fix_rewrites_sources() {
+    if IS_RUSTC_TEST_SUITE {
+        return;
+    }
+    let root = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+    let target_dir = root.join("target").join("workspace_test");
+    let cwd = target_dir.join("fix");
+
+    // `--fix` rewrites the sources, so work on a copy of the fixture
+    let _ = fs::remove_dir_all(&cwd);
+    fs::create_dir_all(cwd.join("src")).unwrap();
+    let fixture = root.join("tests/workspace_test/fix");
+    fs::copy(fixture.join("Cargo.toml"), cwd.join("Cargo.toml")).unwrap();
+    fs::copy(fixture.join("src/lib.rs"), cwd.join("src/lib.rs")).unwrap();
+
+    // Only `manual_slice_contains` is applied, the lint arguments after `--` are still honored
+    let output = Command::new(&*CARGO_CLIPPY_PATH)
+        .current_dir(&cwd)
+        .env("CARGO_INCREMENTAL", "0")
+        .env("CARGO_TARGET_DIR", &target_dir)
+        .arg("clippy")
+        .arg("--fix")
+        .arg("--allow-no-vcs")
+        .arg("--")
+        .arg("-Cdebuginfo=0") // disable debuginfo to generate less data in the target dir
+        .args(["-A", "clippy::needless_return"])
+        .output()
+        .unwrap();
+    println!("status: {}", output.status);
+    println!("stdout: {}", String::from_utf8_lossy(&output.stdout));
+    println!("stderr: {}", String::from_utf8_lossy(&output.stderr));
+    assert!(output.status.success());
+
+    let fixed = fs::read_to_string(cwd.join("src/lib.rs")).unwrap();
+    assert!(fixed.contains("xs.contains(&1)"), "{fixed}");
+    assert!(fixed.contains("return x * 2;"), "{fixed}");
+
+    // The fixed crate still compiles
+    let output = Command::new("cargo")
+        .current_dir(&cwd)
+        .env("CARGO_TARGET_DIR", &target_dir)
+        .arg("check")
+        .output()
+        .unwrap();
+    println!("stderr: {}", String::from_utf8_lossy(&output.stderr));
+    assert!(output.status.success());
+}
+
+#[test]
+fn test_config_files_are_merged_up_to_workspace_root() {
+    if IS_RUSTC_TEST_SUITE {
+        return;
+    }
+    let root = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+    let target_dir = root.join("target").join("workspace_test");
+    let cwd = root.join("tests/workspace_test/config_layers");
+
+    // Make sure we start with a clean state
+    Command::new("cargo")
+        .current_dir(&cwd)
+        .env("CARGO_TARGET_DIR", &target_dir)
+        .arg("clean")
+        .args(["-p", "leaf"])
+        .output()
+        .unwrap();
+
+    let output = Command::new(&*CARGO_CLIPPY_PATH)
+        .current_dir(&cwd)
+        .env("CARGO_INCREMENTAL", "0")
+        .env("CARGO_TARGET_DIR", &target_dir)
+        .arg("clippy")
+        .arg("--")
+        .arg("-Cdebuginfo=0") // disable debuginfo to generate less data in the target dir
+        .output()
+        .unwrap();
+    println!("status: {}", output.status);
+    println!("stdout: {}", String::from_utf8_lossy(&output.stdout));
+    println!("stderr: {}", String::from_utf8_lossy(&output.stderr));
+    assert!(output.status.success());
+
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    // The threshold of the crate's file overrides the one of the workspace root
+    assert!(stderr.contains("this function has too many arguments (4/3)"));
+    assert!(!stderr.contains("(2/"));
+    // `".."` in `mid/clippy.toml` extends the names of the workspace root
+    assert!(stderr.contains("use of a disallowed/placeholder name `root_name`"));
+    assert!(stderr.contains("use of a disallowed/placeholder name `mid_name`"));
+    assert!(!stderr.contains("use of a disallowed/placeholder name `foo`"));
+}
+
+#[test]
+fn test_all_targets_reports_shared_diagnostics_once() {
+    if IS_RUSTC_TEST_SUITE {
+        return;
+    }
+    let root = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+    let target_dir = root.join("target").join("workspace_test");
+    let cwd = root.join("tests/workspace_test/all_targets");
+
+    let run = |deny_warnings: bool| {
+        // Make sure we start with a clean state
+        Command::new("cargo")
+            .current_dir(&cwd)
+            .env("CARGO_TARGET_DIR", &target_dir)
+            .arg("clean")
+            .args(["-p", "all_targets"])
+            .output()
+            .unwrap();
+
+        let output = Command::new(&*CARGO_CLIPPY_PATH)
+            .current_dir(&cwd)
+            .env("CARGO_INCREMENTAL", "0")
+            .env("CARGO_TARGET_DIR", &target_dir)
+            .arg("clippy")
+            .arg("--all-targets")
+            .arg("--")
+            .arg("-Cdebuginfo=0") // disable debuginfo to generate less data in the target dir
+            .args(deny_warnings.then_some("-Dwarnings"))
+            .output()
+            .unwrap();
+        println!("status: {}", output.status);
+        println!("stdout: {}", String::from_utf8_lossy(&output.stdout));
+        println!("stderr: {}", String::from_utf8_lossy(&output.stderr));
+        output
+    };
+
+    // The lib is checked for the lib target and again for its unit tests, Cargo only prints the
+    // diagnostics of the second check which aren't exact repeats
+    let output = run(false);
+    assert!(output.status.success());
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert_eq!(stderr.matches("unneeded `return` statement").count(), 1);
+
+    // Deduplicated errors still fail the build
+    let output = run(true);
+    assert!(!output.status.success());
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert_eq!(stderr.matches("error: unneeded `return` statement").count(), 1);
+}
+
+#[test]
+fn test_config_path_overrides_discovery() {
+    if IS_RUSTC_TEST_SUITE {
+        return;
+    }
+    let root = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+    let target_dir = root.join("target").join("workspace_test");
+    let cwd = root.join("tests/workspace_test/config_layers");
+
+    let run = |conf_path: &str| {
+        // Make sure we start with a clean state
+        Command::new("cargo")
+            .current_dir(&cwd)
+            .env("CARGO_TARGET_DIR", &target_dir)
+            .arg("clean")
+            .args(["-p", "leaf"])
+            .output()
+            .unwrap();
+
+        let output = Command::new(&*CARGO_CLIPPY_PATH)
+            .current_dir(&cwd)
+            .env("CARGO_INCREMENTAL", "0")
+            .env("CARGO_TARGET_DIR", &target_dir)
+            // The flag takes precedence over the environment variable
+            .env("CLIPPY_CONF_PATH", "mid/leaf/clippy.toml")
+            .arg("clippy")
+            .args(["--config-path", conf_path])
+            .arg("--")
+            .arg("-Cdebuginfo=0") // disable debuginfo to generate less data in the target dir
+            .output()
+            .unwrap();
+        println!("status: {}", output.status);
+        println!("stdout: {}", String::from_utf8_lossy(&output.stdout));
+        println!("stderr: {}", String::from_utf8_lossy(&output.stderr));
+        output
+    };
+
+    // Only `strict.toml` is read, relative to the directory `cargo clippy` is run in
+    let output = run("strict.toml");
+    assert!(output.status.success());
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert!(stderr.contains("this function has too many arguments (2/1)"));
+    assert!(stderr.contains("this function has too many arguments (4/1)"));
+    assert!(!stderr.contains("disallowed/placeholder name `root_name`"));
+
+    let output = run("missing.toml");
+    assert!(!output.status.success());
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert!(stderr.contains("error finding Clippy's configuration file: `CLIPPY_CONF_PATH` is set to"));
+
+    // The directory exists but doesn't contain a `clippy.toml`
+    let output = run("mid/leaf/src");
+    assert!(!output.status.success());
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert!(stderr.contains("which doesn't contain a `clippy.toml`"));
+}
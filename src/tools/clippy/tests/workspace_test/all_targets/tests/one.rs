@@ -0,0 +1,4 @@
+#[test]
+fn one() {
+    assert_eq!(all_targets::one(), 1);
+}
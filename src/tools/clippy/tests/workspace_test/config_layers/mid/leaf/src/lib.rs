@@ -0,0 +1,12 @@
+#![warn(clippy::too_many_arguments, clippy::disallowed_names)]
+
+pub fn two(_: u8, _: u8) {}
+
+pub fn four(_: u8, _: u8, _: u8, _: u8) {}
+
+pub fn names() {
+    let root_name = 1;
+    let mid_name = 2;
+    let foo = 3;
+    let _ = (root_name, mid_name, foo);
+}
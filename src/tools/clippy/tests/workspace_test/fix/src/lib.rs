@@ -0,0 +1,7 @@
+pub fn has_one(xs: &[u32]) -> bool {
+    xs.iter().any(|x| *x == 1)
+}
+
+pub fn double(x: u32) -> u32 {
+    return x * 2;
+}